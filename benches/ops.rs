@@ -0,0 +1,175 @@
+//! Benchmarks of the core matrix operations, run with `cargo bench --features bench-fixtures`.
+//! With `--features bench-fixtures,rayon` the `mul` group also times the parallel product.
+//!
+//! Every group checks the result of its operation once per size before timing it,
+//! so a broken kernel fails the bench run instead of producing a meaningless number.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use libmat::bench_fixtures::{diagonally_dominant, random_matrix, SEED, SIZES};
+use libmat::mat::Matrix;
+use num_traits::Inv;
+
+const EPS: f64 = 1e-9;
+
+fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, what: &str) {
+    assert_eq!(a.dims(), b.dims(), "{}: dimensions differ", what);
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert!(
+                (a[i][j] - b[i][j]).abs() <= EPS,
+                "{}: entry ({}, {}) is {}, expected {}",
+                what,
+                i,
+                j,
+                a[i][j],
+                b[i][j]
+            );
+        }
+    }
+}
+
+fn naive_mul(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+    let mut res = Matrix::zero(a.rows(), b.cols()).unwrap();
+    for i in 0..a.rows() {
+        for j in 0..b.cols() {
+            for k in 0..a.cols() {
+                res[i][j] += a[i][k] * b[k][j];
+            }
+        }
+    }
+    res
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+    for &n in SIZES.iter() {
+        let a = random_matrix(n, n, SEED);
+        let b = random_matrix(n, n, SEED + 1);
+        assert_close(&(&a * &b).unwrap(), &naive_mul(&a, &b), "mul");
+        group.bench_with_input(BenchmarkId::new("tiled", n), &(&a, &b), |bench, (a, b)| {
+            bench.iter(|| black_box(*a) * black_box(*b))
+        });
+        // the textbook triple loop, as a baseline for the speedup of the tiled kernel
+        group.bench_with_input(BenchmarkId::new("naive", n), &(&a, &b), |bench, (a, b)| {
+            bench.iter(|| naive_mul(black_box(a), black_box(b)))
+        });
+        #[cfg(feature = "rayon")]
+        {
+            assert_close(&a.par_mul(&b).unwrap(), &naive_mul(&a, &b), "par_mul");
+            group.bench_with_input(
+                BenchmarkId::new("parallel", n),
+                &(&a, &b),
+                |bench, (a, b)| bench.iter(|| black_box(a).par_mul(black_box(b))),
+            );
+        }
+    }
+    group.finish();
+}
+
+fn bench_add_assign(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_assign");
+    for &n in SIZES.iter() {
+        let a = random_matrix(n, n, SEED);
+        let b = random_matrix(n, n, SEED + 1);
+        let mut sum = a.clone();
+        sum += &b;
+        assert_close(&sum, &(&a + &b).unwrap(), "add_assign");
+        // `+=` uses the AVX kernel if the CPU supports it
+        let mut acc = a.clone();
+        group.bench_with_input(BenchmarkId::new("simd", n), &b, |bench, b| {
+            bench.iter(|| acc += black_box(b))
+        });
+        // the loop `+=` falls back to, on the raw storage
+        let mut acc: Vec<f64> = a.clone().into_iter().collect();
+        let b: Vec<f64> = b.into_iter().collect();
+        group.bench_with_input(BenchmarkId::new("scalar", n), &b, |bench, b| {
+            bench.iter(|| acc.iter_mut().zip(black_box(b)).for_each(|(x, y)| *x += *y))
+        });
+    }
+    group.finish();
+}
+
+fn bench_det(c: &mut Criterion) {
+    let mut group = c.benchmark_group("det");
+    for &n in SIZES.iter() {
+        let a = diagonally_dominant(n, SEED);
+        let det = a.det().unwrap();
+        let det_inv = a.clone().inv().unwrap().unwrap().det().unwrap();
+        assert!(
+            (det * det_inv - 1.0).abs() <= EPS,
+            "det: {} * {} != 1",
+            det,
+            det_inv
+        );
+        group.bench_with_input(BenchmarkId::from_parameter(n), &a, |bench, a| {
+            bench.iter(|| black_box(a).det())
+        });
+    }
+    group.finish();
+}
+
+fn bench_inv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inv");
+    for &n in SIZES.iter() {
+        let a = diagonally_dominant(n, SEED);
+        let inv = a.clone().inv().unwrap().unwrap();
+        assert_close(&naive_mul(&a, &inv), &Matrix::one(n).unwrap(), "inv");
+        group.bench_with_input(BenchmarkId::from_parameter(n), &a, |bench, a| {
+            bench.iter(|| black_box(a.clone()).inv())
+        });
+    }
+    group.finish();
+}
+
+fn bench_rref(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rref");
+    for &n in SIZES.iter() {
+        let a = diagonally_dominant(n, SEED);
+        assert_close(&a.rref(), &Matrix::one(n).unwrap(), "rref");
+        group.bench_with_input(BenchmarkId::from_parameter(n), &a, |bench, a| {
+            bench.iter(|| black_box(a).rref())
+        });
+    }
+    group.finish();
+}
+
+fn bench_lupdecompose(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lupdecompose");
+    for &n in SIZES.iter() {
+        let a = diagonally_dominant(n, SEED);
+        let (lu, p) = a.lupdecompose().unwrap().unwrap();
+        let mut l = Matrix::one(n).unwrap();
+        let mut u = Matrix::zero(n, n).unwrap();
+        for i in 0..n {
+            for j in 0..n {
+                if j < i {
+                    l[i][j] = lu[i][j];
+                } else {
+                    u[i][j] = lu[i][j];
+                }
+            }
+        }
+        let mut pa = Matrix::zero(n, n).unwrap();
+        for i in 0..n {
+            for j in 0..n {
+                pa[i][j] = a[p[i]][j];
+            }
+        }
+        assert_close(&naive_mul(&l, &u), &pa, "lupdecompose");
+        group.bench_with_input(BenchmarkId::from_parameter(n), &a, |bench, a| {
+            bench.iter(|| black_box(a).lupdecompose())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_mul,
+    bench_add_assign,
+    bench_det,
+    bench_inv,
+    bench_rref,
+    bench_lupdecompose
+);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+//! Reproducible input matrices for benchmarks, available with the `bench-fixtures` feature.
+//!
+//! All generators take a seed and use a seeded [StdRng], so the same seed and size always give
+//! the same matrix. [SIZES] and [SEED] are the ones used by the bench suite in `benches/`.
+//!
+//! # Example
+//!
+//! ```
+//! # use libmat::bench_fixtures;
+//! let mat_a = bench_fixtures::random_matrix(4, 3, bench_fixtures::SEED);
+//! let mat_b = bench_fixtures::random_matrix(4, 3, bench_fixtures::SEED);
+//! assert_eq!(mat_a, mat_b);
+//! assert_eq!((mat_a.rows(), mat_a.cols()), (4, 3));
+//! ```
+
+use crate::mat::Matrix;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Matrix sizes used by the bench suite.
+pub const SIZES: [usize; 4] = [4, 16, 64, 128];
+
+/// Default seed of the bench suite.
+pub const SEED: u64 = 0x6c69_626d_6174;
+
+/// Create a `rows x cols` matrix with entries uniformly distributed in `[-1, 1)`.
+///
+/// # Panics
+///
+/// If `rows` or `cols` is zero.
+pub fn random_matrix(rows: usize, cols: usize, seed: u64) -> Matrix<f64> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let entries = (0..rows * cols).map(|_| rng.gen_range(-1.0..1.0)).collect();
+    Matrix::from_vec(rows, cols, entries).unwrap()
+}
+
+/// Create a strictly diagonally dominant `dim x dim` matrix.
+///
+/// The off-diagonal entries are those of [random_matrix] and every diagonal entry is larger than
+/// the sum of the absolute values in its row, so the matrix is invertible and well conditioned.
+/// This makes it a safe input for `det()`, `inv()` and `lupdecompose()` at any size.
+///
+/// # Panics
+///
+/// If `dim` is zero.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::bench_fixtures;
+/// let mat = bench_fixtures::diagonally_dominant(8, 1);
+/// for i in 0..8 {
+///     let off: f64 = (0..8).filter(|&j| j != i).map(|j| mat[i][j].abs()).sum();
+///     assert!(mat[i][i] > off);
+/// }
+/// ```
+pub fn diagonally_dominant(dim: usize, seed: u64) -> Matrix<f64> {
+    let mut mat = random_matrix(dim, dim, seed);
+    for i in 0..dim {
+        let off: f64 = (0..dim).filter(|&j| j != i).map(|j| mat[i][j].abs()).sum();
+        mat[i][i] = off + 1.0;
+    }
+    mat
+}
@@ -4,6 +4,24 @@ use std::fmt::{Display, Formatter, Result};
 #[derive(Debug, PartialEq)]
 pub enum MatrixError {
     IndexOutOfBounds(usize),
+    /// The operation would leave a matrix without rows or columns.
+    WouldBeEmpty,
+}
+
+/// Operand of a failed operation in a [DimensionError::LabeledNoMatch].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Operand {
+    pub dims: Dimensions,
+    pub label: Option<String>,
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match &self.label {
+            Some(label) => write!(f, "`{label}` ({})", self.dims),
+            None => write!(f, "{} matrix", self.dims),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -11,7 +29,57 @@ pub enum DimensionError {
     InvalidDimensions,
     InvalidInputDimensions(usize, usize),
     NoMatch(Dimensions, Dimensions, String),
+    /// Like [NoMatch](DimensionError::NoMatch), if at least one of the operands has a label,
+    /// see [Matrix::with_label](crate::mat::Matrix::with_label).
+    LabeledNoMatch(Operand, Operand, String),
     NoSquare,
+    /// The dimensions are fine, but the values of the input are not allowed for the operation.
+    InvalidValue(String),
+}
+
+/// Error of parsing a [Matrix](crate::mat::Matrix) or [Vector](crate::mat::Vector) from a string.
+///
+/// Rows and columns are counted from zero, rows without entries are not counted.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseMatrixError {
+    /// The input has no entries.
+    Empty,
+    /// The token in row `row` and column `col` is not a valid entry.
+    InvalidEntry {
+        row: usize,
+        col: usize,
+        token: String,
+    },
+    /// Row `row` has `found` entries, but the first row has `expected`.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Error of reading or writing a [Matrix](crate::mat::Matrix) as CSV, available with the `csv` feature.
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum CsvError {
+    /// The CSV data couldn't be read or written.
+    Csv(csv::Error),
+    /// The records are empty, ragged or contain an invalid entry.
+    Parse(ParseMatrixError),
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for CsvError {
+    fn from(err: csv::Error) -> CsvError {
+        CsvError::Csv(err)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<ParseMatrixError> for CsvError {
+    fn from(err: ParseMatrixError) -> CsvError {
+        CsvError::Parse(err)
+    }
 }
 
 impl Display for MatrixError {
@@ -21,6 +89,10 @@ impl Display for MatrixError {
                 f,
                 "Tried to access a matrix at index `{idx}`, which is out of bounds.",
             )?,
+            MatrixError::WouldBeEmpty => write!(
+                f,
+                "A matrix needs at least one row and column, so the last one can't be removed.",
+            )?,
         }
         Ok(())
     }
@@ -36,10 +108,46 @@ impl Display for DimensionError {
                 f,
                 "Dimensions of two matrices do not match in the correct way. Cannot {op} {dims} matrix with {bad_dims} matrix.",
             )?,
+            DimensionError::LabeledNoMatch(lhs, rhs, op) => write!(
+                f,
+                "Dimensions of two matrices do not match in the correct way. Cannot {op} {lhs} with {rhs}.",
+            )?,
             DimensionError::InvalidInputDimensions(input_len, correct_len) => write!(f, "Invalid input dimensions. Input has length {input_len}, but should have length {correct_len}.")?,
             DimensionError::NoSquare => {
                 write!(f, "Not a square matrix. Rows and cols need to be the same.")?
             }
+            DimensionError::InvalidValue(reason) => write!(f, "Invalid input values: {reason}.")?,
+        }
+        Ok(())
+    }
+}
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            ParseMatrixError::Empty => write!(f, "Cannot parse a matrix without entries.")?,
+            ParseMatrixError::InvalidEntry { row, col, token } => {
+                write!(f, "Invalid entry `{token}` in row {row}, column {col}.",)?
+            }
+            ParseMatrixError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {row} has {found} entries, but the rows before have {expected}.",
+            )?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            CsvError::Csv(err) => write!(f, "Invalid CSV data: {err}.")?,
+            CsvError::Parse(err) => write!(f, "{err}")?,
         }
         Ok(())
     }
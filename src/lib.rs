@@ -24,9 +24,9 @@
 //!     2, 1, 3;
 //! };
 //!
-//! // Are the matrices invertible?
-//! assert_ne!(mat_a.det()?, 0);
-//! assert_ne!(mat_b.det()?, 0);
+//! // Are the matrices invertible? Integer determinants need to be calculated exactly.
+//! assert_eq!(mat_a.det_bareiss()?, 1);
+//! assert_eq!(mat_b.det_bareiss()?, -12);
 //! # Ok(()) }
 //! ```
 //!
@@ -34,6 +34,11 @@
 #![doc(html_logo_url = "../logo.png")]
 #![doc(html_no_source)]
 
+#[cfg(feature = "bench-fixtures")]
+pub mod bench_fixtures;
 pub mod err;
+#[macro_use]
 mod macros;
 pub mod mat;
+#[cfg(feature = "proptest-support")]
+pub mod proptest_support;
@@ -5,35 +5,54 @@ use crate::mat::{Matrix, SMatrix, Vector};
 /// Creates a new [Matrix].
 ///
 /// Supports [Wolfram Alpha](https://www.wolframalpha.com/input/?i=matrix+multiplication) syntax.
+///
+/// # Panics
+///
+/// Panics if the rows do not all have the same length. Use [try_matrix!](crate::try_matrix) to get an error instead.
 macro_rules! matrix {
     ( $( {$($x:expr),+ $(,)?} ),+ $(,)? ) => {
         {
-            let mut matrix_rows: usize = 0;
-            let mut matrix_cols: usize = 0;
-            let mut matrix_vec = Vec::new();
-            $(
-                matrix_rows += 1;
-                $(
-                    matrix_vec.push($x);
-                )+
-            )+
-            matrix_cols = matrix_vec.len()/matrix_rows;
-            Matrix::from_vec(matrix_rows, matrix_cols, matrix_vec).unwrap()
+            Matrix::from_rows(vec![$(vec![$($x),+]),+])
+                .expect("All rows of a matrix need to have the same length.")
+        }
+    };
+    ( $( $( $x:expr ),+ $(,)? );+ $(;)? ) => {
+        {
+            Matrix::from_rows(vec![$(vec![$($x),+]),+])
+                .expect("All rows of a matrix need to have the same length.")
+        }
+    };
+}
+
+#[macro_export]
+/// Creates a new [Matrix], returning a [DimensionError](crate::err::DimensionError) if the rows
+/// do not all have the same length.
+///
+/// Accepts the same syntax as [matrix].
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::{matrix, try_matrix};
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// assert_eq!(try_matrix!{1, 2; 3, 4}?, matrix!{1, 2; 3, 4});
+/// assert_eq!(
+///     try_matrix!{1, 2, 3; 4, 5},
+///     Err(DimensionError::InvalidInputDimensions(2, 3))
+/// );
+/// # Ok(()) }
+/// ```
+macro_rules! try_matrix {
+    ( $( {$($x:expr),+ $(,)?} ),+ $(,)? ) => {
+        {
+            Matrix::from_rows(vec![$(vec![$($x),+]),+])
         }
     };
     ( $( $( $x:expr ),+ $(,)? );+ $(;)? ) => {
         {
-            let mut matrix_rows: usize = 0;
-            let mut matrix_cols: usize = 0;
-            let mut matrix_vec = Vec::new();
-            $(
-                matrix_rows += 1;
-                $(
-                    matrix_vec.push($x);
-                )+
-            )+
-            matrix_cols = matrix_vec.len()/matrix_rows;
-            Matrix::from_vec(matrix_rows, matrix_cols, matrix_vec).unwrap()
+            Matrix::from_rows(vec![$(vec![$($x),+]),+])
         }
     };
 }
@@ -46,13 +65,13 @@ macro_rules! smatrix {
     ( $( {$($x:expr),+ $(,)?} ),+ $(,)? ) => {
         {
             let arr = [$([$($x),+]),+];
-            SMatrix::from(arr)
+            SMatrix::from_rows(arr)
         }
     };
     ( $( $( $x:expr ),+ $(,)? );+ $(;)? ) => {
         {
             let arr = [$([$($x),+]),+];
-            SMatrix::from(arr)
+            SMatrix::from_rows(arr)
         }
     };
     ([[$init:expr; $c:expr]; $r:expr]) => {
@@ -69,3 +88,70 @@ macro_rules! vector {
         Vector::from(vec![$($x),+])
     }};
 }
+
+#[macro_export]
+/// Asserts that two matrices ([Matrix] or [SMatrix]) of floating point numbers are equal up to an absolute tolerance.
+///
+/// On failure the panic message contains the first entry that differs by more than the tolerance and both matrices.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::{assert_matrix_approx_eq, matrix};
+/// let mat_a = matrix!{0.1 + 0.2, 1.0; 2.0, 3.0};
+/// assert_matrix_approx_eq!(mat_a, matrix!{0.3, 1.0; 2.0, 3.0}, 1e-12);
+/// ```
+///
+/// ```should_panic
+/// # use libmat::mat::Matrix;
+/// # use libmat::{assert_matrix_approx_eq, matrix};
+/// // panics with "first difference at (1, 0): 2 vs 2.5"
+/// assert_matrix_approx_eq!(matrix!{1.0; 2.0}, matrix!{1.0; 2.5}, 1e-3);
+/// ```
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {{
+        let (left, right, epsilon) = (&$left, &$right, $epsilon);
+        if left.rows() != right.rows() || left.cols() != right.cols() {
+            panic!(
+                "assertion `left ≈ right` failed: dimensions differ, {}x{} vs {}x{}\n left:\n{}\nright:\n{}",
+                left.rows(),
+                left.cols(),
+                right.rows(),
+                right.cols(),
+                left,
+                right
+            );
+        }
+        for i in 0..left.rows() {
+            for j in 0..left.cols() {
+                let (l, r) = (left[i][j], right[i][j]);
+                let diff = if l > r { l - r } else { r - l };
+                if !(diff <= epsilon) {
+                    panic!(
+                        "assertion `left ≈ right` failed (epsilon: {}): first difference at ({}, {}): {} vs {}\n left:\n{}\nright:\n{}",
+                        epsilon, i, j, l, r, left, right
+                    );
+                }
+            }
+        }
+    }};
+}
+
+/// Implements a binary operator for `impl<T>` with the body given as a closure over both operands.
+/// Used for the by-reference and mixed ownership forms of the operators, which forward to one main impl.
+macro_rules! impl_binop {
+    (impl $imp:ident::$method:ident for $lhs:ty, $rhs:ty => $out:ty; |$a:ident, $b:ident| $body:expr; where $($bound:tt)+) => {
+        impl<T> $imp<$rhs> for $lhs
+        where
+            $($bound)+
+        {
+            type Output = $out;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+    };
+}
@@ -1,16 +1,108 @@
 mod _mat;
 pub mod dims;
+mod display;
+pub mod fingerprint;
+pub mod float;
+pub mod precond;
+mod simd;
 mod smat;
 mod vec;
 use dims::Dimensions;
+#[cfg(test)]
+use std::cell::Cell;
+use std::cell::OnceCell;
 
 /// Represents a matrix.
-#[derive(Debug, PartialEq, Clone)]
+///
+/// A matrix can have a label, see [Matrix::with_label], which is only used in messages.
+/// Two matrices with the same entries are equal, no matter their labels.
+#[derive(Debug, Clone)]
 pub struct Matrix<T> {
     dims: Dimensions,
     matrix: Vec<T>,
+    label: Option<String>,
+}
+
+impl<T> PartialEq for Matrix<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        self.dims == other.dims && self.matrix == other.matrix
+    }
+}
+
+/// How a pivot is chosen during elimination, see [Matrix::lupdecompose_with] and [Matrix::rref_with].
+#[derive(Debug)]
+pub enum PivotStrategy<T> {
+    /// Use the first entry of the column that is not zero. This needs no comparisons of magnitudes,
+    /// so it works for element types like intervals that have no meaningful absolute value.
+    FirstNonZero,
+    /// Use the entry of the column with the largest magnitude, measured by the given function (partial pivoting).
+    Largest(fn(&T) -> f64),
+}
+
+impl<T> Clone for PivotStrategy<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PivotStrategy<T> {}
+
+/// Immutable matrix that remembers the results of expensive computations.
+///
+/// A frozen matrix is created with [Matrix::freeze]. It can be read like a [Matrix], but not changed,
+/// so the results of [det](Frozen::det), [inverse](Frozen::inverse), [rank](Frozen::rank) and
+/// [lu](Frozen::lu) are computed on first use and reused afterwards.
+///
+/// ```compile_fail
+/// # use libmat::mat::Matrix;
+/// let mut frozen = Matrix::<f64>::one(3).unwrap().freeze();
+/// frozen[0][0] = 2.0;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Frozen<T> {
+    matrix: Matrix<T>,
+    lu: OnceCell<Option<Lup<T>>>,
+    det: OnceCell<T>,
+    inverse: OnceCell<Option<Matrix<T>>>,
+    rank: OnceCell<usize>,
+    /// Number of LUP decompositions computed so far, to check the caching in unit tests.
+    #[cfg(test)]
+    factorizations: Cell<usize>,
+}
+
+/// Result of an iterative solver like [Matrix::conjugate_gradient].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeSolution {
+    /// The approximate solution.
+    pub solution: Vector<f64>,
+    /// Number of iterations that were done.
+    pub iterations: usize,
+    /// Euclidean norm of the residual `b - A * x` of the solution.
+    pub residual_norm: f64,
+    /// Whether the requested tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+/// Result of [Matrix::solve_checked], a solution together with estimates of its accuracy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport {
+    /// The solution.
+    pub solution: Vector<f64>,
+    /// Euclidean norm of the residual `A * x - b` of the solution.
+    pub residual_norm: f64,
+    /// Estimate of the condition number of the matrix in the 1-norm.
+    pub condition_estimate: f64,
+    /// Whether the estimated relative error of the solution, the condition estimate times the machine epsilon,
+    /// exceeds `1e-8`. Solutions with this warning should not be trusted without further checks.
+    pub accuracy_warning: bool,
 }
 
+/// Result of a LUP decomposition, see [Matrix::lupdecompose].
+type Lup<T> = (Matrix<T>, Vec<usize>);
+
 /// Represents a vector.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Vector<T> {
@@ -34,9 +126,11 @@ pub struct Vector<T> {
 /// mat_a[1] = [1,2,3];
 /// assert_eq!(mat_a, smatrix!{{2,0,0},{1,2,3},{0,0,2}});
 /// ```
+///
+/// The entries are stored inline, so creating and computing with an SMatrix does not allocate on the heap.
 #[derive(Debug, PartialEq, Clone)]
 pub struct SMatrix<T, const M: usize, const N: usize> {
-    data: Box<[[T; N]; M]>,
+    data: [[T; N]; M],
 }
 
 pub type SColVector<T, const N: usize> = SMatrix<T, N, 1>;
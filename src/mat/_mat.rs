@@ -1,3 +1,20 @@
+#[cfg(feature = "csv")]
+mod mat_csv;
+mod mat_decomp;
+mod mat_frozen;
 mod mat_impl;
+mod mat_integer;
+mod mat_iterative;
+mod mat_krylov;
+#[cfg(feature = "ndarray")]
+mod mat_ndarray;
 mod mat_ops;
+#[cfg(feature = "rayon")]
+mod mat_par;
+#[cfg(feature = "rand")]
+mod mat_rand;
+mod mat_rref;
+#[cfg(feature = "serde")]
+mod mat_serde;
+mod mat_sinkhorn;
 mod mat_traits;
@@ -0,0 +1,103 @@
+use crate::err::{CsvError, ParseMatrixError};
+use crate::mat::dims::Dimensions;
+use crate::mat::Matrix;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+/// CSV import and export, available with the `csv` feature.
+///
+/// Every matrix row is one CSV record. There is no header row, and surrounding whitespace of the fields is ignored
+/// when reading, like in [from_str](std::str::FromStr::from_str). Rows and columns in errors are counted from zero.
+impl<T> Matrix<T> {
+    /// Write the matrix to `w` as CSV, one record per row, formatting every entry with [Display].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::CsvError;
+    /// # fn main() -> Result<(), CsvError> {
+    /// let mut out = Vec::new();
+    /// matrix!{1.5, 2.0; -3.0, 4.25}.to_csv(&mut out)?;
+    /// assert_eq!(String::from_utf8(out).unwrap(), "1.5,2\n-3,4.25\n");
+    /// # Ok(()) }
+    /// ```
+    pub fn to_csv(&self, w: impl Write) -> Result<(), CsvError>
+    where
+        T: Display,
+    {
+        let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(w);
+        for row in self.matrix.chunks(self.cols()) {
+            writer.write_record(row.iter().map(|x| x.to_string()))?;
+        }
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+
+    /// Read a matrix from CSV data, one row per record, parsing every field with [FromStr].
+    ///
+    /// Returns [CsvError::Csv] if the data can't be read and [CsvError::Parse] with a [ParseMatrixError]
+    /// if there are no records, a record has a different number of fields than the first one,
+    /// or a field can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::{CsvError, ParseMatrixError};
+    /// # fn main() -> Result<(), CsvError> {
+    /// let mat_a: Matrix<i32> = Matrix::from_csv("1, 2, 3\n4, 5, 6\n".as_bytes())?;
+    /// assert_eq!(mat_a, matrix!{1, 2, 3; 4, 5, 6});
+    /// let ragged = Matrix::<i32>::from_csv("1,2\n3\n".as_bytes());
+    /// assert!(matches!(
+    ///     ragged,
+    ///     Err(CsvError::Parse(ParseMatrixError::RaggedRow { row: 1, expected: 2, found: 1 }))
+    /// ));
+    /// # Ok(()) }
+    /// ```
+    pub fn from_csv(r: impl Read) -> Result<Matrix<T>, CsvError>
+    where
+        T: FromStr,
+    {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(r);
+        let mut entries = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+        for record in reader.records() {
+            let record = record?;
+            if rows == 0 {
+                cols = record.len();
+            } else if record.len() != cols {
+                return Err(ParseMatrixError::RaggedRow {
+                    row: rows,
+                    expected: cols,
+                    found: record.len(),
+                }
+                .into());
+            }
+            for (j, field) in record.iter().enumerate() {
+                entries.push(field.parse().map_err(|_| ParseMatrixError::InvalidEntry {
+                    row: rows,
+                    col: j,
+                    token: field.to_owned(),
+                })?);
+            }
+            rows += 1;
+        }
+        if entries.is_empty() {
+            return Err(ParseMatrixError::Empty.into());
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows, cols),
+            matrix: entries,
+            label: None,
+        })
+    }
+}
@@ -0,0 +1,922 @@
+use crate::err::DimensionError;
+use crate::mat::float::ToFloat;
+use crate::mat::{Matrix, PivotStrategy, SolveReport, Vector};
+use num_traits::{Float, Inv, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+
+/// Solve `L * U * x = P * b` for the LUP decomposition `(lu, p)` of a matrix, see [Matrix::lupdecompose_with].
+fn lup_substitute<T: Float>(lu: &Matrix<T>, p: &[usize], b: &[T]) -> Vec<T> {
+    let n = lu.rows();
+    let mut x: Vec<T> = (0..n).map(|i| b[p[i]]).collect();
+    for i in 0..n {
+        for j in 0..i {
+            x[i] = x[i] - lu[i][j] * x[j];
+        }
+    }
+    for i in (0..n).rev() {
+        for j in (i + 1)..n {
+            x[i] = x[i] - lu[i][j] * x[j];
+        }
+        x[i] = x[i] / lu[i][i];
+    }
+    x
+}
+
+/// Solve the transposed system `A^T * x = b` for the LUP decomposition `(lu, p)` of `A`,
+/// that is `U^T * L^T * P * x = b`.
+fn lup_substitute_transposed(lu: &Matrix<f64>, p: &[usize], b: &[f64]) -> Vec<f64> {
+    let n = lu.rows();
+    let mut z = b.to_vec();
+    for i in 0..n {
+        for j in 0..i {
+            z[i] -= lu[j][i] * z[j];
+        }
+        z[i] /= lu[i][i];
+    }
+    for i in (0..n).rev() {
+        for j in (i + 1)..n {
+            z[i] -= lu[j][i] * z[j];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for (i, &row) in p.iter().take(n).enumerate() {
+        x[row] = z[i];
+    }
+    x
+}
+
+/// Decompositions of floating point matrices.
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Calculate the Cholesky decomposition of a symmetric positive-definite matrix.
+    ///
+    /// Returns the lower triangular matrix `L` with `L * L.transpose() == self`, or `None` if the matrix
+    /// is not positive-definite. A pivot counts as non-positive if it is not larger than
+    /// `T::epsilon()` times the corresponding diagonal entry.
+    ///
+    /// Only the lower triangle of `self` is read, so symmetry is assumed and not checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0};
+    /// let mat_l = matrix!{2.0, 0.0, 0.0; 6.0, 1.0, 0.0; -8.0, 5.0, 3.0};
+    /// assert_eq!(mat_a.cholesky()?, Some(mat_l));
+    /// assert_eq!(matrix!{1.0, 2.0; 2.0, 1.0}.cholesky()?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn cholesky(&self) -> Result<Option<Matrix<T>>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let mut l = Matrix::<T>::zero(dim, dim)?;
+        for j in 0..dim {
+            let mut d = self[j][j];
+            for k in 0..j {
+                d = d - l[j][k] * l[j][k];
+            }
+            if d <= T::epsilon() * self[j][j].abs() {
+                return Ok(None);
+            }
+            let l_jj = d.sqrt();
+            l[j][j] = l_jj;
+            for i in (j + 1)..dim {
+                let mut s = self[i][j];
+                for k in 0..j {
+                    s = s - l[i][k] * l[j][k];
+                }
+                l[i][j] = s / l_jj;
+            }
+        }
+        Ok(Some(l))
+    }
+
+    /// Calculate the thin QR decomposition of a matrix with at least as many rows as columns,
+    /// using Householder reflections.
+    ///
+    /// Returns `Q` (`m x n`) with orthonormal columns and the upper triangular `R` (`n x n`) with `Q * R == self`.
+    /// The diagonal of `R` is nonnegative. A matrix with fewer rows than columns gives
+    /// [DimensionError::InvalidInputDimensions] with the row and column count.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{3.0, 1.0; 4.0, 2.0; 0.0, 2.0};
+    /// let (q, r) = mat_a.qr()?;
+    /// assert_eq!(r[0][0], 5.0);
+    /// assert_eq!(r[1][0], 0.0);
+    /// let qr = (q * r)?;
+    /// for i in 0..3 {
+    ///     for j in 0..2 {
+    ///         assert!((qr[i][j] - mat_a[i][j]).abs() < 1e-12);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn qr(&self) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        let (m, n) = (self.rows(), self.cols());
+        if m < n {
+            return Err(DimensionError::InvalidInputDimensions(m, n));
+        }
+        let two = T::one() + T::one();
+        let mut a = self.clone();
+        let mut reflections: Vec<Vec<T>> = Vec::with_capacity(n);
+        for k in 0..n {
+            let norm = (k..m)
+                .fold(T::zero(), |acc, i| acc + a[i][k] * a[i][k])
+                .sqrt();
+            let alpha = if a[k][k] > T::zero() { -norm } else { norm };
+            let mut v: Vec<T> = (k..m).map(|i| a[i][k]).collect();
+            v[0] = v[0] - alpha;
+            let v_norm = v.iter().fold(T::zero(), |acc, x| acc + *x * *x).sqrt();
+            if v_norm > T::zero() {
+                v.iter_mut().for_each(|x| *x = *x / v_norm);
+                for j in k..n {
+                    let s = (k..m).fold(T::zero(), |acc, i| acc + v[i - k] * a[i][j]);
+                    for i in k..m {
+                        a[i][j] = a[i][j] - two * v[i - k] * s;
+                    }
+                }
+            }
+            reflections.push(v);
+        }
+
+        let mut q = Matrix::<T>::zero(m, n)?;
+        for j in 0..n {
+            q[j][j] = T::one();
+        }
+        for (k, v) in reflections.iter().enumerate().rev() {
+            for j in 0..n {
+                let s = (k..m).fold(T::zero(), |acc, i| acc + v[i - k] * q[i][j]);
+                for i in k..m {
+                    q[i][j] = q[i][j] - two * v[i - k] * s;
+                }
+            }
+        }
+
+        let mut r = Matrix::<T>::zero(n, n)?;
+        for i in 0..n {
+            for j in i..n {
+                r[i][j] = a[i][j];
+            }
+            if r[i][i] < T::zero() {
+                for j in i..n {
+                    r[i][j] = -r[i][j];
+                }
+                for row in 0..m {
+                    q[row][i] = -q[row][i];
+                }
+            }
+        }
+        Ok((q, r))
+    }
+
+    /// Solve the linear least squares problem `min ||self * x - b||` with a [QR decomposition](Matrix::qr).
+    ///
+    /// The matrix needs at least as many rows as columns and full column rank. If a diagonal entry of `R` is
+    /// not larger than `T::epsilon()` times the row count times the largest one, the matrix counts as rank deficient and
+    /// [DimensionError::InvalidValue] is returned. A `b` whose length is not the row count gives
+    /// [DimensionError::NoMatch].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // fit y = c + d * t through (0, 1), (1, 3), (2, 5)
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 0.0; 1.0, 1.0; 1.0, 2.0};
+    /// let x = mat_a.lstsq(&vector![1.0, 3.0, 5.0])?;
+    /// assert!((x[0] - 1.0).abs() < 1e-12);
+    /// assert!((x[1] - 2.0).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn lstsq(&self, b: &Vector<T>) -> Result<Vector<T>, DimensionError> {
+        if b.size() != self.rows() {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        let (q, r) = self.qr()?;
+        let n = self.cols();
+        let max_diag = (0..n).fold(T::zero(), |acc, i| acc.max(r[i][i]));
+        let tol = T::epsilon() * T::from(self.rows()).unwrap_or_else(T::one) * max_diag;
+        if (0..n).any(|i| r[i][i] <= tol) {
+            return Err(DimensionError::InvalidValue(
+                "the matrix is rank deficient".to_owned(),
+            ));
+        }
+        let mut x: Vec<T> = (0..n)
+            .map(|j| (0..self.rows()).fold(T::zero(), |acc, i| acc + q[i][j] * b[i]))
+            .collect();
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] = x[i] - r[i][j] * x[j];
+            }
+            x[i] = x[i] / r[i][i];
+        }
+        Ok(Vector::from(x))
+    }
+
+    /// Solve the square linear system `self * x = b` with a LUP decomposition with partial pivoting.
+    ///
+    /// Returns `None` if the matrix is singular, i.e. a column has no non-zero pivot. Returns
+    /// [DimensionError::NoSquare] for non-square matrices and [DimensionError::NoMatch] if the length of `b`
+    /// is not the row count. NaN and infinite entries are not detected, see [solve_checked](Matrix::solve_checked).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // x + 2y = 5, 3x + 4y = 11
+    /// let mat_a = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// let x = mat_a.solve(&vector![5.0, 11.0])?.unwrap();
+    /// assert!(x.approx_eq(&vector![1.0, 2.0], 1e-12));
+    /// assert_eq!(matrix!{1.0, 2.0; 2.0, 4.0}.solve(&vector![1.0, 2.0])?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Option<Vector<T>>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        if b.size() != self.rows() {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        let (lu, p) = match self.lupdecompose_with(PivotStrategy::Largest(|x: &T| {
+            x.abs().to_f64().unwrap_or(f64::NAN)
+        }))? {
+            Some(lup) => lup,
+            None => return Ok(None),
+        };
+        let b: Vec<T> = b.iter().copied().collect();
+        Ok(Some(Vector::from(lup_substitute(&lu, &p, &b))))
+    }
+}
+
+/// Orthogonalization.
+impl<T> Matrix<T>
+where
+    T: ToFloat,
+{
+    /// Calculate an orthonormal basis of the column space with the modified Gram-Schmidt process.
+    ///
+    /// The computation is done in the [floating point type](ToFloat::Float) of `T`. The columns of the result
+    /// are orthonormal and span the same space as the columns of `self`. They are in the order of the columns
+    /// they come from. A column that is linearly dependent on the previous ones (its component orthogonal to
+    /// them is smaller than `epsilon^(2/3)` times its norm) is skipped, so the result has [rank](Matrix::rank)
+    /// many columns. Every column is orthogonalized twice to keep the result orthonormal for ill-conditioned input.
+    ///
+    /// Returns `None` if all columns are zero, because a matrix can't have zero columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // the third column is the sum of the first two
+    /// let mat_a: Matrix<i32> = matrix!{3, 1, 4; 4, 2, 6; 0, 5, 5};
+    /// let q = mat_a.gram_schmidt().unwrap();
+    /// assert_eq!((q.rows(), q.cols()), (3, 2));
+    /// assert_eq!((q[0][0], q[1][0], q[2][0]), (0.6, 0.8, 0.0));
+    /// let gram = (q.transpose() * q)?;
+    /// assert!((gram[0][1]).abs() < 1e-12 && (gram[1][1] - 1.0).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn gram_schmidt(&self) -> Option<Matrix<T::Float>> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mat = self.map(T::to_float);
+        let tol = T::Float::epsilon().cbrt().powi(2);
+        let dot = |a: &[T::Float], b: &[T::Float]| {
+            a.iter()
+                .zip(b.iter())
+                .map(|(&x, &y)| x * y)
+                .sum::<T::Float>()
+        };
+        let mut basis: Vec<Vec<T::Float>> = Vec::new();
+        for j in 0..cols {
+            let mut v: Vec<T::Float> = (0..rows).map(|i| mat[i][j]).collect();
+            let norm = dot(&v, &v).sqrt();
+            for _ in 0..2 {
+                for q in basis.iter() {
+                    let c = dot(&v, q);
+                    v.iter_mut().zip(q.iter()).for_each(|(x, &y)| *x -= c * y);
+                }
+            }
+            let rest = dot(&v, &v).sqrt();
+            if rest > tol * norm {
+                basis.push(v.into_iter().map(|x| x / rest).collect());
+            }
+        }
+        if basis.is_empty() {
+            return None;
+        }
+        let entries = (0..rows)
+            .flat_map(|i| basis.iter().map(move |q| q[i]))
+            .collect();
+        Matrix::from_vec(rows, basis.len(), entries).ok()
+    }
+}
+
+/// The residual `I - A * X` of an approximate inverse `X` of the square matrix `A`.
+///
+/// Every entry is a compensated dot product: the rounding errors of the products (from a fused
+/// multiply-add) and of the sums are added up separately, so the result is about as accurate as in
+/// twice the precision of `F` (Ogita, Rump and Oishi, "Accurate sum and dot product", 2005).
+fn inverse_residual<F: Float>(mat_a: &Matrix<F>, mat_x: &Matrix<F>) -> Matrix<F> {
+    let n = mat_a.rows();
+    let mut res = Vec::with_capacity(n * n);
+    for i in 0..n {
+        for j in 0..n {
+            let mut sum = if i == j { F::one() } else { F::zero() };
+            let mut err = F::zero();
+            for k in 0..n {
+                let (a, x) = (-mat_a[i][k], mat_x[k][j]);
+                let prod = a * x;
+                let next = sum + prod;
+                let z = next - sum;
+                err = err + (sum - (next - z)) + (prod - z) + a.mul_add(x, -prod);
+                sum = next;
+            }
+            res.push(sum + err);
+        }
+    }
+    Matrix {
+        dims: mat_a.dims,
+        matrix: res,
+        label: None,
+    }
+}
+
+/// Iterative refinement of inverses.
+impl<T> Matrix<T>
+where
+    T: ToFloat,
+{
+    /// Improve an approximate inverse of `self` with Newton-Schulz iterations `X = X + X * (I - A * X)`.
+    ///
+    /// The computation is done in the [floating point type](ToFloat::Float) of `T`, but the residual
+    /// `I - A * X` is accumulated with compensated dot products, so the iteration also improves the result
+    /// of [Inv] for badly conditioned matrices. Every iteration costs two matrix multiplications. The iteration only converges if `approx_inv` is already close to the
+    /// inverse, i.e. if `I - A * approx_inv` has a spectral radius less than one.
+    ///
+    /// # Arguments
+    ///
+    /// * `approx_inv` - Approximate inverse of `self`
+    /// * `iterations` - Number of refinement steps
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i32> = matrix!{2, 0; 0, 4};
+    /// let approx_inv = matrix!{0.49, 0.0; 0.0, 0.26};
+    /// let mat_inv = mat_a.refine_inverse(&approx_inv, 5)?;
+    /// assert!((mat_inv[0][0] - 0.5).abs() < 1e-12);
+    /// assert!((mat_inv[1][1] - 0.25).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn refine_inverse(
+        &self,
+        approx_inv: &Matrix<T::Float>,
+        iterations: usize,
+    ) -> Result<Matrix<T::Float>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        if self.dims != approx_inv.dims {
+            return Err(self.no_match(approx_inv.dims, approx_inv.label(), "multiply"));
+        }
+        let mat_a = self.map(T::to_float);
+        let mut mat_x = approx_inv.clone();
+        for _ in 0..iterations {
+            let res = inverse_residual(&mat_a, &mat_x);
+            mat_x = (&mat_x + &(&mat_x * &res)?)?;
+        }
+        Ok(mat_x)
+    }
+
+    /// Invert a matrix with [Inv] and polish the result with `iterations` Newton-Schulz steps,
+    /// see [refine_inverse](Matrix::refine_inverse).
+    ///
+    /// Returns `None` if the matrix is not invertible.
+    pub fn inv_refined(
+        &self,
+        iterations: usize,
+    ) -> Result<Option<Matrix<T::Float>>, DimensionError> {
+        match self.map(T::to_float).inv()? {
+            Some(approx_inv) => Ok(Some(self.refine_inverse(&approx_inv, iterations)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Power of two closest to `1 / magnitude` in the exponent, or one for zero rows and columns.
+///
+/// The exponent is clamped to the range of normal numbers, so the factor of a subnormal magnitude
+/// doesn't overflow to infinity.
+fn scale_factor(magnitude: f64) -> f64 {
+    if magnitude == 0.0 {
+        1.0
+    } else {
+        let exp = -(magnitude.log2().round() as i32);
+        2f64.powi(exp.clamp(f64::MIN_EXP - 1, f64::MAX_EXP - 1))
+    }
+}
+
+/// Equilibration of badly scaled systems.
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// Scale the rows and columns of a matrix so that the largest absolute value in every row and column is close to one.
+    ///
+    /// Returns the scaled matrix `R * self * C` and the diagonals of `R` and `C` as column vectors.
+    /// Every scale factor is a power of two, so scaling changes no digits of the entries. The row factors
+    /// are chosen first, then the column factors for the row-scaled matrix. Rows and columns that are zero
+    /// have the factor one.
+    ///
+    /// Returns [DimensionError::InvalidValue] if an entry is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1024.0, 2048.0; 0.5, 0.125};
+    /// let (scaled, rows, cols) = mat_a.equilibrate()?;
+    /// assert_eq!(rows, vector![1.0 / 2048.0, 2.0]);
+    /// assert_eq!(cols, vector![1.0, 1.0]);
+    /// assert_eq!(scaled, matrix!{0.5, 1.0; 1.0, 0.25});
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn equilibrate(&self) -> Result<(Matrix<f64>, Vector<f64>, Vector<f64>), DimensionError> {
+        let mut mat = self.to_f64_matrix();
+        if mat.has_nonfinite() {
+            return Err(DimensionError::InvalidValue(
+                "the matrix contains NaN or infinite entries".to_owned(),
+            ));
+        }
+        let (rows, cols) = (mat.rows(), mat.cols());
+        let row_scale: Vec<f64> = (0..rows)
+            .map(|i| scale_factor(mat[i].iter().fold(0.0, |max, x| x.abs().max(max))))
+            .collect();
+        for (i, r) in row_scale.iter().enumerate() {
+            mat[i].iter_mut().for_each(|x| *x *= r);
+        }
+        let col_scale: Vec<f64> = (0..cols)
+            .map(|j| scale_factor((0..rows).fold(0.0, |max, i| mat[i][j].abs().max(max))))
+            .collect();
+        for i in 0..rows {
+            mat[i]
+                .iter_mut()
+                .zip(col_scale.iter())
+                .for_each(|(x, c)| *x *= c);
+        }
+        Ok((mat, Vector::from(row_scale), Vector::from(col_scale)))
+    }
+
+    /// [Solve](Matrix::solve) `self * x = b` after [equilibrating](Matrix::equilibrate) the matrix.
+    ///
+    /// The system `(R * self * C) * y = R * b` is solved and `x = C * y` is returned, so the scaling is invisible
+    /// to the caller. This keeps partial pivoting from choosing pivots in rows that are only large because of their
+    /// scale, which can lose all digits of the solution of a badly scaled system.
+    ///
+    /// Returns `None` if the matrix is singular, [DimensionError::NoSquare] for non-square matrices,
+    /// [DimensionError::NoMatch] if the length of `b` is not the row count and [DimensionError::InvalidValue]
+    /// if an entry of `self` is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // the first row is 1e10 x + 1e20 y = 1e20 + 1e10, the second row is x + y = 2
+    /// let mat_a = matrix!{1e10, 1e20; 1.0, 1.0};
+    /// let b = vector![1e20 + 1e10, 2.0];
+    /// let x = mat_a.solve_equilibrated(&b)?.unwrap();
+    /// assert!(x.approx_eq(&vector![1.0, 1.0], 1e-9));
+    /// # Ok(()) }
+    /// ```
+    pub fn solve_equilibrated(
+        &self,
+        b: &Vector<f64>,
+    ) -> Result<Option<Vector<f64>>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        if b.size() != self.rows() {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        let (scaled, row_scale, col_scale) = self.equilibrate()?;
+        let rhs: Vec<f64> = b.iter().zip(row_scale.iter()).map(|(x, r)| x * r).collect();
+        Ok(scaled.solve(&Vector::from(rhs))?.map(|y| {
+            Vector::from(
+                y.iter()
+                    .zip(col_scale.iter())
+                    .map(|(y, c)| y * c)
+                    .collect::<Vec<f64>>(),
+            )
+        }))
+    }
+}
+
+/// Weighted least squares.
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// Solve the weighted least squares problem `min Σ w_i * (a_i * x - b_i)^2`, where `a_i` is row `i` of `self`.
+    ///
+    /// Row `i` and `b_i` are scaled by `sqrt(w_i)` and the result is solved with [lstsq](Matrix::lstsq), so the
+    /// normal equations `A^T * W * A`, which square the condition number, are never formed. A weight of zero
+    /// ignores its observation, a weight of two counts it twice.
+    ///
+    /// Returns [DimensionError::NoMatch] if the length of `b` or `weights` is not the row count and
+    /// [DimensionError::InvalidValue] if a weight is negative, NaN or infinite or if the rows with non-zero weight
+    /// are rank deficient.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // fit y = c + d * t, the outlier (3, 0) has weight zero
+    /// let mat_a = matrix!{1.0, 0.0; 1.0, 1.0; 1.0, 2.0; 1.0, 3.0};
+    /// let b = vector![1.0, 3.0, 5.0, 0.0];
+    /// let x = mat_a.solve_weighted_least_squares(&b, &vector![1.0, 1.0, 1.0, 0.0])?;
+    /// assert!(x.approx_eq(&vector![1.0, 2.0], 1e-12));
+    /// # Ok(()) }
+    /// ```
+    pub fn solve_weighted_least_squares(
+        &self,
+        b: &Vector<f64>,
+        weights: &Vector<f64>,
+    ) -> Result<Vector<f64>, DimensionError> {
+        if b.size() != self.rows() {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        if weights.size() != self.rows() {
+            return Err(self.no_match(weights.dims, None, "weight"));
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(DimensionError::InvalidValue(
+                "weights need to be finite and non-negative".to_owned(),
+            ));
+        }
+        let roots: Vec<f64> = weights.iter().map(|w| w.sqrt()).collect();
+        let mut scaled = self.to_f64_matrix();
+        for (i, r) in roots.iter().enumerate() {
+            scaled[i].iter_mut().for_each(|x| *x *= r);
+        }
+        let rhs: Vec<f64> = b.iter().zip(roots.iter()).map(|(x, r)| x * r).collect();
+        scaled.lstsq(&Vector::from(rhs))
+    }
+}
+
+/// Estimated relative error above which [Matrix::solve_checked] sets [SolveReport::accuracy_warning].
+const ACCURACY_WARNING_THRESHOLD: f64 = 1e-8;
+
+/// Maximum number of steps of the 1-norm estimate of the inverse in [Matrix::solve_checked].
+const NORM_ESTIMATE_STEPS: usize = 5;
+
+/// Estimate the 1-norm of `A^-1` from the LUP decomposition `(lu, p)` of `A` with Hager's method.
+///
+/// Every step solves one system with `A` and one with `A^T`, so the estimate is much cheaper than the inverse.
+/// The result is a lower bound of the norm that is exact or within a small factor in practice.
+fn inverse_norm_estimate(lu: &Matrix<f64>, p: &[usize]) -> f64 {
+    let n = lu.rows();
+    let mut x = vec![1.0 / n as f64; n];
+    let mut estimate = 0.0;
+    for _ in 0..NORM_ESTIMATE_STEPS {
+        let y = lup_substitute(lu, p, &x);
+        estimate = y.iter().map(|v| v.abs()).sum();
+        let signs: Vec<f64> = y
+            .iter()
+            .map(|v| if *v < 0.0 { -1.0 } else { 1.0 })
+            .collect();
+        let z = lup_substitute_transposed(lu, p, &signs);
+        let (j, z_max) = z
+            .iter()
+            .map(|v| v.abs())
+            .enumerate()
+            .fold((0, 0.0), |max, (j, v)| if v > max.1 { (j, v) } else { max });
+        let z_x: f64 = z.iter().zip(x.iter()).map(|(a, b)| a * b).sum();
+        if z_max <= z_x {
+            break;
+        }
+        x = vec![0.0; n];
+        x[j] = 1.0;
+    }
+    estimate
+}
+
+/// Solving with accuracy reports.
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// [Solve](Matrix::solve) `self * x = b` and report how far the solution can be trusted.
+    ///
+    /// Besides the solution the [SolveReport] holds the norm of the residual `self * x - b` and an estimate of the
+    /// condition number in the 1-norm, which costs a few extra triangular solves. If the condition estimate times
+    /// the machine epsilon, an estimate of the relative error of the solution, is larger than `1e-8`, the
+    /// `accuracy_warning` is set and the solution is improved with one step of iterative refinement.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices, [DimensionError::NoMatch] if the length of `b`
+    /// is not the row count and [DimensionError::InvalidValue] if the matrix is singular or an entry of `self`
+    /// or `b` is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 1.0; 1.0, 3.0};
+    /// let report = mat_a.solve_checked(&vector![3.0, 5.0])?;
+    /// assert!(report.solution.approx_eq(&vector![0.8, 1.4], 1e-12));
+    /// assert!(report.residual_norm < 1e-12);
+    /// assert!(!report.accuracy_warning);
+    /// assert!(mat_a.solve_checked(&vector![1.0, f64::NAN]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn solve_checked(&self, b: &Vector<f64>) -> Result<SolveReport, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let n = self.rows();
+        if b.size() != n {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        let a = self.to_f64_matrix();
+        a.check_finite()?;
+        if b.iter().any(|x| !x.is_finite()) {
+            return Err(DimensionError::InvalidValue(
+                "the right-hand side contains NaN or infinite entries".to_owned(),
+            ));
+        }
+        let (lu, p) = match a.lupdecompose_with(PivotStrategy::Largest(|x: &f64| x.abs()))? {
+            Some(lup) => lup,
+            None => {
+                return Err(DimensionError::InvalidValue(
+                    "the matrix is singular".to_owned(),
+                ))
+            }
+        };
+        let residual = |x: &[f64]| -> Vec<f64> {
+            a.matrix
+                .chunks(n)
+                .zip(b.iter())
+                .map(|(row, b)| row.iter().zip(x.iter()).map(|(a, x)| a * x).sum::<f64>() - b)
+                .collect()
+        };
+        let b_entries: Vec<f64> = b.iter().copied().collect();
+        let mut x = lup_substitute(&lu, &p, &b_entries);
+
+        let a_norm = (0..n)
+            .map(|j| (0..n).map(|i| a[i][j].abs()).sum::<f64>())
+            .fold(0.0, f64::max);
+        let condition_estimate = a_norm * inverse_norm_estimate(&lu, &p);
+        let accuracy_warning = condition_estimate * f64::EPSILON > ACCURACY_WARNING_THRESHOLD
+            || condition_estimate.is_nan();
+        if accuracy_warning {
+            let correction = lup_substitute(&lu, &p, &residual(&x));
+            x.iter_mut().zip(correction).for_each(|(x, c)| *x -= c);
+        }
+
+        let residual_norm = Vector::from(residual(&x)).norm();
+        Ok(SolveReport {
+            solution: Vector::from(x),
+            residual_norm,
+            condition_estimate,
+            accuracy_warning,
+        })
+    }
+}
+
+/// Maximum number of sweeps over all off-diagonal entries in [Matrix::eigen_sym].
+const JACOBI_MAX_SWEEPS: usize = 100;
+
+/// Default relative tolerance of [Matrix::eigen_sym] for a `dim x dim` matrix.
+fn jacobi_tolerance<F: Float>(dim: usize) -> F {
+    F::epsilon() * F::from(dim.max(1)).unwrap_or_else(F::one)
+}
+
+/// Spectral decompositions.
+impl<T> Matrix<T>
+where
+    T: ToFloat,
+{
+    /// Calculate eigenvalues and eigenvectors of a symmetric matrix with the cyclic Jacobi method.
+    ///
+    /// The computation is done in the [floating point type](ToFloat::Float) of `T`. Returns the eigenvalues
+    /// in ascending order and an orthogonal matrix whose columns are the corresponding eigenvectors. The
+    /// rotations are applied until the off-diagonal norm is below `n * epsilon` times the Frobenius norm of
+    /// the `n x n` matrix, or at most 100 sweeps have been made. Use [eigen_sym_with](Matrix::eigen_sym_with)
+    /// for a different tolerance or number of sweeps.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices and [DimensionError::InvalidValue] for
+    /// matrices that are not symmetric up to that tolerance, so products like `B * B^T` that are only
+    /// symmetric up to rounding are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i32> = matrix!{2, 1; 1, 2};
+    /// let (values, vectors) = mat_a.eigen_sym()?;
+    /// assert!((values[0] - 1.0).abs() < 1e-12);
+    /// assert!((values[1] - 3.0).abs() < 1e-12);
+    /// assert!((vectors[0][1] - vectors[1][1]).abs() < 1e-12);
+    /// assert!(matrix!{1, 2; 3, 4}.eigen_sym().is_err());
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn eigen_sym(&self) -> Result<(Vector<T::Float>, Matrix<T::Float>), DimensionError> {
+        self.eigen_sym_with(JACOBI_MAX_SWEEPS, jacobi_tolerance(self.rows()))
+    }
+
+    /// Same as [eigen_sym](Matrix::eigen_sym), but with a custom maximum number of Jacobi sweeps and
+    /// tolerance. The iteration stops when the off-diagonal norm is below `tol` times the Frobenius norm
+    /// of the matrix. If that does not happen within `max_sweeps` sweeps, the current approximation is returned.
+    /// Entries `a[i][j]` and `a[j][i]` may also differ by `tol` times the Frobenius norm, see
+    /// [is_symmetric_approx](Matrix::is_symmetric_approx).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let (values, _) = matrix!{2.0_f64, 1.0; 1.0, 2.0}.eigen_sym_with(10, 1e-8)?;
+    /// assert!((values[1] - 3.0).abs() < 1e-8);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn eigen_sym_with(
+        &self,
+        max_sweeps: usize,
+        rel_tol: T::Float,
+    ) -> Result<(Vector<T::Float>, Matrix<T::Float>), DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let (zero, one) = (T::Float::zero(), T::Float::one());
+        let two = one + one;
+        let mut a = self.map(T::to_float);
+        let mut v = Matrix::<T::Float>::one(dim)?;
+        let frobenius = a.matrix.iter().map(|&x| x * x).sum::<T::Float>().sqrt();
+        let tol = rel_tol * frobenius;
+        if !a.is_symmetric_approx(tol) {
+            return Err(DimensionError::InvalidValue(
+                "the matrix is not symmetric".to_owned(),
+            ));
+        }
+
+        for _ in 0..max_sweeps {
+            let mut off = zero;
+            for p in 0..dim {
+                for q in 0..dim {
+                    if p != q {
+                        off += a[p][q] * a[p][q];
+                    }
+                }
+            }
+            if off.sqrt() <= tol {
+                break;
+            }
+
+            for p in 0..dim {
+                for q in (p + 1)..dim {
+                    if a[p][q] == zero {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+                    let c = one / (t * t + one).sqrt();
+                    let s = t * c;
+                    for k in 0..dim {
+                        let (a_kp, a_kq) = (a[k][p], a[k][q]);
+                        a[k][p] = c * a_kp - s * a_kq;
+                        a[k][q] = s * a_kp + c * a_kq;
+                    }
+                    for k in 0..dim {
+                        let (a_pk, a_qk) = (a[p][k], a[q][k]);
+                        a[p][k] = c * a_pk - s * a_qk;
+                        a[q][k] = s * a_pk + c * a_qk;
+                    }
+                    for k in 0..dim {
+                        let (v_kp, v_kq) = (v[k][p], v[k][q]);
+                        v[k][p] = c * v_kp - s * v_kq;
+                        v[k][q] = s * v_kp + c * v_kq;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..dim).collect();
+        order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap_or(Ordering::Equal));
+        let values: Vector<T::Float> = order.iter().map(|&i| a[i][i]).collect();
+        let mut vectors = Matrix::<T::Float>::zero(dim, dim)?;
+        for (col, &i) in order.iter().enumerate() {
+            for k in 0..dim {
+                vectors[k][col] = v[k][i];
+            }
+        }
+        Ok((values, vectors))
+    }
+
+    /// Approximate the dominant eigenvalue (the one with the largest absolute value) and a corresponding unit
+    /// eigenvector of a square matrix with the power iteration.
+    ///
+    /// The iteration stops as soon as the residual `||A * v - lambda * v||` is at most `tol * |lambda|`.
+    /// Returns `None` if that does not happen within `max_iter` iterations, which is the case for example
+    /// if there are two dominant eigenvalues of the same absolute value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 1.0; 0.0, 1.0};
+    /// let (lambda, v) = mat_a.power_iteration(1000, 1e-10)?.unwrap();
+    /// assert!((lambda - 2.0).abs() < 1e-9);
+    /// assert!(v[1].abs() < 1e-9);
+    /// # Ok(()) }
+    /// ```
+    pub fn power_iteration(
+        &self,
+        max_iter: usize,
+        tol: f64,
+    ) -> Result<Option<(f64, Vector<f64>)>, DimensionError>
+    where
+        T: ToPrimitive,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let a = self.to_f64_matrix();
+        // a start vector that is unlikely to be orthogonal to the dominant eigenvector
+        let mut v: Vec<f64> = (0..dim).map(|i| 1.0 + 1.0 / (i + 2) as f64).collect();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|x| *x /= norm);
+
+        for _ in 0..max_iter {
+            let w: Vec<f64> = a
+                .matrix
+                .chunks(dim)
+                .map(|row| row.iter().zip(v.iter()).map(|(x, y)| x * y).sum())
+                .collect();
+            let lambda: f64 = w.iter().zip(v.iter()).map(|(x, y)| x * y).sum();
+            let residual = w
+                .iter()
+                .zip(v.iter())
+                .map(|(x, y)| (x - lambda * y) * (x - lambda * y))
+                .sum::<f64>()
+                .sqrt();
+            if residual <= tol * lambda.abs() {
+                return Ok(Some((lambda, Vector::from(v))));
+            }
+            let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                return Ok(Some((0.0, Vector::from(v))));
+            }
+            v = w.into_iter().map(|x| x / norm).collect();
+        }
+        Ok(None)
+    }
+}
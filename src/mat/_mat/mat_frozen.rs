@@ -0,0 +1,126 @@
+use crate::err::DimensionError;
+use crate::mat::float::ToFloat;
+use crate::mat::{Frozen, Lup, Matrix};
+use num_traits::identities::{One, Zero};
+use num_traits::sign::Signed;
+#[cfg(test)]
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::ops::{Deref, DivAssign};
+
+impl<T> Matrix<T> {
+    /// Turn a matrix into a [Frozen] matrix, which caches determinant, inverse, rank and LUP decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let frozen = matrix!{1.0, 2.0; 3.0, 4.0}.freeze();
+    /// assert_eq!(frozen.det()?, -2.0);
+    /// assert_eq!(frozen.rank(), 2);
+    /// assert_eq!(frozen[1][0], 3.0);
+    /// assert_eq!(frozen.unfreeze(), matrix!{1.0, 2.0; 3.0, 4.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn freeze(self) -> Frozen<T> {
+        Frozen {
+            matrix: self,
+            lu: OnceCell::new(),
+            det: OnceCell::new(),
+            inverse: OnceCell::new(),
+            rank: OnceCell::new(),
+            #[cfg(test)]
+            factorizations: Cell::new(0),
+        }
+    }
+}
+
+impl<T> Frozen<T> {
+    /// Turn the frozen matrix back into a plain [Matrix], dropping all cached results.
+    pub fn unfreeze(self) -> Matrix<T> {
+        self.matrix
+    }
+}
+
+impl<T> Frozen<T>
+where
+    T: Clone + One + Zero + Signed + PartialOrd + std::iter::Sum,
+{
+    /// The LUP decomposition of the matrix, see [Matrix::lupdecompose].
+    pub fn lu(&self) -> Result<Option<&Lup<T>>, DimensionError> {
+        if let Some(lu) = self.lu.get() {
+            return Ok(lu.as_ref());
+        }
+        let lu = self.matrix.lupdecompose()?;
+        #[cfg(test)]
+        self.factorizations.set(self.factorizations.get() + 1);
+        Ok(self.lu.get_or_init(|| lu).as_ref())
+    }
+
+    /// The determinant of the matrix, see [Matrix::det].
+    pub fn det(&self) -> Result<T, DimensionError> {
+        if let Some(det) = self.det.get() {
+            return Ok(det.clone());
+        }
+        let det = match self.lu()? {
+            Some((mat, p)) => Matrix::det_from_lup(mat, p),
+            None => T::zero(),
+        };
+        Ok(self.det.get_or_init(|| det).clone())
+    }
+
+    /// The rank of the matrix, see [Matrix::rank].
+    pub fn rank(&self) -> usize
+    where
+        T: ToFloat,
+    {
+        *self.rank.get_or_init(|| self.matrix.rank())
+    }
+}
+
+impl<T> Frozen<T>
+where
+    T: Clone + One + Zero + Signed + PartialOrd + std::iter::Sum + DivAssign,
+{
+    /// The inverse of the matrix, or `None` if the matrix is not invertible. See [Inv](num_traits::Inv).
+    pub fn inverse(&self) -> Result<Option<&Matrix<T>>, DimensionError> {
+        if let Some(inverse) = self.inverse.get() {
+            return Ok(inverse.as_ref());
+        }
+        let inverse = self.lu()?.map(|(mat, p)| Matrix::inv_from_lup(mat, p));
+        Ok(self.inverse.get_or_init(|| inverse).as_ref())
+    }
+}
+
+/// A frozen matrix can be used like a reference to a [Matrix].
+impl<T> Deref for Frozen<T> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::err::DimensionError;
+    use crate::mat::Matrix;
+
+    #[test]
+    fn det_is_cached() -> Result<(), DimensionError> {
+        let frozen = matrix! {1.0, 2.0, 3.0; 3.0, 2.0, 1.0; 2.0, 1.0, 3.0}.freeze();
+        assert_eq!(frozen.factorizations.get(), 0);
+        let det_a = frozen.det()?;
+        let det_b = frozen.det()?;
+        assert_eq!(det_a, det_b);
+        assert_eq!(det_a, -12.0);
+        assert_eq!(frozen.factorizations.get(), 1);
+        frozen.inverse()?;
+        frozen.lu()?;
+        assert_eq!(frozen.factorizations.get(), 1);
+        Ok(())
+    }
+}
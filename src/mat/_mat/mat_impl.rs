@@ -1,8 +1,10 @@
-use crate::err::DimensionError;
+use crate::err::{DimensionError, MatrixError, Operand};
 use crate::mat::dims::Dimensions;
-use crate::mat::{Matrix, Vector};
-use num_traits::{sign, One, Zero};
+use crate::mat::float::ToFloat;
+use crate::mat::{Lup, Matrix, PivotStrategy, Vector};
+use num_traits::{sign, Float, Inv, One, ToPrimitive, Zero};
 use std::convert::From;
+use std::ops::{AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub};
 
 impl<T> Matrix<T>
 where
@@ -38,6 +40,7 @@ where
             Ok(Matrix::<T> {
                 dims: Dimensions::new(rows, cols),
                 matrix: vec![init; rows * cols],
+                label: None,
             })
         }
     }
@@ -73,10 +76,134 @@ where
             Ok(Matrix::<T> {
                 dims: Dimensions::new(rows, cols),
                 matrix: vec,
+                label: None,
             })
         }
     }
 
+    /// Create a new matrix from the first `rows x cols` items of an iterator.
+    ///
+    /// Entries are taken in row-major order. Any items left in the iterator after
+    /// `rows x cols` items have been consumed are ignored.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] if `rows` or `cols` is zero or `rows x cols` overflows `usize`,
+    /// and [DimensionError::InvalidInputDimensions] if the iterator yields fewer than `rows x cols` items.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - Row count of matrix
+    /// * `cols` - Column count of matrix
+    /// * `iter` - Iterator yielding at least `rows x cols` entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = Matrix::from_iter(2, 3, (1..).map(|x| x * 2))?;
+    /// assert_eq!(mat, matrix!{2, 4, 6; 8, 10, 12});
+    /// assert_eq!(
+    ///     Matrix::from_iter(2, 3, 0..5),
+    ///     Err(DimensionError::InvalidInputDimensions(5, 6))
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn from_iter<I>(rows: usize, cols: usize, iter: I) -> Result<Matrix<T>, DimensionError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = match rows.checked_mul(cols) {
+            Some(len) if len > 0 => len,
+            _ => return Err(DimensionError::InvalidDimensions),
+        };
+        let vec: Vec<T> = iter.into_iter().take(len).collect();
+        Matrix::from_vec(rows, cols, vec)
+    }
+
+    /// Collect an iterator into a matrix, checking that it yields exactly `rows x cols` entries.
+    ///
+    /// Entries are taken in row-major order. Unlike [from_iter](Matrix::from_iter), left over items are an error,
+    /// so the whole iterator is consumed and it needs to be finite.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] if `rows` or `cols` is zero or `rows x cols` overflows `usize`,
+    /// and [DimensionError::InvalidInputDimensions] with the number of items and `rows x cols` if they differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let doubled = Matrix::from_iter_with_dims(2, 2, mat_a.into_iter().map(|x| x * 2))?;
+    /// assert_eq!(doubled, matrix!{2, 4; 6, 8});
+    /// assert_eq!(
+    ///     Matrix::from_iter_with_dims(2, 2, 0..5),
+    ///     Err(DimensionError::InvalidInputDimensions(5, 4))
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn from_iter_with_dims<I>(
+        rows: usize,
+        cols: usize,
+        iter: I,
+    ) -> Result<Matrix<T>, DimensionError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if !matches!(rows.checked_mul(cols), Some(len) if len > 0) {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        Matrix::from_vec(rows, cols, iter.into_iter().collect())
+    }
+
+    /// Create a new matrix from an iterator of rows.
+    ///
+    /// The column count is inferred from the first row, every following row needs to have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// assert_eq!(mat, matrix!{1, 2; 3, 4; 5, 6});
+    /// assert_eq!(
+    ///     Matrix::from_rows(vec![vec![1, 2], vec![3]]),
+    ///     Err(DimensionError::InvalidInputDimensions(1, 2))
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Matrix<T>, DimensionError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut vec = Vec::<T>::new();
+        let mut row_count: usize = 0;
+        let mut cols: usize = 0;
+        for row in rows {
+            let len_before = vec.len();
+            vec.extend(row);
+            let row_len = vec.len() - len_before;
+            if row_count == 0 {
+                cols = row_len;
+            } else if row_len != cols {
+                return Err(DimensionError::InvalidInputDimensions(row_len, cols));
+            }
+            row_count += 1;
+        }
+        if row_count == 0 || cols == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        Matrix::from_vec(row_count, cols, vec)
+    }
+
     // pub fn insert_row(&mut self, at: usize, row: &[T]) -> Result<(), MatrixError> {
     //     if row.len() != self[0].len() {
     //         Err(MatrixError::IndexOutOfBounds)
@@ -121,6 +248,63 @@ where
         Ok(res)
     }
 
+    /// Create an identity matrix with the dimensions of the square matrix `self`, see [one](Matrix::one).
+    ///
+    /// Returns [DimensionError::NoSquare] if `self` is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{4, 7; 2, 6};
+    /// assert_eq!(mat_a.identity_like()?, Matrix::one(2)?);
+    /// assert_eq!(matrix!{1, 2, 3}.identity_like(), Err(DimensionError::NoSquare));
+    /// # Ok(()) }
+    /// ```
+    pub fn identity_like(&self) -> Result<Matrix<T>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        Matrix::one(self.rows())
+    }
+
+    /// Create a matrix with the dimensions of `self` where every entry is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(matrix!{4, 7, 1; 2, 6, 3}.zeros_like(), matrix!{0, 0, 0; 0, 0, 0});
+    /// ```
+    pub fn zeros_like(&self) -> Matrix<T> {
+        Matrix::<T> {
+            dims: self.dims,
+            matrix: vec![T::zero(); self.matrix.len()],
+            label: None,
+        }
+    }
+
+    /// Create a matrix with the dimensions of `self` where every entry is one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(matrix!{4.0; 2.0}.ones_like(), matrix!{1.0; 1.0});
+    /// ```
+    pub fn ones_like(&self) -> Matrix<T> {
+        Matrix::<T> {
+            dims: self.dims,
+            matrix: vec![T::one(); self.matrix.len()],
+            label: None,
+        }
+    }
+
     /// Create a zero-matrix of type `T`.
     ///
     /// # Arguments
@@ -179,61 +363,198 @@ where
         }
         Ok(res_mat)
     }
+
+    /// Calculate the LUP decomposition of a square matrix with partial pivoting.
+    ///
+    /// In every column the entry with the largest absolute value at or below the diagonal is chosen as pivot
+    /// and its row is swapped up. Returns `None` if a column has no non-zero pivot, so the matrix is singular.
+    /// Otherwise returns the matrix holding `L` (below the diagonal, with an implicit unit diagonal) and `U`,
+    /// together with the row permutation `p` of length `dim + 1`: row `i` of `L * U` is row `p[i]` of `self`,
+    /// and `p[dim] - dim` is the number of row swaps.
+    ///
+    /// For integer matrices the divisions truncate, so `L` and `U` are not exact in general.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1.0, 2.0; 4.0, 2.0};
+    /// let (lu, p) = mat_a.lupdecompose()?.unwrap();
+    /// assert_eq!(lu, matrix!{4.0, 2.0; 0.25, 1.5});
+    /// assert_eq!(p, vec![1, 0, 3]);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
     pub fn lupdecompose(&self) -> Result<Option<(Matrix<T>, Vec<usize>)>, DimensionError>
     where
         T: sign::Signed + PartialOrd + Clone + Zero + One + std::iter::Sum,
     {
         if !self.is_square() {
-            Err(DimensionError::NoSquare)
-        } else {
-            let mut a: Matrix<T> = self.clone();
-            let dim = self.rows();
-            let mut imax: usize;
-            let mut max_a: T;
-            let mut p: Vec<usize> = (0..=dim).collect();
-
-            for i in 0..dim {
-                max_a = T::zero();
-                imax = i;
-
-                for k in i..dim {
-                    if a[i][k].abs() > max_a {
-                        max_a = a[i][k].abs();
-                        imax = k;
-                    }
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        Ok(self.lup_eliminate(|a, i| {
+            let mut imax = i;
+            let mut max_a = T::zero();
+            for k in i..dim {
+                if a[k][i].abs() > max_a {
+                    max_a = a[k][i].abs();
+                    imax = k;
                 }
+            }
+            if max_a.is_zero() {
+                None
+            } else {
+                Some(imax)
+            }
+        }))
+    }
 
-                if max_a.is_zero() {
-                    return Ok(None);
-                }
+    /// Gaussian elimination of a square matrix into the LUP decomposition, shared by
+    /// [lupdecompose](Matrix::lupdecompose) and [lupdecompose_with](Matrix::lupdecompose_with).
+    ///
+    /// `pivot(a, i)` returns the row at or below `i` that is swapped up to eliminate column `i` of the
+    /// partially decomposed matrix `a`, or `None` if there is no pivot, so the matrix is singular.
+    fn lup_eliminate<P>(&self, pivot: P) -> Option<Lup<T>>
+    where
+        T: Sub<Output = T> + Div<Output = T>,
+        P: Fn(&Matrix<T>, usize) -> Option<usize>,
+    {
+        let mut a = self.clone();
+        a.label = None;
+        let dim = self.rows();
+        let mut p: Vec<usize> = (0..=dim).collect();
 
-                if imax != i {
-                    p.swap(i, imax);
+        for i in 0..dim {
+            let imax = pivot(&a, i)?;
 
-                    let mut t_ij: Matrix<T> = Matrix::one(self.rows()).unwrap();
-                    t_ij[i][i] = T::zero();
-                    t_ij[imax][imax] = T::zero();
-                    t_ij[i][imax] = T::one();
-                    t_ij[imax][i] = T::one();
-                    // switch rows i and imax
-                    a = (a * t_ij)?;
+            if imax != i {
+                p.swap(i, imax);
+                a.swap_rows(i, imax);
+                p[dim] += 1;
+            }
 
-                    p[dim] += 1;
+            for j in (i + 1)..dim {
+                a[j][i] = a[j][i].clone() / a[i][i].clone();
+                for k in (i + 1)..dim {
+                    a[j][k] = a[j][k].clone() - a[j][i].clone() * a[i][k].clone();
                 }
+            }
+        }
+        Some((a, p))
+    }
 
-                for j in (i + 1)..dim {
-                    a[j][i] = a[j][i].clone() / a[i][i].clone();
-                    for k in (i + 1)..dim {
-                        a[j][k] = a[j][k].clone() - a[j][i].clone() * a[i][k].clone();
-                    }
-                }
+    /// Raise a square matrix to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// Only `O(log(exp))` matrix multiplications are needed. `exp == 0` gives the identity matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let fib = matrix!{1, 1; 1, 0};
+    /// assert_eq!(fib.pow(10)?, matrix!{89, 55; 55, 34});
+    /// assert_eq!(fib.pow(0)?, Matrix::one(2)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn pow(&self, exp: u32) -> Result<Matrix<T>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let mut result = Matrix::one(self.rows())?;
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = (result * base.clone())?;
             }
-            Ok(Some((a, p)))
+            exp /= 2;
+            if exp > 0 {
+                base = (base.clone() * base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Calculate the LUP decomposition of a square matrix, choosing pivots with `strategy`.
+    ///
+    /// Returns `None` if no non-zero pivot can be found for a column, so the matrix is singular.
+    /// Otherwise returns the matrix holding `L` (below the diagonal, with an implicit unit diagonal) and `U`,
+    /// together with the row permutation `p` of length `dim + 1`, where `p[dim] - dim` is the number of row swaps.
+    ///
+    /// Unlike [lupdecompose](Matrix::lupdecompose) this only needs the field operations of `T`,
+    /// so it can be used with types like intervals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, PivotStrategy};
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{0.0, 2.0; 1.0, 3.0};
+    /// let (lu, p) = mat_a.lupdecompose_with(PivotStrategy::FirstNonZero)?.unwrap();
+    /// assert_eq!(lu, matrix!{1.0, 3.0; 0.0, 2.0});
+    /// assert_eq!(p, vec![1, 0, 3]);
+    /// # Ok(()) }
+    /// ```
+    pub fn lupdecompose_with(
+        &self,
+        strategy: PivotStrategy<T>,
+    ) -> Result<Option<Lup<T>>, DimensionError>
+    where
+        T: Sub<Output = T> + Div<Output = T>,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        Ok(self.lup_eliminate(|a, i| match strategy {
+            PivotStrategy::FirstNonZero => (i..dim).find(|&k| !a[k][i].is_zero()),
+            PivotStrategy::Largest(magnitude) => (i..dim)
+                .filter(|&k| !a[k][i].is_zero())
+                .max_by(|&k, &l| magnitude(&a[k][i]).total_cmp(&magnitude(&a[l][i]))),
+        }))
+    }
+
+    /// Calculate the determinant of a square matrix from a LUP decomposition with the given pivot strategy,
+    /// see [lupdecompose_with](Matrix::lupdecompose_with).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, PivotStrategy};
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1.0, 2.0, 3.0; 3.0, 2.0, 1.0; 2.0, 1.0, 3.0};
+    /// assert_eq!(mat.det_with(PivotStrategy::FirstNonZero)?, -12.0);
+    /// assert_eq!(mat.det_with(PivotStrategy::Largest(|x: &f64| x.abs()))?, -12.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn det_with(&self, strategy: PivotStrategy<T>) -> Result<T, DimensionError>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Neg<Output = T>,
+    {
+        if let Some((mat, p)) = self.lupdecompose_with(strategy)? {
+            Ok(Matrix::det_from_lup(&mat, &p))
+        } else {
+            Ok(T::zero())
         }
     }
 
     /// Calculate the determinant of a square matrix.
     ///
+    /// The determinant is calculated from a [LUP decomposition](Matrix::lupdecompose), which divides entries,
+    /// so this is meant for floating point matrices. For integer matrices the divisions truncate and the result
+    /// is wrong in general; use [det_bareiss](Matrix::det_bareiss) for them.
+    ///
     /// # Example
     ///
     /// ```
@@ -250,85 +571,719 @@ where
         T: sign::Signed + PartialOrd + std::iter::Sum,
     {
         if let Some((mat, p)) = self.lupdecompose()? {
-            let mut det = mat.matrix[0].clone();
-            for i in 1..mat.cols() {
-                det = det * mat.matrix[i * mat.cols() + i].clone();
-            }
-            if (p[mat.rows()] - mat.rows()) % 2 == 0 {
-                Ok(det)
-            } else {
-                Ok(-det)
-            }
+            Ok(Matrix::det_from_lup(&mat, &p))
         } else {
             Ok(T::zero())
         }
     }
 
-    /// Returns true if the matrix is a square matrix, false otherwise.
+    /// Calculate the determinant from the result of [lupdecompose](Matrix::lupdecompose).
+    #[allow(clippy::manual_is_multiple_of)]
+    pub(crate) fn det_from_lup(mat: &Matrix<T>, p: &[usize]) -> T
+    where
+        T: Neg<Output = T>,
+    {
+        let mut det = mat.matrix[0].clone();
+        for i in 1..mat.cols() {
+            det = det * mat.matrix[i * mat.cols() + i].clone();
+        }
+        if (p[mat.rows()] - mat.rows()) % 2 == 0 {
+            det
+        } else {
+            -det
+        }
+    }
+
+    /// Calculate the rank of a matrix, i.e. the number of linearly independent rows.
+    ///
+    /// For integer matrices the rank is found with the fraction-free [Bareiss algorithm](Matrix::det_bareiss),
+    /// where every division is exact, so it is exact as long as no intermediate value overflows `T`.
+    /// Floating point matrices are reduced with gaussian elimination with partial pivoting in
+    /// [ToFloat::Float], and a pivot counts as zero if it is at most `epsilon * max(rows, cols)` times the
+    /// largest absolute value in its column of `self`, so matrices that are singular up to rounding errors
+    /// get the lower rank.
     ///
     /// # Example
     ///
     /// ```
     /// # use libmat::mat::Matrix;
-    /// # use libmat::err::DimensionError;
-    /// # fn main() -> Result<(), DimensionError> {
-    /// let mat_a: Matrix<i32> = Matrix::one(3)?;
-    /// let mat_b: Matrix<f32> = Matrix::zero(3, 4)?;
-    /// assert_eq!(mat_a.is_square(), true);
-    /// assert_eq!(mat_b.is_square(), false);
-    /// # Ok(()) }
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2, 3; 2, 4, 6; 1, 0, 1};
+    /// assert_eq!(mat_a.rank(), 2);
+    /// assert_eq!(matrix!{0, 0; 0, 0}.rank(), 0);
+    /// assert_eq!(matrix!{0.1, 0.2, 0.3; 0.4, 0.5, 0.6; 0.7, 0.8, 0.9}.rank(), 2);
     /// ```
-    pub fn is_square(&self) -> bool {
-        self.dims.is_square()
+    pub fn rank(&self) -> usize
+    where
+        T: sign::Signed + PartialOrd + ToFloat,
+    {
+        if T::IS_FLOAT {
+            self.map(|x| x.to_float()).rank_with_tolerance()
+        } else {
+            self.rank_bareiss()
+        }
     }
 
-    /// Transpose a matrix.
+    /// The rank with fraction-free elimination, where every division by the previous pivot is exact.
+    fn rank_bareiss(&self) -> usize
+    where
+        T: sign::Signed + PartialOrd,
+    {
+        let mut a = self.clone();
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut prev = T::one();
+        let mut rank = 0;
+        for col in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let mut imax = rank;
+            for r in (rank + 1)..rows {
+                if a[r][col].abs() > a[imax][col].abs() {
+                    imax = r;
+                }
+            }
+            if a[imax][col].is_zero() {
+                continue;
+            }
+            a.swap_rows(rank, imax);
+            for r in (rank + 1)..rows {
+                for c in (col + 1)..cols {
+                    a[r][c] = (a[r][c].clone() * a[rank][col].clone()
+                        - a[r][col].clone() * a[rank][c].clone())
+                        / prev.clone();
+                }
+                a[r][col] = T::zero();
+            }
+            prev = a[rank][col].clone();
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Calculate the determinant of a square matrix with the Bareiss algorithm.
+    ///
+    /// This is fraction-free gaussian elimination: every division in it is exact, so for integer matrices all
+    /// intermediate values stay integers and the result is exact, as long as no entry overflows.
     ///
     /// # Example
     ///
     /// ```
     /// # use libmat::mat::Matrix;
     /// # use libmat::matrix;
-    /// let mat_a = matrix!{1, 2, 3, 4; 5, 6, 7, 8; 9, 10, 11, 12};
-    /// // 1  2  3  4
-    /// // 5  6  7  8
-    /// // 9 10 11 12
-    /// let mat_b = matrix!{1, 5, 9; 2, 6, 10; 3, 7, 11; 4, 8, 12};
-    /// // 1 5  9
-    /// // 2 6 10
-    /// // 3 7 11
-    /// // 4 8 12
-    /// assert_eq!(mat_a.transpose(), mat_b);
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i64> = matrix!{1, 2, 3; 3, 2, 1; 2, 1, 3};
+    /// assert_eq!(mat_a.det_bareiss()?, -12);
+    /// # Ok(()) }
     /// ```
-    pub fn transpose(&self) -> Matrix<T> {
-        let mut vec = Vec::<T>::new();
-        for i in 0..self.cols() {
-            for j in 0..self.rows() {
-                vec.push(self.matrix[j * self.cols() + i].clone());
+    pub fn det_bareiss(&self) -> Result<T, DimensionError>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Neg<Output = T>,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let mut a = self.clone();
+        let dim = self.rows();
+        let mut prev = T::one();
+        let mut negate = false;
+        for k in 0..dim - 1 {
+            if a[k][k].is_zero() {
+                match ((k + 1)..dim).find(|&r| !a[r][k].is_zero()) {
+                    Some(r) => {
+                        a.swap_rows(k, r);
+                        negate = !negate;
+                    }
+                    None => return Ok(T::zero()),
+                }
+            }
+            for i in (k + 1)..dim {
+                for j in (k + 1)..dim {
+                    a[i][j] = (a[i][j].clone() * a[k][k].clone()
+                        - a[i][k].clone() * a[k][j].clone())
+                        / prev.clone();
+                }
             }
+            prev = a[k][k].clone();
         }
-        Matrix::<T>::from_vec(self.cols(), self.rows(), vec).unwrap()
-    }
-}
-
-impl<T> From<Vector<T>> for Matrix<T>
-where
-    T: Zero + One + Clone,
-{
-    fn from(v: Vector<T>) -> Matrix<T> {
-        if v.is_row_vector() {
-            Matrix::<T>::from_vec(1, v.size(), v.entries).unwrap()
+        let det = a[dim - 1][dim - 1].clone();
+        if negate {
+            Ok(-det)
         } else {
-            Matrix::<T>::from_vec(v.size(), 1, v.entries).unwrap()
+            Ok(det)
         }
     }
-}
 
-// GETTERS
-impl<T> Matrix<T> {
-    /// Get the number of rows
-    pub fn rows(&self) -> usize {
-        self.dims.rows()
+    /// The matrix without row `i` and column `j`.
+    fn minor_matrix(&self, i: usize, j: usize) -> Matrix<T> {
+        let cols = self.cols();
+        Matrix::<T> {
+            dims: Dimensions::new(self.rows() - 1, cols - 1),
+            matrix: self
+                .matrix
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| idx / cols != i && idx % cols != j)
+                .map(|(_, x)| x.clone())
+                .collect(),
+            label: None,
+        }
+    }
+
+    /// Calculate the cofactor of the entry at row `i` and column `j` of a square matrix,
+    /// that is `(-1)^(i+j)` times the determinant of the matrix without row `i` and column `j`.
+    ///
+    /// The determinant is calculated with [det_bareiss](Matrix::det_bareiss), so it is exact for integer matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3; 0, 4, 5; 1, 0, 6};
+    /// assert_eq!(mat_a.cofactor(0, 0)?, 24);
+    /// assert_eq!(mat_a.cofactor(0, 1)?, 5);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn cofactor(&self, i: usize, j: usize) -> Result<T, DimensionError>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Neg<Output = T>,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        if i >= self.rows() {
+            panic!("Unreachable index: {}", i);
+        }
+        if j >= self.cols() {
+            panic!("Unreachable index: {}", j);
+        }
+        let minor = if self.rows() == 1 {
+            T::one()
+        } else {
+            self.minor_matrix(i, j).det_bareiss()?
+        };
+        if (i + j) % 2 == 0 {
+            Ok(minor)
+        } else {
+            Ok(-minor)
+        }
+    }
+
+    /// Calculate the adjugate of a square matrix, that is the transpose of its cofactor matrix.
+    ///
+    /// It satisfies `A * adj(A) = det(A) * I`, so for matrices with an invertible determinant the inverse is
+    /// `adj(A) / det(A)`. No division by entries is needed, so this also works for integer matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2, 1; 5, 3};
+    /// let adj = mat_a.adjugate()?;
+    /// assert_eq!(adj, matrix!{3, -1; -5, 2});
+    /// assert_eq!((mat_a * adj)?, Matrix::one(2)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn adjugate(&self) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Neg<Output = T>,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let mut adj = Matrix::<T> {
+            dims: self.dims,
+            matrix: Vec::with_capacity(dim * dim),
+            label: None,
+        };
+        for i in 0..dim {
+            for j in 0..dim {
+                adj.matrix.push(self.cofactor(j, i)?);
+            }
+        }
+        Ok(adj)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Transpose a matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2, 3, 4; 5, 6, 7, 8; 9, 10, 11, 12};
+    /// // 1  2  3  4
+    /// // 5  6  7  8
+    /// // 9 10 11 12
+    /// let mat_b = matrix!{1, 5, 9; 2, 6, 10; 3, 7, 11; 4, 8, 12};
+    /// // 1 5  9
+    /// // 2 6 10
+    /// // 3 7 11
+    /// // 4 8 12
+    /// assert_eq!(mat_a.transpose(), mat_b);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut vec = Vec::<T>::new();
+        for i in 0..self.cols() {
+            for j in 0..self.rows() {
+                vec.push(self.matrix[j * self.cols() + i].clone());
+            }
+        }
+        Matrix::<T> {
+            dims: Dimensions::new(self.cols(), self.rows()),
+            matrix: vec,
+            label: None,
+        }
+    }
+
+    /// Calculate the trace of a square matrix, the sum of its diagonal entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// assert_eq!(mat_a.trace()?, 15);
+    /// # Ok(()) }
+    /// ```
+    pub fn trace(&self) -> Result<T, DimensionError>
+    where
+        T: Zero,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        Ok((0..self.rows()).fold(T::zero(), |acc, i| {
+            acc + self.matrix[i * self.cols() + i].clone()
+        }))
+    }
+
+    /// Copy the entries of `self` into `target`, reusing the allocation of `target`.
+    ///
+    /// Returns [DimensionError::NoMatch] if `target` has different dimensions, `target` is unchanged then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let mut buffer = Matrix::zero(2, 2)?;
+    /// mat_a.clone_into(&mut buffer)?;
+    /// assert_eq!(buffer, mat_a);
+    /// # Ok(()) }
+    /// ```
+    pub fn clone_into(&self, target: &mut Matrix<T>) -> Result<(), DimensionError> {
+        if self.dims != target.dims {
+            return Err(self.no_match(target.dims, target.label(), "copy"));
+        }
+        target.matrix.clone_from_slice(&self.matrix);
+        Ok(())
+    }
+
+    /// Multiply every entry with `factor` and return the matrix, so calls can be chained.
+    ///
+    /// This works in place like [MulAssign], so the matrix keeps its [label](Matrix::with_label).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2; 3, 4}.scaled(2).offset(1);
+    /// assert_eq!(mat_a, matrix!{3, 5; 7, 9});
+    /// ```
+    pub fn scaled(mut self, factor: T) -> Matrix<T>
+    where
+        T: MulAssign,
+    {
+        self *= factor;
+        self
+    }
+
+    /// Add `delta` to every entry and return the matrix, so calls can be chained.
+    ///
+    /// This works in place like [AddAssign], so the matrix keeps its [label](Matrix::with_label).
+    pub fn offset(mut self, delta: T) -> Matrix<T>
+    where
+        T: AddAssign,
+    {
+        self += delta;
+        self
+    }
+
+    /// Multiply two matrices of the same dimensions entry by entry (Hadamard product).
+    ///
+    /// Returns [DimensionError::NoMatch] if the dimensions differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let gradient = matrix!{0.5, -1.5; 2.0, 0.25};
+    /// let mask = matrix!{1.0, 0.0; 0.0, 1.0};
+    /// assert_eq!(gradient.hadamard(&mask)?, matrix!{0.5, 0.0; 0.0, 0.25});
+    /// # Ok(()) }
+    /// ```
+    pub fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Mul<Output = T>,
+    {
+        if self.dims != other.dims {
+            return Err(self.no_match(other.dims, other.label(), "multiply entrywise"));
+        }
+        Ok(Matrix::<T> {
+            dims: self.dims,
+            matrix: self
+                .matrix
+                .iter()
+                .zip(other.matrix.iter())
+                .map(|(a, b)| a.clone() * b.clone())
+                .collect(),
+            label: None,
+        })
+    }
+
+    /// Kronecker product of two matrices.
+    ///
+    /// For a `m x n` matrix `self` and a `p x q` matrix `other` the result is the `(m*p) x (n*q)` block matrix,
+    /// where every entry `a[i][j]` of `self` is replaced by the block `a[i][j] * other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2};
+    /// let mat_b = matrix!{1, 0; 2, 3};
+    /// assert_eq!(mat_a.kronecker(&mat_b), matrix!{1, 0, 2, 0; 2, 3, 4, 6});
+    /// ```
+    pub fn kronecker(&self, other: &Matrix<T>) -> Matrix<T>
+    where
+        T: Mul<Output = T>,
+    {
+        let (rows, cols) = (self.rows() * other.rows(), self.cols() * other.cols());
+        Matrix::<T> {
+            dims: Dimensions::new(rows, cols),
+            matrix: (0..rows)
+                .flat_map(|r| {
+                    let (i, k) = (r / other.rows(), r % other.rows());
+                    self[i]
+                        .iter()
+                        .flat_map(move |a| other[k].iter().map(move |b| a.clone() * b.clone()))
+                })
+                .collect(),
+            label: None,
+        }
+    }
+}
+
+impl<T> From<Vector<T>> for Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    fn from(v: Vector<T>) -> Matrix<T> {
+        if v.is_row_vector() {
+            Matrix::<T>::from_vec(1, v.size(), v.entries).unwrap()
+        } else {
+            Matrix::<T>::from_vec(v.size(), 1, v.entries).unwrap()
+        }
+    }
+}
+
+/// Reshaping into vectors.
+impl<T> Matrix<T> {
+    /// Flatten the matrix into a column vector of its entries in row-major order.
+    ///
+    /// The entries are moved, not copied. Any label is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let vec_a = matrix!{1, 2, 3; 4, 5, 6}.into_vector();
+    /// assert_eq!(vec_a, vector![1, 2, 3, 4, 5, 6]);
+    /// assert!(vec_a.is_col_vector());
+    /// ```
+    pub fn into_vector(self) -> Vector<T> {
+        Vector::<T> {
+            dims: Dimensions::new(self.matrix.len(), 1),
+            entries: self.matrix,
+        }
+    }
+
+    /// Flatten the matrix into a row vector of its entries in row-major order,
+    /// like [into_vector](Matrix::into_vector).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let vec_a = matrix!{1, 2; 3, 4}.into_row_vector();
+    /// assert_eq!(vec_a, vector![1, 2, 3, 4].to_row_vector());
+    /// ```
+    pub fn into_row_vector(self) -> Vector<T> {
+        Vector::<T> {
+            dims: Dimensions::new(1, self.matrix.len()),
+            entries: self.matrix,
+        }
+    }
+}
+
+/// Stacking and splitting.
+///
+/// The results are new matrices without labels.
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Put the rows of `other` below the rows of `self`.
+    ///
+    /// Returns [DimensionError::NoMatch] if the matrices have a different number of columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// assert_eq!(mat_a.vstack(&matrix!{5, 6})?, matrix!{1, 2; 3, 4; 5, 6});
+    /// assert!(mat_a.vstack(&matrix!{5, 6, 7}).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn vstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        if self.cols() != other.cols() {
+            return Err(self.no_match(other.dims, other.label(), "vertically stack"));
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(self.rows() + other.rows(), self.cols()),
+            matrix: self
+                .matrix
+                .iter()
+                .chain(other.matrix.iter())
+                .cloned()
+                .collect(),
+            label: None,
+        })
+    }
+
+    /// Put the columns of `other` to the right of the columns of `self`.
+    ///
+    /// Returns [DimensionError::NoMatch] if the matrices have a different number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// assert_eq!(mat_a.hstack(&matrix!{5; 6})?, matrix!{1, 2, 5; 3, 4, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn hstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        if self.rows() != other.rows() {
+            return Err(self.no_match(other.dims, other.label(), "horizontally stack"));
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(self.rows(), self.cols() + other.cols()),
+            matrix: self
+                .row_iter()
+                .zip(other.row_iter())
+                .flat_map(|(left, right)| left.iter().chain(right.iter()).cloned())
+                .collect(),
+            label: None,
+        })
+    }
+
+    /// Split the matrix into the rows before `mid` and the rows from `mid` on.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] unless `0 < mid < rows`, because otherwise one of the
+    /// parts would have no rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4; 5, 6};
+    /// let (top, bottom) = mat_a.split_rows_at(1)?;
+    /// assert_eq!(top, matrix!{1, 2});
+    /// assert_eq!(bottom, matrix!{3, 4; 5, 6});
+    /// assert_eq!(mat_a.split_rows_at(3), Err(DimensionError::InvalidDimensions));
+    /// # Ok(()) }
+    /// ```
+    pub fn split_rows_at(&self, mid: usize) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        if mid == 0 || mid >= self.rows() {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let (top, bottom) = self.matrix.split_at(mid * self.cols());
+        Ok((
+            Matrix::<T> {
+                dims: Dimensions::new(mid, self.cols()),
+                matrix: top.to_vec(),
+                label: None,
+            },
+            Matrix::<T> {
+                dims: Dimensions::new(self.rows() - mid, self.cols()),
+                matrix: bottom.to_vec(),
+                label: None,
+            },
+        ))
+    }
+
+    /// Split the matrix into the columns before `mid` and the columns from `mid` on.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] unless `0 < mid < cols`, because otherwise one of the
+    /// parts would have no columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // the coefficients and the right-hand side of an augmented system
+    /// let augmented = matrix!{1, 2, 5; 3, 4, 6};
+    /// let (coefficients, rhs) = augmented.split_cols_at(2)?;
+    /// assert_eq!(coefficients, matrix!{1, 2; 3, 4});
+    /// assert_eq!(rhs, matrix!{5; 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn split_cols_at(&self, mid: usize) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        if mid == 0 || mid >= self.cols() {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for row in self.row_iter() {
+            let (l, r) = row.split_at(mid);
+            left.extend_from_slice(l);
+            right.extend_from_slice(r);
+        }
+        Ok((
+            Matrix::<T> {
+                dims: Dimensions::new(self.rows(), mid),
+                matrix: left,
+                label: None,
+            },
+            Matrix::<T> {
+                dims: Dimensions::new(self.rows(), self.cols() - mid),
+                matrix: right,
+                label: None,
+            },
+        ))
+    }
+
+    /// Iterate over consecutive blocks of `chunk` rows, from top to bottom.
+    ///
+    /// If `chunk` doesn't divide the number of rows, the last block has fewer rows. If `chunk` is larger
+    /// than the number of rows, the only block is the whole matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1; 2; 3; 4; 5};
+    /// let blocks: Vec<Matrix<i32>> = mat_a.chunks_rows(2).collect();
+    /// assert_eq!(blocks, vec![matrix!{1; 2}, matrix!{3; 4}, matrix!{5}]);
+    /// ```
+    pub fn chunks_rows(&self, chunk: usize) -> impl Iterator<Item = Matrix<T>> + '_ {
+        assert!(chunk > 0, "Chunk size cannot be zero.");
+        let cols = self.cols();
+        // a chunk that doesn't fit into `usize` entries takes all rows
+        self.matrix
+            .chunks(chunk.saturating_mul(cols))
+            .map(move |rows| Matrix::<T> {
+                dims: Dimensions::new(rows.len() / cols, cols),
+                matrix: rows.to_vec(),
+                label: None,
+            })
+    }
+}
+
+/// Rank of floating point matrices.
+impl<F> Matrix<F>
+where
+    F: Float,
+{
+    /// The rank with gaussian elimination, where a pivot counts as zero if it is at most
+    /// `epsilon * max(rows, cols)` times the largest absolute value in its column.
+    fn rank_with_tolerance(mut self) -> usize {
+        let (rows, cols) = (self.rows(), self.cols());
+        let scale = F::epsilon() * F::from(rows.max(cols)).unwrap();
+        let mut rank = 0;
+        for col in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let tolerance = (0..rows).fold(F::zero(), |m, r| m.max(self[r][col].abs())) * scale;
+            let mut imax = rank;
+            for r in (rank + 1)..rows {
+                if self[r][col].abs() > self[imax][col].abs() {
+                    imax = r;
+                }
+            }
+            if self[imax][col].is_nan() || self[imax][col].abs() <= tolerance {
+                continue;
+            }
+            self.swap_rows(rank, imax);
+            for r in (rank + 1)..rows {
+                let factor = self[r][col] / self[rank][col];
+                for c in col..cols {
+                    let sub = factor * self[rank][c];
+                    self[r][c] = self[r][c] - sub;
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+}
+
+// GETTERS
+impl<T> Matrix<T> {
+    /// Get the number of rows
+    pub fn rows(&self) -> usize {
+        self.dims.rows()
     }
 
     /// Get the number of columns
@@ -336,19 +1291,917 @@ impl<T> Matrix<T> {
         self.dims.cols()
     }
 
-    pub fn dims(&self) -> Dimensions {
-        Dimensions::new(self.rows(), self.cols())
+    pub fn dims(&self) -> Dimensions {
+        Dimensions::new(self.rows(), self.cols())
+    }
+
+    /// Get the number of rows and columns as a tuple, which is handy for pattern matching.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// let (rows, cols) = mat_a.shape();
+    /// assert_eq!((rows, cols), (2, 3));
+    /// let kind = match mat_a.shape() {
+    ///     (1, 1) => "scalar",
+    ///     (r, c) if r == c => "square",
+    ///     _ => "rectangular",
+    /// };
+    /// assert_eq!(kind, "rectangular");
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        self.dims.as_tuple()
+    }
+
+    /// Iterate over the rows of the matrix as slices, from top to bottom.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// let rows: Vec<&[i32]> = mat_a.row_iter().collect();
+    /// assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+    /// ```
+    pub fn row_iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.matrix.chunks(self.cols())
+    }
+
+    /// Iterate over the columns of the matrix, from left to right.
+    ///
+    /// The entries of a column are not contiguous in memory, so every column is cloned into a `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// let cols: Vec<Vec<i32>> = mat_a.col_iter().collect();
+    /// assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    /// ```
+    pub fn col_iter(&self) -> impl Iterator<Item = Vec<T>> + '_
+    where
+        T: Clone,
+    {
+        (0..self.cols()).map(move |j| {
+            self.matrix
+                .iter()
+                .skip(j)
+                .step_by(self.cols())
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns true if the matrix is a square matrix, false otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i32> = Matrix::one(3)?;
+    /// let mat_b: Matrix<f32> = Matrix::zero(3, 4)?;
+    /// assert_eq!(mat_a.is_square(), true);
+    /// assert_eq!(mat_b.is_square(), false);
+    /// # Ok(()) }
+    /// ```
+    pub fn is_square(&self) -> bool {
+        self.dims.is_square()
+    }
+
+    /// Returns true if the matrix is square and equal to its transpose, false otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 2; 2, 3}.is_symmetric());
+    /// assert!(!matrix!{1, 2; 3, 4}.is_symmetric());
+    /// assert!(!matrix!{1, 2, 3; 2, 1, 3}.is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.is_square()
+            && (0..self.rows()).all(|i| {
+                (0..i).all(|j| self.matrix[i * self.cols() + j] == self.matrix[j * self.cols() + i])
+            })
+    }
+
+    /// Check whether all entries for which `outside(row, col)` is true are zero.
+    fn zero_outside(&self, outside: impl Fn(usize, usize) -> bool) -> bool
+    where
+        T: Zero,
+    {
+        let cols = self.cols();
+        self.matrix
+            .iter()
+            .enumerate()
+            .all(|(k, x)| !outside(k / cols, k % cols) || x.is_zero())
+    }
+
+    /// Returns true if all entries off the main diagonal are zero, false otherwise.
+    ///
+    /// The matrix doesn't need to be square, the main diagonal of a non-square matrix are the entries `a[i][i]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 0; 0, 3}.is_diagonal());
+    /// assert!(matrix!{1, 0, 0; 0, 2, 0}.is_diagonal());
+    /// assert!(!matrix!{1, 0; 2, 3}.is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool
+    where
+        T: Zero,
+    {
+        self.zero_outside(|i, j| i != j)
+    }
+
+    /// Returns true if all entries below the main diagonal are zero, false otherwise.
+    ///
+    /// Non-square matrices are checked the same way, so an upper trapezoidal matrix counts as upper triangular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 2; 0, 3}.is_upper_triangular());
+    /// assert!(!matrix!{1, 2; 4, 3}.is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool
+    where
+        T: Zero,
+    {
+        self.zero_outside(|i, j| i > j)
+    }
+
+    /// Returns true if all entries above the main diagonal are zero, false otherwise.
+    ///
+    /// Non-square matrices are checked the same way, so a lower trapezoidal matrix counts as lower triangular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 0; 2, 3}.is_lower_triangular());
+    /// assert!(!matrix!{1, 2; 0, 3}.is_lower_triangular());
+    /// ```
+    pub fn is_lower_triangular(&self) -> bool
+    where
+        T: Zero,
+    {
+        self.zero_outside(|i, j| i < j)
+    }
+
+    /// Give the matrix a name, which is used in its [Display](std::fmt::Display) output and in the
+    /// [DimensionError]s of operations it is an operand of.
+    ///
+    /// The label is kept by [clone](Clone::clone), but results of operations don't have a label,
+    /// and it is ignored when comparing matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let stiffness = Matrix::new(3, 4, 1.0).unwrap().with_label("stiffness");
+    /// let load = Matrix::new(3, 4, 1.0).unwrap().with_label("load");
+    /// let err = (stiffness * load).unwrap_err();
+    /// assert!(err.to_string().contains("Cannot multiply `stiffness` (3x4) with `load` (3x4)"));
+    /// ```
+    pub fn with_label(mut self, name: &str) -> Matrix<T> {
+        self.label = Some(name.to_owned());
+        self
+    }
+
+    /// The label of the matrix, see [with_label](Matrix::with_label).
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Error for an operation `op` with an operand with dimensions `dims` and an optional label,
+    /// which doesn't fit to `self`. Labeled operands give a [DimensionError::LabeledNoMatch].
+    pub(crate) fn no_match(
+        &self,
+        dims: Dimensions,
+        label: Option<&str>,
+        op: &str,
+    ) -> DimensionError {
+        if self.label.is_none() && label.is_none() {
+            DimensionError::NoMatch(self.dims, dims, op.to_owned())
+        } else {
+            DimensionError::LabeledNoMatch(
+                Operand {
+                    dims: self.dims,
+                    label: self.label.clone(),
+                },
+                Operand {
+                    dims,
+                    label: label.map(str::to_owned),
+                },
+                op.to_owned(),
+            )
+        }
+    }
+
+    pub fn entry(&self, i: impl Into<usize>, j: impl Into<usize>) -> T
+    where
+        T: Clone,
+    {
+        self.matrix[self.cols() * i.into() + j.into()].clone()
+    }
+
+    pub fn entry_mut(&mut self, i: impl Into<usize>, j: impl Into<usize>) -> &mut T {
+        let cols = self.cols();
+        &mut self.matrix[cols * i.into() + j.into()]
+    }
+
+    /// Swap two rows of a matrix in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the indices is not a valid row index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mut mat_a = matrix!{1, 2; 3, 4; 5, 6};
+    /// mat_a.swap_rows(0, 2);
+    /// assert_eq!(mat_a, matrix!{5, 6; 3, 4; 1, 2});
+    /// ```
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        if i >= self.rows() || j >= self.rows() {
+            panic!("Unreachable index: {}", i.max(j));
+        }
+        if i != j {
+            let cols = self.cols();
+            let (lo, hi) = (i.min(j), i.max(j));
+            let (head, tail) = self.matrix.split_at_mut(hi * cols);
+            head[lo * cols..lo * cols + cols].swap_with_slice(&mut tail[..cols]);
+        }
+    }
+
+    /// Check that row `i` exists and is not the only row, before it is removed.
+    fn check_row_removal(&self, i: usize) -> Result<(), MatrixError> {
+        if i >= self.rows() {
+            Err(MatrixError::IndexOutOfBounds(i))
+        } else if self.rows() == 1 {
+            Err(MatrixError::WouldBeEmpty)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Remove row `i` and return it as a row vector, moving the last row into its place.
+    ///
+    /// Only the last row is moved, so this takes `O(cols)` time, but it changes the order of the rows,
+    /// see [remove_row_ordered](Matrix::remove_row_ordered) for a variant that keeps it.
+    ///
+    /// Returns [MatrixError::IndexOutOfBounds] if there is no row `i` and [MatrixError::WouldBeEmpty]
+    /// if it is the only row, the matrix is unchanged then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::MatrixError;
+    /// # fn main() -> Result<(), MatrixError> {
+    /// let mut mat_a = matrix!{1, 2; 3, 4; 5, 6};
+    /// assert_eq!(mat_a.take_row(0)?, vector![1, 2].to_row_vector());
+    /// assert_eq!(mat_a, matrix!{5, 6; 3, 4});
+    /// # Ok(()) }
+    /// ```
+    pub fn take_row(&mut self, i: usize) -> Result<Vector<T>, MatrixError> {
+        self.check_row_removal(i)?;
+        let last = self.rows() - 1;
+        self.swap_rows(i, last);
+        let entries = self.matrix.split_off(last * self.cols());
+        self.dims = Dimensions::new(last, self.cols());
+        Ok(Vector::<T> {
+            dims: Dimensions::new(1, entries.len()),
+            entries,
+        })
+    }
+
+    /// Remove row `i` and return it as a row vector, keeping the order of the other rows.
+    ///
+    /// All rows after `i` are moved up, so this takes `O(rows * cols)` time. Returns the same errors
+    /// as [take_row](Matrix::take_row).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::MatrixError;
+    /// # fn main() -> Result<(), MatrixError> {
+    /// let mut mat_a = matrix!{1, 2; 3, 4; 5, 6};
+    /// assert_eq!(mat_a.remove_row_ordered(0)?, vector![1, 2].to_row_vector());
+    /// assert_eq!(mat_a, matrix!{3, 4; 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn remove_row_ordered(&mut self, i: usize) -> Result<Vector<T>, MatrixError> {
+        self.check_row_removal(i)?;
+        let cols = self.cols();
+        let entries: Vec<T> = self.matrix.drain(i * cols..(i + 1) * cols).collect();
+        self.dims = Dimensions::new(self.rows() - 1, cols);
+        Ok(Vector::<T> {
+            dims: Dimensions::new(1, cols),
+            entries,
+        })
+    }
+
+    /// Return [MatrixError::IndexOutOfBounds] for the first index that is not less than `bound`
+    /// and [MatrixError::WouldBeEmpty] if there are no indices.
+    fn check_selection(indices: &[usize], bound: usize) -> Result<(), MatrixError> {
+        if let Some(&i) = indices.iter().find(|&&i| i >= bound) {
+            Err(MatrixError::IndexOutOfBounds(i))
+        } else if indices.is_empty() {
+            Err(MatrixError::WouldBeEmpty)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Build a new matrix from the rows at `indices`, in the given order.
+    ///
+    /// Indices may repeat, so this also expresses row permutations and resampling.
+    /// Returns [MatrixError::IndexOutOfBounds] for an index that is not a row and [MatrixError::WouldBeEmpty]
+    /// if `indices` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::MatrixError;
+    /// # fn main() -> Result<(), MatrixError> {
+    /// let mat_a = matrix!{1, 2; 3, 4; 5, 6};
+    /// assert_eq!(mat_a.select_rows(&[2, 0, 2])?, matrix!{5, 6; 1, 2; 5, 6});
+    /// assert_eq!(mat_a.select_rows(&[3]), Err(MatrixError::IndexOutOfBounds(3)));
+    /// # Ok(()) }
+    /// ```
+    pub fn select_rows(&self, indices: &[usize]) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Clone,
+    {
+        Self::check_selection(indices, self.rows())?;
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(indices.len(), self.cols()),
+            matrix: indices
+                .iter()
+                .flat_map(|&i| self[i].iter().cloned())
+                .collect(),
+            label: None,
+        })
+    }
+
+    /// Build a new matrix from the columns at `indices`, in the given order.
+    ///
+    /// Indices may repeat, so this also expresses column permutations and feature subsets.
+    /// Returns [MatrixError::IndexOutOfBounds] for an index that is not a column and [MatrixError::WouldBeEmpty]
+    /// if `indices` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::MatrixError;
+    /// # fn main() -> Result<(), MatrixError> {
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.select_cols(&[2, 0, 0])?, matrix!{3, 1, 1; 6, 4, 4});
+    /// assert_eq!(mat_a.select_cols(&[]), Err(MatrixError::WouldBeEmpty));
+    /// # Ok(()) }
+    /// ```
+    pub fn select_cols(&self, indices: &[usize]) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Clone,
+    {
+        Self::check_selection(indices, self.cols())?;
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(self.rows(), indices.len()),
+            matrix: self
+                .matrix
+                .chunks(self.cols())
+                .flat_map(|row| indices.iter().map(move |&j| row[j].clone()))
+                .collect(),
+            label: None,
+        })
+    }
+
+    /// Build a new matrix from all columns except the ones at `indices`, keeping their order.
+    ///
+    /// Indices may repeat and be in any order. Returns [MatrixError::IndexOutOfBounds] for an index that is not
+    /// a column and [MatrixError::WouldBeEmpty] if every column would be dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::MatrixError;
+    /// # fn main() -> Result<(), MatrixError> {
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.drop_cols(&[1])?, matrix!{1, 3; 4, 6});
+    /// assert_eq!(mat_a.drop_cols(&[0, 1, 2]), Err(MatrixError::WouldBeEmpty));
+    /// # Ok(()) }
+    /// ```
+    pub fn drop_cols(&self, indices: &[usize]) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Clone,
+    {
+        if let Some(&j) = indices.iter().find(|&&j| j >= self.cols()) {
+            return Err(MatrixError::IndexOutOfBounds(j));
+        }
+        let kept: Vec<usize> = (0..self.cols()).filter(|j| !indices.contains(j)).collect();
+        self.select_cols(&kept)
     }
 
-    pub fn entry(&self, i: impl Into<usize>, j: impl Into<usize>) -> T
+    /// Frobenius norm of a matrix, the square root of the sum of all squared entries,
+    /// computed in the [floating point type](ToFloat) of the entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(matrix!{1, 2; 2, 4}.frobenius_norm(), 5.0);
+    /// let norm: f32 = matrix!{1.0_f32, 2.0; 2.0, 4.0}.frobenius_norm();
+    /// assert_eq!(norm, 5.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> T::Float
     where
-        T: Clone,
+        T: ToFloat,
     {
-        self.matrix[self.cols() * i.into() + j.into()].clone()
+        self.matrix
+            .iter()
+            .map(|x| {
+                let x = x.to_float();
+                x * x
+            })
+            .sum::<T::Float>()
+            .sqrt()
     }
 
-    pub fn entry_mut(&mut self, i: impl Into<usize>, j: impl Into<usize>) -> &mut T {
+    /// Sums of the entries of each row, as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.row_sums(), vector![6, 15]);
+    /// ```
+    pub fn row_sums(&self) -> Vector<T>
+    where
+        T: Zero + Clone,
+    {
+        self.matrix
+            .chunks(self.cols())
+            .map(|row| row.iter().fold(T::zero(), |acc, x| acc + x.clone()))
+            .collect()
+    }
+
+    /// Sums of the entries of each column, as a row vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.col_sums(), vector![5, 7, 9].to_row_vector());
+    /// ```
+    pub fn col_sums(&self) -> Vector<T>
+    where
+        T: Zero + Clone,
+    {
+        let mut sums = vec![T::zero(); self.cols()];
+        for row in self.matrix.chunks(self.cols()) {
+            for (sum, x) in sums.iter_mut().zip(row.iter()) {
+                *sum = sum.clone() + x.clone();
+            }
+        }
+        Vector::from(sums).to_row_vector()
+    }
+
+    /// Apply `f` to every entry and collect the results into a matrix of the same dimensions.
+    ///
+    /// The entries are visited in row-major order. The result has no label.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a: Matrix<i32> = matrix!{1, -2; 3, -4};
+    /// assert_eq!(mat_a.map(|x| f64::from(*x) / 2.0), matrix!{0.5, -1.0; 1.5, -2.0});
+    /// assert_eq!(mat_a.map(|x| x.abs()), matrix!{1, 2; 3, 4});
+    /// let positive = mat_a.map(|x| *x > 0);
+    /// assert_eq!((positive[0][0], positive[0][1]), (true, false));
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Matrix::<U> {
+            dims: self.dims,
+            matrix: self.matrix.iter().map(f).collect(),
+            label: None,
+        }
+    }
+
+    /// Convert every entry to `f64`, entries that can't be represented become `NaN`.
+    pub(crate) fn to_f64_matrix(&self) -> Matrix<f64>
+    where
+        T: ToPrimitive,
+    {
+        self.map(|x| x.to_f64().unwrap_or(f64::NAN))
+    }
+}
+
+/// Approximate comparison of floating point matrices.
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Check whether all entries of `self` and `other` differ by at most `epsilon`.
+    ///
+    /// Returns `false` if the dimensions differ or an entry is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{0.1 + 0.2, 1.0; 2.0, 3.0};
+    /// let mat_b = matrix!{0.3, 1.0; 2.0, 3.0};
+    /// assert_ne!(mat_a, mat_b);
+    /// assert!(mat_a.approx_eq(&mat_b, 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<T>, epsilon: T) -> bool {
+        self.dims == other.dims
+            && self
+                .matrix
+                .iter()
+                .zip(other.matrix.iter())
+                .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Returns true if the matrix is square and every entry differs from its mirrored entry by at most `epsilon`.
+    ///
+    /// Like [is_symmetric](Matrix::is_symmetric), but for matrices that are only symmetric up to round-off.
+    /// Returns `false` if an off-diagonal entry is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{2.0, 0.1 + 0.2; 0.3, 1.0};
+    /// assert!(!mat_a.is_symmetric());
+    /// assert!(mat_a.is_symmetric_approx(1e-12));
+    /// ```
+    pub fn is_symmetric_approx(&self, epsilon: T) -> bool {
+        self.is_square()
+            && (0..self.rows()).all(|i| (0..i).all(|j| (self[i][j] - self[j][i]).abs() <= epsilon))
+    }
+
+    /// Check whether all entries of `self` and `other` differ by at most `epsilon` times the larger of their absolute values.
+    ///
+    /// Unlike [approx_eq](Matrix::approx_eq) this works for entries of any magnitude, but entries close to zero
+    /// need to be (almost) exactly equal. Returns `false` if the dimensions differ or an entry is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1e20, 1.0};
+    /// let mat_b = matrix!{1e20 + 1e6, 1.0};
+    /// assert!(mat_a.approx_eq_relative(&mat_b, 1e-12));
+    /// assert!(!mat_a.approx_eq(&mat_b, 1e-12));
+    /// ```
+    pub fn approx_eq_relative(&self, other: &Matrix<T>, epsilon: T) -> bool {
+        self.dims == other.dims
+            && self
+                .matrix
+                .iter()
+                .zip(other.matrix.iter())
+                .all(|(a, b)| (*a - *b).abs() <= epsilon * a.abs().max(b.abs()))
+    }
+
+    /// Remove rounding noise from a matrix that should be the identity, like `A * A^-1`.
+    ///
+    /// Diagonal entries within `tolerance` of one are set to exactly one and all other entries within `tolerance`
+    /// of zero are set to exactly zero. Entries further away are kept, so a result that is not the identity
+    /// shows that the input wasn't close to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let noisy = matrix!{1.0 + 1e-15, -2e-16; 3e-17, 1.0 - 4e-16};
+    /// assert_eq!(noisy.snap_to_identity(1e-12), Matrix::one(2)?);
+    /// assert_eq!(matrix!{1.0, 0.5; 0.0, 1.0}.snap_to_identity(1e-12), matrix!{1.0, 0.5; 0.0, 1.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn snap_to_identity(&self, tolerance: T) -> Matrix<T> {
         let cols = self.cols();
-        &mut self.matrix[cols * i.into() + j.into()]
+        Matrix::<T> {
+            dims: self.dims,
+            matrix: self
+                .matrix
+                .iter()
+                .enumerate()
+                .map(|(idx, &x)| {
+                    let target = if idx / cols == idx % cols {
+                        T::one()
+                    } else {
+                        T::zero()
+                    };
+                    if (x - target).abs() <= tolerance {
+                        target
+                    } else {
+                        x
+                    }
+                })
+                .collect(),
+            label: None,
+        }
+    }
+}
+
+/// Checked numerical routines for floating point matrices.
+///
+/// NaN and infinite entries propagate silently through elimination, so these variants reject them up front
+/// with [DimensionError::InvalidValue] instead of returning a meaningless result.
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Check whether any entry is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(!matrix!{1.0, 2.0; 3.0, 4.0}.has_nonfinite());
+    /// assert!(matrix!{1.0, f64::NAN; 3.0, 4.0}.has_nonfinite());
+    /// assert!(matrix!{f64::NEG_INFINITY}.has_nonfinite());
+    /// ```
+    pub fn has_nonfinite(&self) -> bool {
+        self.matrix.iter().any(|x| !x.is_finite())
+    }
+
+    /// Return [DimensionError::InvalidValue] if any entry is NaN or infinite.
+    pub(crate) fn check_finite(&self) -> Result<(), DimensionError> {
+        if self.has_nonfinite() {
+            Err(DimensionError::InvalidValue(
+                "the matrix contains NaN or infinite entries".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Calculate the [determinant](Matrix::det), failing with [DimensionError::InvalidValue] if an entry is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(matrix!{2.0, 0.0; 0.0, 3.0}.det_checked()?, 6.0);
+    /// assert!(matrix!{2.0, f64::INFINITY; 0.0, 3.0}.det_checked().is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn det_checked(&self) -> Result<T, DimensionError>
+    where
+        T: sign::Signed + std::iter::Sum,
+    {
+        self.check_finite()?;
+        self.det()
+    }
+
+    /// Calculate the [inverse](Matrix::inv), failing with [DimensionError::InvalidValue] if an entry is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(matrix!{2.0, 0.0; 0.0, 4.0}.inv_checked()?, Some(matrix!{0.5, 0.0; 0.0, 0.25}));
+    /// assert!(matrix!{2.0, 0.0; f64::NAN, 4.0}.inv_checked().is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn inv_checked(&self) -> Result<Option<Matrix<T>>, DimensionError>
+    where
+        T: sign::Signed + std::iter::Sum + DivAssign,
+    {
+        self.check_finite()?;
+        self.clone().inv()
+    }
+
+    /// Check whether a square matrix is singular up to round-off.
+    ///
+    /// Singular floating point matrices rarely have a determinant of exactly zero, so instead the matrix is
+    /// decomposed with [partial pivoting](Matrix::lupdecompose) and reported as singular if the smallest pivot is at
+    /// most `tolerance` times the largest absolute entry. Scaling the matrix doesn't change the result.
+    ///
+    /// This is a test of the condition of the matrix relative to its largest entry, not of its rank. A
+    /// well-conditioned but badly scaled matrix like `diag(1, 1e-12)` counts as singular for any `tolerance` of
+    /// at least `1e-12`. [Equilibrate](Matrix::equilibrate) such matrices first to compare every row at its own
+    /// scale.
+    ///
+    /// Returns [DimensionError::NoSquare] if the matrix is not square and [DimensionError::InvalidValue] if an entry
+    /// is NaN or infinite or if `tolerance` is negative or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // the second row is twice the first one, up to round-off
+    /// let mat_a = matrix!{0.1, 0.3; 0.2, 0.6 + 1e-15};
+    /// assert_ne!(mat_a.det()?, 0.0);
+    /// assert!(mat_a.is_singular(1e-12)?);
+    /// assert!(!matrix!{1.0, 2.0; 3.0, 4.0}.is_singular(1e-12)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn is_singular(&self, tolerance: T) -> Result<bool, DimensionError>
+    where
+        T: sign::Signed + std::iter::Sum,
+    {
+        if tolerance.is_nan() || tolerance < T::zero() {
+            return Err(DimensionError::InvalidValue(
+                "the tolerance must not be negative or NaN".to_owned(),
+            ));
+        }
+        self.check_finite()?;
+        let (lu, _) = match self.lupdecompose()? {
+            Some(lup) => lup,
+            None => return Ok(true),
+        };
+        let largest = self.matrix.iter().fold(T::zero(), |m, x| m.max(x.abs()));
+        let smallest_pivot = (0..lu.rows())
+            .map(|i| lu[i][i].abs())
+            .fold(T::infinity(), T::min);
+        Ok(smallest_pivot <= tolerance * largest)
+    }
+}
+
+/// NaN-aware reductions of floating point matrices.
+///
+/// Comparisons with NaN are always false and arithmetic with NaN gives NaN, so a single missing value
+/// silently spoils a minimum, maximum or mean. These variants skip NaN entries instead.
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Check whether any entry is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1.0, f64::NAN; 3.0, 4.0}.has_nan());
+    /// assert!(!matrix!{1.0, f64::INFINITY; 3.0, 4.0}.has_nan());
+    /// ```
+    pub fn has_nan(&self) -> bool {
+        self.matrix.iter().any(|x| x.is_nan())
+    }
+
+    /// Return a copy of the matrix where every NaN entry is replaced with `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1.0, f64::NAN; f64::NAN, 4.0};
+    /// assert_eq!(mat_a.replace_nan(0.0), matrix!{1.0, 0.0; 0.0, 4.0});
+    /// ```
+    pub fn replace_nan(&self, value: T) -> Matrix<T> {
+        self.map(|x| if x.is_nan() { value } else { *x })
+    }
+
+    fn non_nan(&self) -> impl Iterator<Item = T> + '_ {
+        self.matrix.iter().copied().filter(|x| !x.is_nan())
+    }
+
+    /// Smallest entry that is not NaN, or `None` if all entries are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(matrix!{3.0, f64::NAN; -1.0, 4.0}.nan_min(), Some(-1.0));
+    /// assert_eq!(matrix!{f64::NAN}.nan_min(), None);
+    /// ```
+    pub fn nan_min(&self) -> Option<T> {
+        self.non_nan().reduce(T::min)
+    }
+
+    /// Largest entry that is not NaN, or `None` if all entries are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(matrix!{3.0, f64::NAN; -1.0, 4.0}.nan_max(), Some(4.0));
+    /// ```
+    pub fn nan_max(&self) -> Option<T> {
+        self.non_nan().reduce(T::max)
+    }
+
+    /// Arithmetic mean of the entries that are not NaN, together with the number of NaN entries that were skipped.
+    ///
+    /// The mean is `None` if all entries are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(matrix!{1.0, f64::NAN; 2.0, 6.0}.nan_mean(), (Some(3.0), 1));
+    /// assert_eq!(matrix!{f64::NAN, f64::NAN}.nan_mean(), (None, 2));
+    /// ```
+    pub fn nan_mean(&self) -> (Option<T>, usize) {
+        let (sum, count) = self
+            .non_nan()
+            .fold((T::zero(), 0_usize), |(sum, count), x| (sum + x, count + 1));
+        let skipped = self.matrix.len() - count;
+        if count == 0 {
+            (None, skipped)
+        } else {
+            (T::from(count).map(|count| sum / count), skipped)
+        }
+    }
+}
+
+/// Integer powers of floating point matrices.
+impl<T> Matrix<T>
+where
+    T: Float + sign::Signed + std::iter::Sum + DivAssign,
+{
+    /// Raise a square matrix to an integer power, where negative exponents raise the [inverse](Matrix::inv).
+    ///
+    /// Non-negative exponents are the same as [pow](Matrix::pow). For `exp < 0` the matrix is inverted once
+    /// and the inverse is raised to `-exp`, so `None` is returned if the matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 0.0; 0.0, 4.0};
+    /// assert_eq!(mat_a.powi(2)?, Some(matrix!{4.0, 0.0; 0.0, 16.0}));
+    /// assert_eq!(mat_a.powi(-2)?, Some(matrix!{0.25, 0.0; 0.0, 0.0625}));
+    /// assert_eq!(matrix!{1.0, 2.0; 2.0, 4.0}.powi(-1)?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn powi(&self, exp: i32) -> Result<Option<Matrix<T>>, DimensionError> {
+        if exp >= 0 {
+            return self.pow(exp.unsigned_abs()).map(Some);
+        }
+        match self.clone().inv()? {
+            Some(mat_inv) => Ok(Some(mat_inv.pow(exp.unsigned_abs())?)),
+            None => Ok(None),
+        }
     }
 }
@@ -0,0 +1,266 @@
+use crate::err::DimensionError;
+use crate::mat::{Matrix, Vector};
+use std::convert::TryFrom;
+
+/// Dense matrix with `i128` entries that integer normal forms work on, so intermediate values have room to grow.
+type Wide = Vec<Vec<i128>>;
+
+fn identity(dim: usize) -> Wide {
+    (0..dim)
+        .map(|i| (0..dim).map(|j| i128::from(i == j)).collect())
+        .collect()
+}
+
+/// Subtract `factor` times row `src` from row `dst`.
+fn sub_row(mat: &mut Wide, dst: usize, src: usize, factor: i128) {
+    for j in 0..mat[dst].len() {
+        mat[dst][j] -= factor * mat[src][j];
+    }
+}
+
+/// Subtract `factor` times column `src` from column `dst`.
+fn sub_col(mat: &mut Wide, dst: usize, src: usize, factor: i128) {
+    for row in mat.iter_mut() {
+        row[dst] -= factor * row[src];
+    }
+}
+
+fn negate_row(mat: &mut Wide, row: usize) {
+    mat[row].iter_mut().for_each(|x| *x = -*x);
+}
+
+fn swap_cols(mat: &mut Wide, a: usize, b: usize) {
+    for row in mat.iter_mut() {
+        row.swap(a, b);
+    }
+}
+
+/// Bring `d` into row-style Hermite normal form, applying the same row operations to `u`.
+/// Returns the number of non-zero rows.
+fn hermite(d: &mut Wide, u: &mut Wide) -> usize {
+    let (rows, cols) = (d.len(), d[0].len());
+    let mut row = 0;
+    for col in 0..cols {
+        if row == rows {
+            break;
+        }
+        loop {
+            // the non-zero entry of smallest absolute value in the column becomes the pivot
+            let pivot = (row..rows)
+                .filter(|&i| d[i][col] != 0)
+                .min_by_key(|&i| d[i][col].abs());
+            let pi = match pivot {
+                Some(i) => i,
+                None => break,
+            };
+            d.swap(row, pi);
+            u.swap(row, pi);
+            for i in (row + 1)..rows {
+                let q = d[i][col] / d[row][col];
+                sub_row(d, i, row, q);
+                sub_row(u, i, row, q);
+            }
+            if ((row + 1)..rows).all(|i| d[i][col] == 0) {
+                break;
+            }
+        }
+        if d[row][col] == 0 {
+            continue;
+        }
+        if d[row][col] < 0 {
+            negate_row(d, row);
+            negate_row(u, row);
+        }
+        for i in 0..row {
+            let q = d[i][col].div_euclid(d[row][col]);
+            sub_row(d, i, row, q);
+            sub_row(u, i, row, q);
+        }
+        row += 1;
+    }
+    row
+}
+
+/// Convert back to `i64`, failing with [DimensionError::InvalidValue] if an entry doesn't fit.
+fn narrow(mat: Wide) -> Result<Matrix<i64>, DimensionError> {
+    let (rows, cols) = (mat.len(), mat[0].len());
+    let entries = mat
+        .into_iter()
+        .flatten()
+        .map(i64::try_from)
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|_| DimensionError::InvalidValue("an entry overflows i64".to_owned()))?;
+    Matrix::from_vec(rows, cols, entries)
+}
+
+/// Integer normal forms.
+///
+/// The computations are done with `i128` entries, so intermediate values can be much larger than the entries of
+/// the input. Entries still grow quickly for larger matrices, and if an entry of a result doesn't fit into `i64`,
+/// [DimensionError::InvalidValue] is returned. An `i128` overflow during the computation panics in debug builds.
+impl Matrix<i64> {
+    fn to_wide(&self) -> Wide {
+        self.matrix
+            .chunks(self.cols())
+            .map(|row| row.iter().map(|&x| i128::from(x)).collect())
+            .collect()
+    }
+
+    /// Calculate the Smith normal form `D` of an integer matrix.
+    ///
+    /// Returns `(U, D, V)` with `U * self * V == D`, where `U` and `V` are unimodular (square integer matrices
+    /// with determinant `1` or `-1`) and `D` has the dimensions of `self`. `D` is zero outside its diagonal,
+    /// the diagonal entries are non-negative and each one divides the next, so the non-zero ones come first.
+    ///
+    /// Every step uses the entry of smallest absolute value as the pivot, which keeps the entries small.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i64> = matrix!{2, 4, 4; -6, 6, 12; 10, -4, -16};
+    /// let (u, d, v) = mat_a.smith_normal_form()?;
+    /// assert_eq!(d, matrix!{2, 0, 0; 0, 6, 0; 0, 0, 12});
+    /// assert_eq!(((u * mat_a)? * v)?, d);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn smith_normal_form(
+        &self,
+    ) -> Result<(Matrix<i64>, Matrix<i64>, Matrix<i64>), DimensionError> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut d = self.to_wide();
+        let mut u = identity(rows);
+        let mut v = identity(cols);
+
+        for t in 0..rows.min(cols) {
+            loop {
+                // move the non-zero entry of smallest absolute value to (t, t)
+                let pivot = (t..rows)
+                    .flat_map(|i| (t..cols).map(move |j| (i, j)))
+                    .filter(|&(i, j)| d[i][j] != 0)
+                    .min_by_key(|&(i, j)| d[i][j].abs());
+                let (pi, pj) = match pivot {
+                    Some(p) => p,
+                    None => return Ok((narrow(u)?, narrow(d)?, narrow(v)?)),
+                };
+                d.swap(t, pi);
+                u.swap(t, pi);
+                swap_cols(&mut d, t, pj);
+                swap_cols(&mut v, t, pj);
+
+                let p = d[t][t];
+                for i in (t + 1)..rows {
+                    let q = d[i][t] / p;
+                    sub_row(&mut d, i, t, q);
+                    sub_row(&mut u, i, t, q);
+                }
+                for j in (t + 1)..cols {
+                    let q = d[t][j] / p;
+                    sub_col(&mut d, j, t, q);
+                    sub_col(&mut v, j, t, q);
+                }
+                // remainders are smaller than the pivot, so they become the next pivot
+                if ((t + 1)..rows).any(|i| d[i][t] != 0) || ((t + 1)..cols).any(|j| d[t][j] != 0) {
+                    continue;
+                }
+                // the pivot has to divide all remaining entries, otherwise a row with a remainder is added
+                match ((t + 1)..rows).find(|&i| ((t + 1)..cols).any(|j| d[i][j] % p != 0)) {
+                    Some(i) => {
+                        sub_row(&mut d, t, i, -1);
+                        sub_row(&mut u, t, i, -1);
+                    }
+                    None => break,
+                }
+            }
+            if d[t][t] < 0 {
+                negate_row(&mut d, t);
+                negate_row(&mut u, t);
+            }
+        }
+        Ok((narrow(u)?, narrow(d)?, narrow(v)?))
+    }
+
+    /// Calculate the row-style Hermite normal form `H` of an integer matrix.
+    ///
+    /// Returns `(H, U)` with `U * self == H`, where `U` is unimodular. `H` is in row echelon form, the first non-zero
+    /// entry (pivot) of every row is positive and the entries above a pivot are non-negative and smaller than it.
+    /// Zero rows come last. The Hermite normal form is unique, but `U` is only unique for matrices of full row rank.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i64> = matrix!{3, 3, 1, 4; 0, 1, 0, 0; 0, 0, 19, 16; 0, 0, 0, 3};
+    /// let (h, u) = mat_a.hermite_normal_form()?;
+    /// assert_eq!(h, matrix!{3, 0, 1, 1; 0, 1, 0, 0; 0, 0, 19, 1; 0, 0, 0, 3});
+    /// assert_eq!((u * mat_a)?, h);
+    /// # Ok(()) }
+    /// ```
+    pub fn hermite_normal_form(&self) -> Result<(Matrix<i64>, Matrix<i64>), DimensionError> {
+        let mut h = self.to_wide();
+        let mut u = identity(self.rows());
+        hermite(&mut h, &mut u);
+        Ok((narrow(h)?, narrow(u)?))
+    }
+
+    /// Find an integer solution `x` of `self * x = b`.
+    ///
+    /// Returns `None` if there is no solution with integer entries, even if there is a rational one.
+    /// If there are many solutions, one of them is returned. The solution is found with the
+    /// [Hermite normal form](Matrix::hermite_normal_form) of the transpose, so the same overflow caveats apply.
+    ///
+    /// Returns [DimensionError::NoMatch] if the length of `b` is not the row count.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // 6x + 10y + 15z = 1 has integer solutions, 2x + 4y = 1 has none
+    /// let mat_a: Matrix<i64> = matrix!{6, 10, 15};
+    /// let x = mat_a.solve_integer(&vector![1])?.unwrap();
+    /// assert_eq!(6 * x[0] + 10 * x[1] + 15 * x[2], 1);
+    /// assert_eq!(matrix!{2, 4}.solve_integer(&vector![1])?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn solve_integer(&self, b: &Vector<i64>) -> Result<Option<Vector<i64>>, DimensionError> {
+        if b.size() != self.rows() {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        // U * A^T = H, so A * U^T = H^T, and x = U^T * y for a solution y of H^T * y = b
+        let mut h = self.transpose().to_wide();
+        let mut u = identity(self.cols());
+        let rank = hermite(&mut h, &mut u);
+        let b: Vec<i128> = b.iter().map(|&x| i128::from(x)).collect();
+
+        let mut y = vec![0_i128; self.cols()];
+        for k in 0..rank {
+            let pivot_col = (0..self.rows()).find(|&j| h[k][j] != 0).unwrap();
+            let rest: i128 = (0..k).map(|l| h[l][pivot_col] * y[l]).sum();
+            let rhs = b[pivot_col] - rest;
+            if rhs % h[k][pivot_col] != 0 {
+                return Ok(None);
+            }
+            y[k] = rhs / h[k][pivot_col];
+        }
+        // the equations without a pivot have to hold as well
+        if (0..self.rows()).any(|j| (0..rank).map(|k| h[k][j] * y[k]).sum::<i128>() != b[j]) {
+            return Ok(None);
+        }
+        let x = (0..self.cols())
+            .map(|i| (0..self.cols()).map(|k| u[k][i] * y[k]).sum::<i128>())
+            .map(i64::try_from)
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|_| DimensionError::InvalidValue("an entry overflows i64".to_owned()))?;
+        Ok(Some(Vector::from(x)))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::err::DimensionError;
+use crate::mat::precond::Preconditioner;
+use crate::mat::{IterativeSolution, Matrix, Vector};
+use num_traits::ToPrimitive;
+
+/// Preconditioner that does nothing, used for the plain solvers.
+struct Identity;
+
+impl Preconditioner for Identity {
+    fn apply(&self, r: &Vector<f64>) -> Result<Vector<f64>, DimensionError> {
+        Ok(r.clone())
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Iterative solvers for linear systems.
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// Solve `self * x = b` for a symmetric positive-definite matrix with the conjugate gradient method.
+    ///
+    /// Starts at `x = 0` and stops as soon as the residual norm is at most `tol` times the norm of `b`,
+    /// or after `max_iter` iterations. The result tells whether the tolerance was reached.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices and [DimensionError::NoMatch] if `b`
+    /// has the wrong length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{4.0, 1.0; 1.0, 3.0};
+    /// let result = mat_a.conjugate_gradient(&vector![1.0, 2.0], 1e-12, 10)?;
+    /// assert!(result.converged);
+    /// assert!((result.solution[0] - 1.0 / 11.0).abs() < 1e-12);
+    /// assert!((result.solution[1] - 7.0 / 11.0).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn conjugate_gradient(
+        &self,
+        b: &Vector<f64>,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<IterativeSolution, DimensionError> {
+        self.conjugate_gradient_preconditioned(b, &Identity, tol, max_iter)
+    }
+
+    /// Solve `self * x = b` for a symmetric positive-definite matrix with the preconditioned conjugate gradient
+    /// method. Works like [conjugate_gradient](Matrix::conjugate_gradient), but every residual is multiplied with
+    /// the approximate inverse `precond`, which needs to be symmetric positive-definite as well.
+    ///
+    /// Returns [DimensionError::NoMatch] if `precond` approximates a matrix of another size.
+    /// See the [precond](crate::mat::precond) module for available preconditioners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::mat::precond::JacobiPreconditioner;
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{100.0, 1.0; 1.0, 1.0};
+    /// let precond = JacobiPreconditioner::from_matrix(&mat_a)?;
+    /// let result = mat_a.conjugate_gradient_preconditioned(&vector![1.0, 1.0], &precond, 1e-10, 10)?;
+    /// assert!(result.converged);
+    /// assert!(result.residual_norm < 1e-10 * 2_f64.sqrt());
+    /// # Ok(()) }
+    /// ```
+    pub fn conjugate_gradient_preconditioned(
+        &self,
+        b: &Vector<f64>,
+        precond: &dyn Preconditioner,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<IterativeSolution, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let n = self.rows();
+        if b.size() != n {
+            return Err(self.no_match(b.dims, None, "solve"));
+        }
+        // fails for a preconditioner of the wrong size, before any work is done
+        let mut z = precond.apply(b)?;
+        let a = self.to_f64_matrix();
+        let mul = |v: &[f64]| -> Vec<f64> { a.matrix.chunks(n).map(|row| dot(row, v)).collect() };
+
+        let b_norm = b.norm();
+        let mut x = vec![0.0; n];
+        let mut r = b.clone();
+        let mut p = z.entries.clone();
+        let mut rz = dot(&r, &z);
+        let mut iterations = 0;
+
+        while dot(&r, &r).sqrt() > tol * b_norm && iterations < max_iter {
+            let ap = mul(&p);
+            let alpha = rz / dot(&p, &ap);
+            x.iter_mut()
+                .zip(p.iter())
+                .for_each(|(x, p)| *x += alpha * p);
+            r.entries
+                .iter_mut()
+                .zip(ap.iter())
+                .for_each(|(r, ap)| *r -= alpha * ap);
+            z = precond.apply(&r)?;
+            let rz_new = dot(&r, &z);
+            let beta = rz_new / rz;
+            rz = rz_new;
+            p.iter_mut()
+                .zip(z.iter())
+                .for_each(|(p, z)| *p = z + beta * *p);
+            iterations += 1;
+        }
+
+        // the updated residual drifts from the true one, so report the latter
+        let residual_norm = mul(&x)
+            .iter()
+            .zip(b.iter())
+            .map(|(ax, b)| (b - ax) * (b - ax))
+            .sum::<f64>()
+            .sqrt();
+        Ok(IterativeSolution {
+            solution: Vector::from(x),
+            iterations,
+            residual_norm,
+            converged: residual_norm <= tol * b_norm,
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::{Matrix, Vector};
+use num_traits::ToPrimitive;
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Krylov subspace methods.
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// Run `k` steps of the Arnoldi iteration starting from `v0`.
+    ///
+    /// Returns the matrix `V` (`n x (k+1)`) whose orthonormal columns span the Krylov subspace
+    /// `span(v0, A v0, ..., A^k v0)`, and the upper Hessenberg matrix `H` (`(k+1) x k`) with
+    /// `A * V_k = V * H`, where `V_k` are the first `k` columns of `V`. Only matrix-vector products are used,
+    /// and every new vector is orthogonalized twice against the previous ones to keep `V` orthonormal.
+    ///
+    /// If the Krylov subspace is invariant after `m <= k` steps (breakdown), the truncated factors are returned:
+    /// `V` is `n x m` and `H` is `m x m` with `A * V = V * H`.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices, [DimensionError::NoMatch] if `v0` has the wrong
+    /// length, [DimensionError::InvalidDimensions] if `k` is zero and [DimensionError::InvalidValue] if `v0` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{dims::Dimensions, Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 1.0, 0.0; 1.0, 3.0, 1.0; 0.0, 1.0, 4.0};
+    /// let (v, h) = mat_a.arnoldi(&vector![1.0, 0.0, 0.0], 2)?;
+    /// assert_eq!(v.dims(), Dimensions::new(3, 3));
+    /// assert_eq!(h.dims(), Dimensions::new(3, 2));
+    /// assert_eq!(h[0][0], 2.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn arnoldi(
+        &self,
+        v0: &Vector<f64>,
+        k: usize,
+    ) -> Result<(Matrix<f64>, Matrix<f64>), DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let n = self.rows();
+        if v0.size() != n {
+            return Err(self.no_match(v0.dims, None, "multiply"));
+        }
+        if k == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let v0_norm = v0.norm();
+        if v0_norm == 0.0 || !v0_norm.is_finite() {
+            return Err(DimensionError::InvalidValue(
+                "the start vector needs to be finite and non-zero".to_owned(),
+            ));
+        }
+
+        let a = self.to_f64_matrix();
+        let tol = 1e-12 * a.frobenius_norm();
+        let mut basis: Vec<Vec<f64>> = vec![v0.iter().map(|x| x / v0_norm).collect()];
+        let mut h = vec![vec![0.0; k]; k + 1];
+        let mut steps = k;
+
+        for j in 0..k {
+            let mut w: Vec<f64> = a.matrix.chunks(n).map(|row| dot(row, &basis[j])).collect();
+            for _ in 0..2 {
+                for (i, q) in basis.iter().enumerate() {
+                    let c = dot(&w, q);
+                    h[i][j] += c;
+                    w.iter_mut().zip(q.iter()).for_each(|(x, y)| *x -= c * y);
+                }
+            }
+            let norm = dot(&w, &w).sqrt();
+            if norm <= tol {
+                steps = j + 1;
+                break;
+            }
+            h[j + 1][j] = norm;
+            basis.push(w.into_iter().map(|x| x / norm).collect());
+        }
+
+        // After a breakdown, even in the last step, no vector was added to the basis and `V` and `H` are square.
+        let m = basis.len();
+        let v = Matrix::<f64> {
+            dims: Dimensions::new(n, m),
+            matrix: (0..n)
+                .flat_map(|i| basis.iter().map(move |q| q[i]))
+                .collect(),
+            label: None,
+        };
+        let h = Matrix::<f64> {
+            dims: Dimensions::new(m, steps),
+            matrix: h
+                .into_iter()
+                .take(m)
+                .flat_map(|row| row.into_iter().take(steps))
+                .collect(),
+            label: None,
+        };
+        Ok((v, h))
+    }
+}
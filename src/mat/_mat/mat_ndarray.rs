@@ -0,0 +1,71 @@
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::Matrix;
+use ndarray::Array2;
+use std::convert::TryFrom;
+
+/// Converts a matrix into an [ndarray] array with the same shape, available with the `ndarray` feature.
+///
+/// The entries are moved, not copied, and the array is in standard (row-major) layout. Any label is dropped.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use ndarray::{array, Array2};
+/// let arr = Array2::from(matrix!{1, 2, 3; 4, 5, 6});
+/// assert_eq!(arr, array![[1, 2, 3], [4, 5, 6]]);
+/// ```
+impl<T> From<Matrix<T>> for Array2<T> {
+    fn from(mat: Matrix<T>) -> Array2<T> {
+        Array2::from_shape_vec(mat.shape(), mat.matrix)
+            .expect("the entries of a matrix fill its dimensions")
+    }
+}
+
+/// Converts an [ndarray] array into a matrix with the same shape, available with the `ndarray` feature.
+///
+/// The entries are moved, arrays in standard layout keep their buffer. Fails with
+/// [DimensionError::InvalidDimensions] if the array has no rows or no columns.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # use ndarray::{array, Array2};
+/// # use std::convert::TryFrom;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat_a = Matrix::try_from(array![[1.0, 2.0], [3.0, 4.0]])?;
+/// assert_eq!(mat_a, matrix!{1.0, 2.0; 3.0, 4.0});
+/// let empty = Matrix::try_from(Array2::<f64>::zeros((0, 3)));
+/// assert_eq!(empty, Err(DimensionError::InvalidDimensions));
+/// # Ok(()) }
+/// ```
+impl<T> TryFrom<Array2<T>> for Matrix<T> {
+    type Error = DimensionError;
+
+    fn try_from(arr: Array2<T>) -> Result<Matrix<T>, DimensionError> {
+        let (rows, cols) = arr.dim();
+        if rows == 0 || cols == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let matrix = if arr.is_standard_layout() {
+            // the entries are contiguous in row-major order, but may start after an offset into the buffer
+            let (mut entries, offset) = arr.into_raw_vec_and_offset();
+            let offset = offset.unwrap_or(0);
+            entries.truncate(offset + rows * cols);
+            entries.drain(..offset);
+            entries
+        } else {
+            arr.into_iter().collect()
+        };
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows, cols),
+            matrix,
+            label: None,
+        })
+    }
+}
@@ -1,7 +1,7 @@
 // use crate::err::DimensionError;
 use crate::{
     err::DimensionError,
-    mat::{Matrix, Vector},
+    mat::{dims::Dimensions, simd, Matrix, Vector},
 };
 use num_traits::identities::{One, Zero};
 use std::ops::{
@@ -9,47 +9,6 @@ use std::ops::{
 };
 use std::result::Result;
 
-// impl Matrix<i64> {
-//     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//     #[target_feature(enable = "avx2")]
-//     pub unsafe fn add_assign_avx2(&mut self, rhs: Matrix<i64>) {
-//         #[cfg(target_arch = "x86")]
-//         use std::arch::x86::{__m256i, _mm256_add_epi64, _mm256_set_epi64x};
-//         #[cfg(target_arch = "x86_64")]
-//         use std::arch::x86_64::{__m256i, _mm256_add_epi64, _mm256_set_epi64x};
-
-//         const INTS_PER_MM: usize = std::mem::size_of::<__m256i>() / std::mem::size_of::<i64>();
-
-//         for i in 0..self.row_count() {
-//             let (head, middle, tail) = self[i].align_to_mut::<__m256i>();
-//             let head_len = head.len();
-
-//             add_slices(head, &rhs[i][..head_len]);
-
-//             let middle_add_chunks =
-//                 rhs[i][head_len..(head_len + middle.len() * INTS_PER_MM)].chunks(INTS_PER_MM);
-//             for (row_data, add_data) in middle.iter_mut().zip(middle_add_chunks) {
-//                 let add_mm = _mm256_set_epi64x(add_data[0], add_data[1], add_data[2], add_data[3]);
-//                 *row_data = _mm256_add_epi64(*row_data, add_mm);
-//             }
-
-//             add_slices(tail, &rhs[i][(head_len + middle.len() * INTS_PER_MM)..]);
-//         }
-
-//         fn add_slices(a: &mut [i64], b: &[i64]) {
-//             if a.len() >= 1 {
-//                 a[0] += b[0];
-//             }
-//             if a.len() >= 2 {
-//                 a[1] += b[1];
-//             }
-//             if a.len() >= 3 {
-//                 a[2] += b[2];
-//             }
-//         }
-//     }
-// }
-
 /// Elementwise addition. Both matrices need to have the same dimensions.
 ///
 /// # Example
@@ -60,42 +19,58 @@ use std::result::Result;
 /// let mat_a = Matrix::<i32>::one(3)?;
 /// let mat_b = Matrix::<i32>::one(3)?;
 /// let mat_c = Matrix::diag(3, 2)?;
+/// assert_eq!((&mat_a + &mat_b)?, mat_c);
 /// assert_eq!((mat_a + mat_b)?, mat_c);
 /// # Ok(()) }
 /// ```
-impl<T> Add for Matrix<T>
+impl<T> Add<&Matrix<T>> for Matrix<T>
 where
     T: AddAssign + Clone,
 {
     type Output = Result<Matrix<T>, DimensionError>;
 
-    fn add(self, rhs: Matrix<T>) -> Self::Output {
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
         if self.dims != rhs.dims {
-            Err(DimensionError::NoMatch(
-                self.dims,
-                rhs.dims,
-                "add".to_owned(),
-            ))
+            Err(self.no_match(rhs.dims, rhs.label(), "add"))
         } else {
             let mut result_matrix = self;
+            result_matrix.label = None;
             result_matrix += rhs;
             Ok(result_matrix)
         }
     }
 }
 
+impl_binop!(impl Add::add for Matrix<T>, Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a + &b; where T: AddAssign + Clone);
+impl_binop!(impl Add::add for &Matrix<T>, Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a.clone() + &b; where T: AddAssign + Clone);
+impl_binop!(impl Add::add for &Matrix<T>, &Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a.clone() + b; where T: AddAssign + Clone);
+
 impl<T> AddAssign<Matrix<T>> for Matrix<T>
 where
     T: AddAssign + Clone,
 {
     fn add_assign(&mut self, rhs: Matrix<T>) {
-        if self.dims != rhs.dims {
-            panic!("Dimensions do not match.");
+        *self += &rhs;
+    }
+}
+
+/// In-place elementwise addition. Adding a reference doesn't clone the right matrix.
+///
+/// # Panics
+///
+/// Panics with the message of the [DimensionError] if the dimensions differ.
+/// [try_add_assign](Matrix::try_add_assign) returns the error instead.
+impl<T> AddAssign<&Matrix<T>> for Matrix<T>
+where
+    T: AddAssign + Clone,
+{
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        if let Err(err) = self.try_add_assign(rhs) {
+            panic!("{}", err);
         }
-        self.matrix
-            .iter_mut()
-            .zip(rhs.matrix.iter())
-            .for_each(|(a, b)| *a += b.clone());
     }
 }
 
@@ -119,6 +94,7 @@ where
 
     fn add(self, rhs: T) -> Self::Output {
         let mut result_matrix = self;
+        result_matrix.label = None;
         result_matrix += rhs;
         result_matrix
     }
@@ -143,42 +119,129 @@ where
 /// # fn main() -> Result<(), DimensionError> {
 /// let mat_a: Matrix<i32> = Matrix::one(3)?;
 /// let mat_b: Matrix<i32> = Matrix::one(3)?;
+/// assert_eq!((&mat_a - &mat_b)?, Matrix::zero(3, 3)?);
 /// assert_eq!((mat_a - mat_b)?, Matrix::zero(3, 3)?);
 /// # Ok(()) }
 /// ```
-impl<T> Sub for Matrix<T>
+impl<T> Sub<&Matrix<T>> for Matrix<T>
 where
     T: SubAssign + Clone,
 {
     type Output = Result<Matrix<T>, DimensionError>;
 
-    fn sub(self, rhs: Matrix<T>) -> Self::Output {
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
         if self.dims != rhs.dims {
-            Err(DimensionError::NoMatch(
-                self.dims,
-                rhs.dims,
-                "add".to_owned(),
-            ))
+            Err(self.no_match(rhs.dims, rhs.label(), "subtract"))
         } else {
             let mut result_matrix = self;
+            result_matrix.label = None;
             result_matrix -= rhs;
             Ok(result_matrix)
         }
     }
 }
 
+impl_binop!(impl Sub::sub for Matrix<T>, Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a - &b; where T: SubAssign + Clone);
+impl_binop!(impl Sub::sub for &Matrix<T>, Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a.clone() - &b; where T: SubAssign + Clone);
+impl_binop!(impl Sub::sub for &Matrix<T>, &Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a.clone() - b; where T: SubAssign + Clone);
+
 impl<T> SubAssign<Matrix<T>> for Matrix<T>
 where
     T: SubAssign + Clone,
 {
     fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+/// In-place elementwise subtraction. Subtracting a reference doesn't clone the right matrix.
+///
+/// # Panics
+///
+/// Panics with the message of the [DimensionError] if the dimensions differ.
+/// [try_sub_assign](Matrix::try_sub_assign) returns the error instead.
+impl<T> SubAssign<&Matrix<T>> for Matrix<T>
+where
+    T: SubAssign + Clone,
+{
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        if let Err(err) = self.try_sub_assign(rhs) {
+            panic!("{}", err);
+        }
+    }
+}
+
+/// Fallible in-place arithmetic.
+impl<T> Matrix<T> {
+    /// Add `rhs` to `self` entrywise, like `+=`, but return an error instead of panicking.
+    ///
+    /// Returns [DimensionError::NoMatch] if the dimensions differ, `self` is unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut total = matrix!{1, 2; 3, 4};
+    /// let delta = matrix!{1, 1; 1, 1};
+    /// for _ in 0..3 {
+    ///     total.try_add_assign(&delta)?;
+    /// }
+    /// assert_eq!(total, matrix!{4, 5; 6, 7});
+    /// assert!(total.try_add_assign(&matrix!{1, 2}).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn try_add_assign(&mut self, rhs: &Matrix<T>) -> Result<(), DimensionError>
+    where
+        T: AddAssign + Clone,
+    {
         if self.dims != rhs.dims {
-            panic!("Dimensions do not match.");
+            return Err(self.no_match(rhs.dims, rhs.label(), "add"));
         }
-        self.matrix
-            .iter_mut()
-            .zip(rhs.matrix.iter())
-            .for_each(|(a, b)| *a -= b.clone());
+        if !simd::add_assign(&mut self.matrix, &rhs.matrix) {
+            self.matrix
+                .iter_mut()
+                .zip(rhs.matrix.iter())
+                .for_each(|(a, b)| *a += b.clone());
+        }
+        Ok(())
+    }
+
+    /// Subtract `rhs` from `self` entrywise, like `-=`, but return an error instead of panicking.
+    ///
+    /// Returns [DimensionError::NoMatch] if the dimensions differ, `self` is unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat_a = matrix!{1, 2; 3, 4};
+    /// mat_a.try_sub_assign(&matrix!{1, 1; 1, 1})?;
+    /// assert_eq!(mat_a, matrix!{0, 1; 2, 3});
+    /// # Ok(()) }
+    /// ```
+    pub fn try_sub_assign(&mut self, rhs: &Matrix<T>) -> Result<(), DimensionError>
+    where
+        T: SubAssign + Clone,
+    {
+        if self.dims != rhs.dims {
+            return Err(self.no_match(rhs.dims, rhs.label(), "subtract"));
+        }
+        if !simd::sub_assign(&mut self.matrix, &rhs.matrix) {
+            self.matrix
+                .iter_mut()
+                .zip(rhs.matrix.iter())
+                .for_each(|(a, b)| *a -= b.clone());
+        }
+        Ok(())
     }
 }
 
@@ -202,6 +265,7 @@ where
 
     fn sub(self, rhs: T) -> Self::Output {
         let mut result = self;
+        result.label = None;
         result -= rhs;
         result
     }
@@ -235,6 +299,7 @@ where
 
     fn neg(self) -> Self::Output {
         let mut result_matrix = self;
+        result_matrix.label = None;
         result_matrix
             .matrix
             .iter_mut()
@@ -243,10 +308,98 @@ where
     }
 }
 
+/// Negating a reference to a matrix returns a new negated matrix and leaves the original untouched.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{1, -2; 3, -4};
+/// assert_eq!(-&mat_a, matrix!{-1, 2; -3, 4});
+/// assert_eq!(mat_a, matrix!{1, -2; 3, -4});
+/// ```
+impl<T> Neg for &Matrix<T>
+where
+    T: Neg<Output = T> + Clone,
+{
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        Matrix::<T> {
+            dims: self.dims,
+            matrix: self.matrix.iter().map(|x| -(x.clone())).collect(),
+            label: None,
+        }
+    }
+}
+
+/// Side length of the square tiles of the right matrix in [mul_rows_into](Matrix::mul_rows_into).
+/// A tile of 64x64 `f64` entries is 32 KiB, so it stays in the L1 or L2 cache while it is used.
+const MUL_BLOCK: usize = 64;
+
+impl<T> Matrix<T>
+where
+    T: Zero + Clone + Mul<Output = T>,
+{
+    /// Add the rows of `self * rhs` starting at row `first_row` to `out`, which holds whole rows of the product.
+    ///
+    /// The rows of `rhs` are traversed in tiles of [MUL_BLOCK] x [MUL_BLOCK], and every entry of `self` is
+    /// multiplied with a contiguous run of a row of `rhs`, so no transposed copy is needed and the innermost loop
+    /// can be vectorized for primitive types. The dimensions need to be checked by the caller.
+    pub(crate) fn mul_rows_into(&self, rhs: &Matrix<T>, first_row: usize, out: &mut [T]) {
+        let (inner, cols) = (self.cols(), rhs.cols());
+        for k_start in (0..inner).step_by(MUL_BLOCK) {
+            let k_end = (k_start + MUL_BLOCK).min(inner);
+            for j_start in (0..cols).step_by(MUL_BLOCK) {
+                let j_end = (j_start + MUL_BLOCK).min(cols);
+                for (i, out_row) in out.chunks_mut(cols).enumerate() {
+                    let row_self =
+                        &self.matrix[(first_row + i) * inner..(first_row + i + 1) * inner];
+                    for (k, a) in row_self.iter().enumerate().take(k_end).skip(k_start) {
+                        let row_rhs = &rhs.matrix[k * cols + j_start..k * cols + j_end];
+                        for (entry, b) in out_row[j_start..j_end].iter_mut().zip(row_rhs) {
+                            // move the sum out instead of cloning it, which is cheaper for big number types
+                            let acc = std::mem::replace(entry, T::zero());
+                            *entry = acc + a.clone() * b.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Allocate the product `self * rhs` and fill its entries with `kernel`, usually [mul_rows_into](Matrix::mul_rows_into).
+    ///
+    /// Returns [DimensionError::NoMatch] without calling `kernel` if `self` doesn't have as many columns as `rhs` has rows.
+    pub(crate) fn product_with<F>(
+        &self,
+        rhs: &Matrix<T>,
+        kernel: F,
+    ) -> Result<Matrix<T>, DimensionError>
+    where
+        F: FnOnce(&mut [T]),
+    {
+        if self.cols() != rhs.rows() {
+            return Err(self.no_match(rhs.dims, rhs.label(), "multiply"));
+        }
+        let mut result_matrix = Matrix::<T> {
+            dims: Dimensions::new(self.rows(), rhs.cols()),
+            matrix: vec![T::zero(); self.rows() * rhs.cols()],
+            label: None,
+        };
+        kernel(&mut result_matrix.matrix);
+        Ok(result_matrix)
+    }
+}
+
 /// Matrix multiplicaiton as described in
 /// [Matrix multipication](https://en.wikipedia.org/wiki/Matrix_multiplication),
 /// so the left matrix needs to have the same amount of columns as the right one has rows.
 ///
+/// The product is computed in cache-sized tiles directly on the row-major storage. With the `rayon` feature,
+/// `par_mul()` also splits the rows between threads.
+///
 /// # Example
 ///
 /// ```
@@ -257,51 +410,32 @@ where
 /// let mat_a = matrix!{1, 2, 3, 4; 5, 6, 7, 8};
 /// let mat_b = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9; 10, 11, 12};
 /// let mat_c = matrix!{70, 80, 90; 158, 184, 210};
+/// assert_eq!((&mat_a * &mat_b)?, mat_c);
 /// assert_eq!((mat_a * mat_b)?, mat_c);
 /// # Ok(()) }
 /// ```
-impl<T> Mul for Matrix<T>
+impl<T> Mul<&Matrix<T>> for &Matrix<T>
 where
-    T: Zero + One + Clone + std::iter::Sum,
+    T: Zero + Clone + Mul<Output = T>,
 {
     type Output = Result<Matrix<T>, DimensionError>;
 
-    fn mul(self, rhs: Matrix<T>) -> Self::Output {
-        if self.cols() != rhs.rows() {
-            Err(DimensionError::NoMatch(
-                self.dims,
-                rhs.dims,
-                "multiply".to_owned(),
-            ))
-        } else {
-            let r_rhs = rhs.transpose();
-            let mut result_matrix = Matrix::<T>::zero(self.rows(), rhs.cols()).unwrap();
-            let res_cols = result_matrix.cols();
-
-            result_matrix
-                .matrix
-                .chunks_mut(res_cols)
-                .zip(self.matrix.chunks(self.cols()))
-                .for_each(|(row_mut, row_self)| {
-                    row_mut
-                        .iter_mut()
-                        .zip(r_rhs.matrix.chunks(r_rhs.cols()))
-                        .for_each(|(entry_mut, col_rhs)| {
-                            *entry_mut = row_self
-                                .iter()
-                                .zip(col_rhs.iter())
-                                .map(|(a, b)| a.clone() * b.clone())
-                                .sum();
-                        })
-                });
-
-            Ok(result_matrix)
-        }
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.product_with(rhs, |out| self.mul_rows_into(rhs, 0, out))
     }
 }
 
-/// Matrices can be multiplied with Vectors.
-/// The dimensions of the two objects need to match like with matrix multiplication.
+impl_binop!(impl Mul::mul for Matrix<T>, Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| &a * &b; where T: Zero + Clone + Mul<Output = T>);
+impl_binop!(impl Mul::mul for &Matrix<T>, Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| a * &b; where T: Zero + Clone + Mul<Output = T>);
+impl_binop!(impl Mul::mul for Matrix<T>, &Matrix<T> => Result<Matrix<T>, DimensionError>;
+    |a, b| &a * b; where T: Zero + Clone + Mul<Output = T>);
+
+/// Matrices can be multiplied with column vectors.
+/// The dimensions of the two objects need to match like with matrix multiplication,
+/// so the vector needs to be a column vector with as many entries as the matrix has columns.
+/// The result is a column vector.
 ///
 /// # Example
 ///
@@ -310,13 +444,15 @@ where
 /// # use libmat::{matrix, vector};
 /// # use libmat::err::DimensionError;
 /// # fn main() -> Result<(), DimensionError> {
-/// let v_a = vector![1, 2, 3].to_row_vector();
 /// let mat_a = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
-/// let v_b = vector![30, 36, 42].to_row_vector();
-/// assert_eq!((v_a * mat_a)?, v_b);
+/// let v_a = vector![1, 2, 3];
+/// let v_b = vector![14, 32, 50];
+/// assert_eq!((&mat_a * &v_a)?, v_b);
+/// assert_eq!((mat_a.clone() * v_a.clone())?, v_b);
+/// assert!((mat_a * v_a.to_row_vector()).is_err());
 /// # Ok(()) }
 /// ```
-impl<T> Mul<Vector<T>> for Matrix<T>
+impl<T> Mul<Vector<T>> for &Matrix<T>
 where
     T: One + Zero + std::iter::Sum + Clone,
 {
@@ -324,11 +460,18 @@ where
 
     fn mul(self, vec: Vector<T>) -> Self::Output {
         let mat_v: Matrix<T> = vec.into();
-        let res = (self * mat_v)?;
+        let res = (self * &mat_v)?;
         Ok(res.into())
     }
 }
 
+impl_binop!(impl Mul::mul for Matrix<T>, Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| &a * b; where T: One + Zero + std::iter::Sum + Clone);
+impl_binop!(impl Mul::mul for &Matrix<T>, &Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a * b.clone(); where T: One + Zero + std::iter::Sum + Clone);
+impl_binop!(impl Mul::mul for Matrix<T>, &Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| &a * b.clone(); where T: One + Zero + std::iter::Sum + Clone);
+
 /// A matrix can be scaled by scaling a reference to a matrix. Each entry will be scaled by the given factor.
 ///
 /// # Example
@@ -348,6 +491,35 @@ where
 
     fn mul(self, scalar: T) -> Self::Output {
         let mut result_matrix = self;
+        result_matrix.label = None;
+        result_matrix *= scalar;
+        result_matrix
+    }
+}
+
+/// Scaling a reference to a matrix returns a new scaled matrix and leaves the original untouched.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// assert_eq!(&mat_a * 2, matrix!{2, 4; 6, 8});
+/// assert_eq!(mat_a, matrix!{1, 2; 3, 4});
+/// ```
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: MulAssign + Clone,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        let mut result_matrix = Matrix::<T> {
+            dims: self.dims,
+            matrix: self.matrix.clone(),
+            label: None,
+        };
         result_matrix *= scalar;
         result_matrix
     }
@@ -358,10 +530,48 @@ where
     T: MulAssign + Clone,
 {
     fn mul_assign(&mut self, scalar: T) {
-        self.matrix.iter_mut().for_each(|a| *a *= scalar.clone());
+        if !simd::mul_assign_scalar(&mut self.matrix, &scalar) {
+            self.matrix.iter_mut().for_each(|a| *a *= scalar.clone());
+        }
     }
 }
 
+/// Scaling with the scalar on the left, for the primitive number types.
+/// The result is the same as with the scalar on the right.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// assert_eq!(2 * &mat_a, matrix!{2, 4; 6, 8});
+/// assert_eq!(2 * mat_a.clone(), mat_a * 2);
+/// ```
+macro_rules! impl_scalar_mul_matrix {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: Matrix<$t>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl Mul<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: &Matrix<$t>) -> Self::Output {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul_matrix!(i32, i64, u32, u64, f32, f64);
+
 /// Elementwise division. Same as multiplying with the inverse.
 ///
 /// # Example
@@ -382,6 +592,7 @@ where
 
     fn div(self, divisor: T) -> Self::Output {
         let mut result_matrix = self;
+        result_matrix.label = None;
         result_matrix /= divisor;
         result_matrix
     }
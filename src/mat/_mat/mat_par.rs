@@ -0,0 +1,122 @@
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::{Matrix, Vector};
+use num_traits::Zero;
+use rayon::prelude::*;
+use std::ops::Mul;
+
+/// Number of rows of the product that one task computes in [par_mul](Matrix::par_mul).
+const PAR_MUL_ROWS: usize = 16;
+
+/// Parallel row-wise operations, available with the `rayon` feature.
+///
+/// The rows are processed in parallel directly on the storage of the matrix, without allocating per row.
+impl<T> Matrix<T>
+where
+    T: Sync,
+{
+    /// Apply `f` to every row in parallel and collect the results into a column vector.
+    ///
+    /// The `i`-th entry of the result is `f` applied to the `i`-th row, no matter how the rows were scheduled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let mat_a = matrix!{1, 5, 3; 4, 2, 6};
+    /// let row_max = mat_a.par_map_rows(|row| *row.iter().max().unwrap());
+    /// assert_eq!(row_max, vector![5, 6]);
+    /// ```
+    pub fn par_map_rows<U, F>(&self, f: F) -> Vector<U>
+    where
+        U: Send,
+        F: Fn(&[T]) -> U + Sync,
+    {
+        Vector::<U> {
+            dims: Dimensions::new(self.rows(), 1),
+            entries: self.matrix.par_chunks(self.cols()).map(&f).collect(),
+        }
+    }
+
+    /// Sums of the entries of each row computed in parallel, see [row_sums](Matrix::row_sums).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.par_row_sums(), mat_a.row_sums());
+    /// ```
+    pub fn par_row_sums(&self) -> Vector<T>
+    where
+        T: Zero + Clone + Send,
+    {
+        self.par_map_rows(|row| row.iter().fold(T::zero(), |acc, x| acc + x.clone()))
+    }
+
+    /// Fold all entries in row-major order in parallel.
+    ///
+    /// Runs of consecutive entries are folded with `fold`, starting from `identity()`, and the partial results
+    /// are combined with `reduce`, always keeping the order of the entries. Where the runs are split depends on
+    /// the scheduling, so `fold` and `reduce` need to be associative and `identity()` needs to be a neutral
+    /// element of `reduce`. Then the result is the same as folding all entries serially,
+    /// even if `reduce` is not commutative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let digits = mat_a.par_fold_entries(String::new, |acc, x| acc + &x.to_string(), |a, b| a + &b);
+    /// assert_eq!(digits, "1234");
+    /// ```
+    pub fn par_fold_entries<A, I, F, R>(&self, identity: I, fold: F, reduce: R) -> A
+    where
+        A: Send,
+        I: Fn() -> A + Sync + Send,
+        F: Fn(A, &T) -> A + Sync + Send,
+        R: Fn(A, A) -> A + Sync + Send,
+    {
+        self.matrix
+            .par_chunks(self.cols())
+            .fold(&identity, |acc, row| row.iter().fold(acc, &fold))
+            .reduce(&identity, reduce)
+    }
+
+    /// Multiply two matrices, computing blocks of rows of the product in parallel.
+    ///
+    /// Every task runs the same tiled kernel as [Mul](std::ops::Mul) on its own rows of the result, so the
+    /// result is exactly the same as `self * rhs`, also for floating point matrices. Products with at most one
+    /// block of rows are computed on the current thread.
+    ///
+    /// Returns [DimensionError::NoMatch] if `self` doesn't have as many columns as `rhs` has rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3, 4; 5, 6, 7, 8};
+    /// let mat_b = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9; 10, 11, 12};
+    /// assert_eq!(mat_a.par_mul(&mat_b)?, (&mat_a * &mat_b)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Zero + Clone + Mul<Output = T> + Send,
+    {
+        self.product_with(rhs, |out| {
+            if self.rows() <= PAR_MUL_ROWS {
+                return self.mul_rows_into(rhs, 0, out);
+            }
+            out.par_chunks_mut(PAR_MUL_ROWS * rhs.cols())
+                .enumerate()
+                .for_each(|(block, out)| self.mul_rows_into(rhs, block * PAR_MUL_ROWS, out));
+        })
+    }
+}
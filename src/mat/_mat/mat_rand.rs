@@ -0,0 +1,188 @@
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::Matrix;
+use num_traits::cast::ToPrimitive;
+use rand::seq::index;
+use rand::Rng;
+
+/// Extra random directions sampled by [low_rank_approx](Matrix::low_rank_approx) beyond the target rank.
+const OVERSAMPLING: usize = 5;
+
+/// Randomized helpers, available with the `rand` feature.
+impl<T> Matrix<T> {
+    /// Reduce the column dimension of a matrix by a random projection.
+    ///
+    /// The matrix is multiplied with a `cols x target_dim` matrix of random signs (Achlioptas projection),
+    /// scaled by `1/sqrt(target_dim)`, so pairwise distances between rows are approximately preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_dim` - Column count of the projected matrix, at most `self.cols()`
+    /// * `rng` - Source of randomness
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::err::DimensionError;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let mat_a = Matrix::new(10, 50, 1_i32)?;
+    /// let mat_b = mat_a.random_projection(20, &mut rng)?;
+    /// assert_eq!((mat_b.rows(), mat_b.cols()), (10, 20));
+    /// # Ok(()) }
+    /// ```
+    pub fn random_projection<R>(
+        &self,
+        target_dim: usize,
+        rng: &mut R,
+    ) -> Result<Matrix<f64>, DimensionError>
+    where
+        T: ToPrimitive,
+        R: Rng + ?Sized,
+    {
+        if target_dim == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        if target_dim > self.cols() {
+            return Err(DimensionError::InvalidInputDimensions(
+                target_dim,
+                self.cols(),
+            ));
+        }
+        let scale = 1.0 / (target_dim as f64).sqrt();
+        let projection = Matrix::from_vec(
+            self.cols(),
+            target_dim,
+            (0..self.cols() * target_dim)
+                .map(|_| if rng.gen_bool(0.5) { scale } else { -scale })
+                .collect(),
+        )?;
+        self.to_f64_matrix() * projection
+    }
+
+    /// Select `k` rows uniformly at random without replacement.
+    ///
+    /// The selected rows keep their relative order from `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let mat_a = matrix!{1, 1; 2, 2; 3, 3; 4, 4};
+    /// let mat_b = mat_a.subsampled_rows(2, &mut rng)?;
+    /// assert_eq!(mat_b.rows(), 2);
+    /// assert!(mat_b[0][0] < mat_b[1][0]);
+    /// # Ok(()) }
+    /// ```
+    pub fn subsampled_rows<R>(&self, k: usize, rng: &mut R) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Clone,
+        R: Rng + ?Sized,
+    {
+        if k == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        if k > self.rows() {
+            return Err(DimensionError::InvalidInputDimensions(k, self.rows()));
+        }
+        let mut rows = index::sample(rng, self.rows(), k).into_vec();
+        rows.sort_unstable();
+        let cols = self.cols();
+        let mut vec = Vec::<T>::with_capacity(k * cols);
+        for i in rows {
+            vec.extend_from_slice(&self.matrix[i * cols..i * cols + cols]);
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(k, cols),
+            matrix: vec,
+            label: None,
+        })
+    }
+
+    /// Calculate a rank-`k` approximation `A ≈ U * V^T` with randomized subspace iteration.
+    ///
+    /// The range of the matrix is sampled with a random projection onto `k` plus a few extra directions and
+    /// refined by `iterations` power iterations, re-orthogonalized with a [QR decomposition](Matrix::qr) after
+    /// every product. The matrix is then projected onto that subspace and the projection is truncated to rank `k`
+    /// exactly with a [symmetric eigendecomposition](Matrix::eigen_sym) of its Gram matrix.
+    ///
+    /// `U` (`rows x k`) has orthonormal columns and `V` (`cols x k`) carries the scale, ordered from the
+    /// largest singular value to the smallest. Only products with `self` and its transpose and factorizations
+    /// of the small sampled matrices are needed, so this works for matrices where a full SVD is out of reach.
+    /// A few power iterations are enough unless the singular values decay slowly.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] if `k` is zero, [DimensionError::InvalidInputDimensions]
+    /// with `k` and the smaller dimension if `k` is larger than that, and [DimensionError::InvalidValue]
+    /// if an entry is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let mat_a = matrix!{1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 3.0, 6.0, 9.0; 4.0, 8.0, 12.0};
+    /// let (u, v) = mat_a.low_rank_approx(1, 2, &mut rng)?;
+    /// assert_eq!((u.cols(), v.cols()), (1, 1));
+    /// let approx = (u * v.transpose())?;
+    /// assert!(approx.approx_eq(&mat_a, 1e-10));
+    /// # Ok(()) }
+    /// ```
+    pub fn low_rank_approx<R>(
+        &self,
+        k: usize,
+        iterations: usize,
+        rng: &mut R,
+    ) -> Result<(Matrix<f64>, Matrix<f64>), DimensionError>
+    where
+        T: ToPrimitive,
+        R: Rng + ?Sized,
+    {
+        let (m, n) = (self.rows(), self.cols());
+        if k == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        if k > m.min(n) {
+            return Err(DimensionError::InvalidInputDimensions(k, m.min(n)));
+        }
+        let a = self.to_f64_matrix();
+        if a.matrix.iter().any(|x| !x.is_finite()) {
+            return Err(DimensionError::InvalidValue(
+                "a low rank approximation needs finite entries".to_owned(),
+            ));
+        }
+        let a_t = a.transpose();
+
+        let l = (k + OVERSAMPLING).min(m.min(n));
+        let omega = Matrix::from_vec(n, l, (0..n * l).map(|_| rng.gen_range(-1.0..1.0)).collect())?;
+        let (mut q, _) = (&a * &omega)?.qr()?;
+        for _ in 0..iterations {
+            let (z, _) = (&a_t * &q)?.qr()?;
+            q = (&a * &z)?.qr()?.0;
+        }
+
+        let b = (q.transpose() * a)?;
+        let (_, w) = (&b * &b.transpose())?.eigen_sym()?;
+        let w_k = Matrix::<f64> {
+            dims: Dimensions::new(l, k),
+            matrix: (0..l)
+                .flat_map(|i| (l - k..l).rev().map(move |j| (i, j)))
+                .map(|(i, j)| w[i][j])
+                .collect(),
+            label: None,
+        };
+        let u = (q * w_k.clone())?;
+        let v = (b.transpose() * w_k)?;
+        Ok((u, v))
+    }
+}
@@ -0,0 +1,235 @@
+use crate::err::DimensionError;
+use crate::mat::{Matrix, PivotStrategy, Vector};
+use num_traits::identities::{One, Zero};
+use num_traits::ToPrimitive;
+use std::ops::{Div, Sub};
+
+/// Row echelon forms.
+///
+/// These methods only need the field operations `+`, `-`, `*` and `/`, so they are meant for fields like
+/// floating point or rational numbers. For signed integer matrices they are only exact if every division happens
+/// to leave no remainder. Unsigned integer matrices would underflow when rows are subtracted, so they should be
+/// reduced with [rref_as_f64](Matrix::rref_as_f64) instead.
+impl<T> Matrix<T>
+where
+    T: Clone + Zero + One + Sub<Output = T> + Div<Output = T> + PartialEq,
+{
+    /// Calculate the reduced row echelon form of a matrix with Gauss-Jordan elimination.
+    ///
+    /// The pivot of every column is its first non-zero entry at or below the current row.
+    /// For floating point matrices [rref_with](Matrix::rref_with) and partial pivoting give less round-off.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{2.0, 4.0, 2.0; 1.0, 3.0, 2.0; 1.0, 1.0, 0.0};
+    /// let mat_r = matrix!{1.0, 0.0, -1.0; 0.0, 1.0, 1.0; 0.0, 0.0, 0.0};
+    /// assert_eq!(mat_a.rref(), mat_r);
+    /// ```
+    pub fn rref(&self) -> Matrix<T> {
+        self.rref_with_pivots(PivotStrategy::FirstNonZero).0
+    }
+
+    /// Calculate the reduced row echelon form of a matrix, choosing pivots with `strategy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, PivotStrategy};
+    /// # use libmat::matrix;
+    /// // augmented system of 1e-20 * x + y = 1 and x + y = 2, the solution is close to (1, 1)
+    /// let mat_a = matrix!{1e-20, 1.0, 1.0; 1.0, 1.0, 2.0};
+    /// // the tiny pivot 1e-20 loses x to round-off
+    /// assert_eq!(mat_a.rref(), matrix!{1.0, 0.0, 0.0; 0.0, 1.0, 1.0});
+    /// let partial = mat_a.rref_with(PivotStrategy::Largest(|x: &f64| x.abs()));
+    /// assert_eq!(partial, matrix!{1.0, 0.0, 1.0; 0.0, 1.0, 1.0});
+    /// ```
+    pub fn rref_with(&self, strategy: PivotStrategy<T>) -> Matrix<T> {
+        self.rref_with_pivots(strategy).0
+    }
+
+    /// Calculate the reduced row echelon form and the pivot column of every non-zero row.
+    pub(crate) fn rref_with_pivots(&self, strategy: PivotStrategy<T>) -> (Matrix<T>, Vec<usize>) {
+        self.clone().eliminate(self.cols(), strategy)
+    }
+
+    /// Gauss-Jordan elimination that only looks for pivots in the first `pivot_cols` columns.
+    /// The row operations are applied to all columns.
+    fn eliminate(self, pivot_cols: usize, strategy: PivotStrategy<T>) -> (Matrix<T>, Vec<usize>) {
+        let mut mat = self;
+        mat.label = None;
+        let (rows, cols) = (mat.rows(), mat.cols());
+        let mut pivots = Vec::<usize>::new();
+        let mut row = 0;
+        for col in 0..pivot_cols {
+            if row == rows {
+                break;
+            }
+            let pivot = match strategy {
+                PivotStrategy::FirstNonZero => (row..rows).find(|&r| !mat[r][col].is_zero()),
+                PivotStrategy::Largest(magnitude) => (row..rows)
+                    .filter(|&r| !mat[r][col].is_zero())
+                    .max_by(|&r, &s| magnitude(&mat[r][col]).total_cmp(&magnitude(&mat[s][col]))),
+            };
+            let pivot_row = match pivot {
+                Some(r) => r,
+                None => continue,
+            };
+            mat.swap_rows(row, pivot_row);
+
+            let pivot = mat[row][col].clone();
+            for c in col..cols {
+                mat[row][c] = mat[row][c].clone() / pivot.clone();
+            }
+            for r in 0..rows {
+                if r == row || mat[r][col].is_zero() {
+                    continue;
+                }
+                let factor = mat[r][col].clone();
+                for c in col..cols {
+                    mat[r][c] = mat[r][c].clone() - factor.clone() * mat[row][c].clone();
+                }
+            }
+            pivots.push(col);
+            row += 1;
+        }
+        (mat, pivots)
+    }
+
+    /// Reduce the augmented matrix `[self | rhs]` and return both halves.
+    ///
+    /// Pivots are only chosen in the columns of `self`, so the left half is the [reduced row echelon form](Matrix::rref)
+    /// of `self` and the right half holds the same row operations applied to `rhs`.
+    /// If `self` is invertible the right half is the solution `X` of `self * X = rhs`.
+    /// A zero row in the left half with non-zero entries in the right half means that the system has no solution.
+    ///
+    /// Returns [DimensionError::NoMatch] if `self` and `rhs` have a different number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // x + 2y = 5, 3x + 4y = 11
+    /// let mat_a = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// let (reduced, solution) = mat_a.rref_augmented(&matrix!{5.0; 11.0})?;
+    /// assert_eq!(reduced, Matrix::one(2)?);
+    /// assert_eq!(solution, matrix!{1.0; 2.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn rref_augmented(
+        &self,
+        rhs: &Matrix<T>,
+    ) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        if self.rows() != rhs.rows() {
+            return Err(self.no_match(rhs.dims, rhs.label(), "augment"));
+        }
+        let (lcols, rcols) = (self.cols(), rhs.cols());
+        let augmented = Matrix::from_vec(
+            self.rows(),
+            lcols + rcols,
+            (0..self.rows())
+                .flat_map(|i| self[i].iter().chain(rhs[i].iter()).cloned())
+                .collect(),
+        )?;
+        let (mat, _) = augmented.eliminate(lcols, PivotStrategy::FirstNonZero);
+        let (left, right) = (0..mat.rows()).map(|i| mat[i].split_at(lcols)).fold(
+            (Vec::new(), Vec::new()),
+            |(mut left, mut right), (l, r)| {
+                left.extend_from_slice(l);
+                right.extend_from_slice(r);
+                (left, right)
+            },
+        );
+        Ok((
+            Matrix::from_vec(self.rows(), lcols, left)?,
+            Matrix::from_vec(self.rows(), rcols, right)?,
+        ))
+    }
+
+    /// Calculate a basis of the null space (kernel) of a matrix.
+    ///
+    /// There is one basis vector for every column without a pivot in the reduced row echelon form.
+    /// The basis vectors are column vectors, the returned `Vec` is empty if the matrix has full column rank.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let mat_a = matrix!{1, 2, 3; 2, 4, 6};
+    /// assert_eq!(mat_a.null_space(), vec![vector![-2, 1, 0], vector![-3, 0, 1]]);
+    /// assert!(matrix!{1.0, 2.0; 3.0, 4.0}.null_space().is_empty());
+    /// ```
+    pub fn null_space(&self) -> Vec<Vector<T>> {
+        let (mat, pivots) = self.rref_with_pivots(PivotStrategy::FirstNonZero);
+        (0..self.cols())
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut basis_vector = vec![T::zero(); self.cols()];
+                basis_vector[free] = T::one();
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    basis_vector[pivot] = T::zero() - mat[row][free].clone();
+                }
+                Vector::from(basis_vector)
+            })
+            .collect()
+    }
+
+    /// Calculate a basis of the null space (kernel) of a matrix as the columns of a matrix.
+    ///
+    /// The columns are the vectors of [null_space](Matrix::null_space), so `self * kernel` is a zero matrix.
+    /// Returns `None` if the kernel is trivial, i.e. only contains the zero vector, because a matrix can't have zero columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3; 2, 4, 6};
+    /// let kernel = mat_a.kernel().unwrap();
+    /// assert_eq!(kernel, matrix!{-2, -3; 1, 0; 0, 1});
+    /// assert_eq!((mat_a * kernel)?, Matrix::zero(2, 2)?);
+    /// assert_eq!(matrix!{1.0, 2.0; 3.0, 4.0}.kernel(), None);
+    /// # Ok(()) }
+    /// ```
+    pub fn kernel(&self) -> Option<Matrix<T>> {
+        let basis = self.null_space();
+        if basis.is_empty() {
+            return None;
+        }
+        let entries = (0..self.cols())
+            .flat_map(|i| basis.iter().map(move |v| v[i].clone()))
+            .collect();
+        Matrix::from_vec(self.cols(), basis.len(), entries).ok()
+    }
+}
+
+/// Row echelon forms of matrices whose element type is not a field.
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// Convert every entry to `f64` and calculate the [reduced row echelon form](Matrix::rref) of the result.
+    ///
+    /// This is the way to reduce unsigned and other integer matrices, where [rref](Matrix::rref) would underflow
+    /// or truncate divisions. Entries that can't be represented as `f64` become `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a: Matrix<u32> = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.rref_as_f64(), matrix!{1.0, 0.0, -1.0; 0.0, 1.0, 2.0});
+    /// ```
+    pub fn rref_as_f64(&self) -> Matrix<f64> {
+        self.to_f64_matrix().rref()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::Matrix;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+#[derive(serde::Serialize)]
+struct MatrixRef<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T],
+}
+
+#[derive(serde::Deserialize)]
+struct MatrixRepr<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+/// A matrix is serialized as `{rows, cols, data}` with the entries in row-major order.
+/// The label is not serialized.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// let json = serde_json::to_string(&mat_a).unwrap();
+/// assert_eq!(json, r#"{"rows":2,"cols":2,"data":[1,2,3,4]}"#);
+/// ```
+impl<T> Serialize for Matrix<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        MatrixRef {
+            rows: self.rows(),
+            cols: self.cols(),
+            data: &self.matrix,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializing fails with a [DimensionError] message if a dimension is zero or
+/// the length of `data` is not `rows * cols`.
+impl<'de, T> Deserialize<'de> for Matrix<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Matrix<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = MatrixRepr::<T>::deserialize(deserializer)?;
+        if repr.rows == 0 || repr.cols == 0 {
+            return Err(D::Error::custom(DimensionError::InvalidDimensions));
+        }
+        let size = repr
+            .rows
+            .checked_mul(repr.cols)
+            .ok_or_else(|| D::Error::custom(DimensionError::InvalidDimensions))?;
+        if repr.data.len() != size {
+            return Err(D::Error::custom(DimensionError::InvalidInputDimensions(
+                repr.data.len(),
+                size,
+            )));
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(repr.rows, repr.cols),
+            matrix: repr.data,
+            label: None,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::err::DimensionError;
+use crate::mat::Matrix;
+use num_traits::ToPrimitive;
+
+impl<T> Matrix<T>
+where
+    T: ToPrimitive,
+{
+    /// Scale a nonnegative square matrix to a doubly stochastic matrix with the Sinkhorn-Knopp algorithm.
+    ///
+    /// Rows and columns are normalized alternately until every row and column sum is within `tol` of one,
+    /// or `iterations` rounds are done. In the latter case the last iterate is returned, so check the sums
+    /// if convergence matters. Matrices with zeros in the wrong places converge slowly or not at all.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices and [DimensionError::InvalidValue] if an entry
+    /// is negative or not finite, or a row or column only has zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// let balanced = mat_a.sinkhorn(100, 1e-12)?;
+    /// for sum in balanced.row_sums().iter().chain(balanced.col_sums().iter()) {
+    ///     assert!((sum - 1.0).abs() <= 1e-12);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn sinkhorn(&self, iterations: usize, tol: f64) -> Result<Matrix<f64>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let mut a = self.to_f64_matrix();
+        if let Some(idx) = a.matrix.iter().position(|x| !x.is_finite() || *x < 0.0) {
+            return Err(DimensionError::InvalidValue(format!(
+                "entry ({}, {}) is not a nonnegative number",
+                idx / a.cols(),
+                idx % a.cols()
+            )));
+        }
+        if let Some(i) = a.row_sums().iter().position(|s| *s == 0.0) {
+            return Err(DimensionError::InvalidValue(format!("row {} is zero", i)));
+        }
+        if let Some(j) = a.col_sums().iter().position(|s| *s == 0.0) {
+            return Err(DimensionError::InvalidValue(format!(
+                "column {} is zero",
+                j
+            )));
+        }
+
+        let dim = a.rows();
+        let converged = |a: &Matrix<f64>| {
+            a.row_sums()
+                .iter()
+                .chain(a.col_sums().iter())
+                .all(|s| (s - 1.0).abs() <= tol)
+        };
+        for _ in 0..iterations {
+            if converged(&a) {
+                break;
+            }
+            for (i, sum) in a.row_sums().iter().enumerate() {
+                a.matrix[i * dim..(i + 1) * dim]
+                    .iter_mut()
+                    .for_each(|x| *x /= sum);
+            }
+            let col_sums = a.col_sums();
+            for row in a.matrix.chunks_mut(dim) {
+                row.iter_mut()
+                    .zip(col_sums.iter())
+                    .for_each(|(x, sum)| *x /= sum);
+            }
+        }
+        Ok(a)
+    }
+}
@@ -1,30 +1,121 @@
-use crate::err::DimensionError;
-use crate::mat::Matrix;
+use crate::err::{DimensionError, ParseMatrixError};
+use crate::mat::dims::Dimensions;
+use crate::mat::{display, Matrix};
 use num_traits::identities::{One, Zero};
 use num_traits::ops::inv::Inv;
 use num_traits::sign::Signed;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::result::Result;
+use std::str::FromStr;
 
+/// Prints one row per line with every column right-aligned to its widest entry.
+///
+/// A label is printed on its own line first. The precision of the format string is applied to every entry.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{-12.5, 3.0; 1.0, 100.25};
+/// assert_eq!(mat_a.to_string(), "-12.5      3\n    1 100.25");
+/// assert_eq!(format!("{:.1}", mat_a), "-12.5   3.0\n  1.0 100.2");
+/// ```
 impl<T> Display for Matrix<T>
 where
     T: Display,
 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        for i in 0..self.rows() {
-            for j in 0..self.cols() {
-                let n = &self.matrix[i * self.cols() + j];
-                if j == self.cols() - 1 && i == self.rows() - 1 {
-                    write!(f, "{n}")?;
-                } else if j == self.cols() - 1 {
-                    writeln!(f, "{n}")?;
-                } else {
-                    write!(f, "{n}\t")?;
-                }
+        if let Some(label) = self.label() {
+            writeln!(f, "{label} =")?;
+        }
+        display::write_aligned(f, self.matrix.chunks(self.cols()))
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Display,
+{
+    /// Format the matrix on a single line, for example for logging.
+    ///
+    /// Unlike the [Display] output, the label is left out and the entries are not aligned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2; 3, 4}.with_label("a");
+    /// assert_eq!(mat_a.to_string_flat(), "[[1, 2], [3, 4]]");
+    /// ```
+    pub fn to_string_flat(&self) -> String {
+        display::flat_string(self.matrix.chunks(self.cols()))
+    }
+}
+
+/// Parses the row syntax of [matrix!](crate::matrix): rows are separated by `;` or line breaks and the entries of
+/// a row by commas and/or whitespace. Rows without entries, like after a trailing `;`, are skipped.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::ParseMatrixError;
+/// # fn main() -> Result<(), ParseMatrixError> {
+/// let mat_a: Matrix<f64> = "1 2 3; 4 5 6".parse()?;
+/// assert_eq!(mat_a, matrix!{1.0, 2.0, 3.0; 4.0, 5.0, 6.0});
+/// assert_eq!("1, 2\n3, 4".parse::<Matrix<i32>>()?, matrix!{1, 2; 3, 4});
+/// assert_eq!(
+///     "1 2; 3 x".parse::<Matrix<i32>>(),
+///     Err(ParseMatrixError::InvalidEntry { row: 1, col: 1, token: "x".to_owned() })
+/// );
+/// # Ok(()) }
+/// ```
+impl<T> FromStr for Matrix<T>
+where
+    T: FromStr,
+{
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Matrix<T>, ParseMatrixError> {
+        let rows = s
+            .split([';', '\n'])
+            .map(|row| {
+                row.split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|token| !token.is_empty())
+                    .collect::<Vec<&str>>()
+            })
+            .filter(|tokens| !tokens.is_empty())
+            .collect::<Vec<Vec<&str>>>();
+        let cols = match rows.first() {
+            Some(first) => first.len(),
+            None => return Err(ParseMatrixError::Empty),
+        };
+        let mut entries = Vec::with_capacity(rows.len() * cols);
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != cols {
+                return Err(ParseMatrixError::RaggedRow {
+                    row: i,
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            for (j, token) in row.iter().enumerate() {
+                entries.push(token.parse().map_err(|_| ParseMatrixError::InvalidEntry {
+                    row: i,
+                    col: j,
+                    token: (*token).to_owned(),
+                })?);
             }
         }
-        Ok(())
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows.len(), cols),
+            matrix: entries,
+            label: None,
+        })
     }
 }
 
@@ -47,44 +138,94 @@ where
     /// let mat_a: Matrix<f32> = matrix!{{0.0,-1.0,2.0},{1.0,2.0,0.0},{2.0,1.0,0.0}};
     /// let mat_c: Matrix<i32> = matrix!{{1,0,0},{0,1,0},{0,0,0}}; // not invertible
     /// let mat_b = matrix!{{0.0, -1.0/3.0, 2.0/3.0}, {0.0, 2.0/3.0, -1.0/3.0}, {1.0/2.0, 1.0/3.0, -1.0/6.0}};
-    /// assert_eq!(mat_a.inv()?, Some(mat_b));
+    /// assert!(mat_a.inv()?.unwrap().approx_eq(&mat_b, 1e-6));
     /// assert_eq!(mat_c.inv()?, None);
     /// # Ok(()) }
     /// ```
     fn inv(self) -> Self::Output {
         if let Some((mat, p)) = self.lupdecompose()? {
-            let dim = mat.rows();
-            let mut mat_inv = Matrix::<T>::zero(dim, dim).unwrap();
-            for j in 0..dim {
-                for i in 0..dim {
-                    mat_inv[i][j] = {
-                        if p[i] == j {
-                            T::one()
-                        } else {
-                            T::zero()
-                        }
-                    };
+            Ok(Some(Matrix::inv_from_lup(&mat, &p)))
+        } else {
+            Ok(None)
+        }
+    }
+}
 
-                    for k in 0..i {
-                        mat_inv[i][j] =
-                            mat_inv[i][j].clone() - mat[i][k].clone() * mat_inv[k][j].clone();
+impl<T> Matrix<T>
+where
+    T: One + Zero + Clone + Signed + std::ops::DivAssign,
+{
+    /// Calculate the inverse from the result of [lupdecompose](Matrix::lupdecompose).
+    pub(crate) fn inv_from_lup(mat: &Matrix<T>, p: &[usize]) -> Matrix<T> {
+        let dim = mat.rows();
+        let mut mat_inv = Matrix::<T>::zero(dim, dim).unwrap();
+        for j in 0..dim {
+            for i in 0..dim {
+                mat_inv[i][j] = {
+                    if p[i] == j {
+                        T::one()
+                    } else {
+                        T::zero()
                     }
-                }
+                };
 
-                for i in (0..dim).rev() {
-                    for k in (i + 1)..dim {
-                        mat_inv[i][j] =
-                            mat_inv[i][j].clone() - mat[i][k].clone() * mat_inv[k][j].clone();
-                    }
-                    mat_inv[i][j] /= mat[i][i].clone();
+                for k in 0..i {
+                    mat_inv[i][j] =
+                        mat_inv[i][j].clone() - mat[i][k].clone() * mat_inv[k][j].clone();
                 }
             }
-            if (p[dim] - dim) % 2 != 0 {
-                mat_inv.matrix.reverse();
+
+            for i in (0..dim).rev() {
+                for k in (i + 1)..dim {
+                    mat_inv[i][j] =
+                        mat_inv[i][j].clone() - mat[i][k].clone() * mat_inv[k][j].clone();
+                }
+                mat_inv[i][j] /= mat[i][i].clone();
             }
-            Ok(Some(mat_inv))
-        } else {
-            Ok(None)
         }
+        mat_inv
+    }
+}
+
+/// Consumes the matrix and yields its entries in row-major order.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// let entries: Vec<i32> = mat_a.into_iter().map(|x| x * 10).collect();
+/// assert_eq!(entries, vec![10, 20, 30, 40]);
+/// ```
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matrix.into_iter()
+    }
+}
+
+/// Yields references to the entries in row-major order.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// let mut sum = 0;
+/// for x in &mat_a {
+///     sum += x;
+/// }
+/// assert_eq!(sum, 10);
+/// ```
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matrix.iter()
     }
 }
@@ -22,6 +22,10 @@ impl Dimensions {
     pub fn cols(&self) -> usize {
         self.cols
     }
+    /// The number of rows and columns as a tuple `(rows, cols)`.
+    pub fn as_tuple(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
 }
 
 impl Display for Dimensions {
@@ -30,3 +34,50 @@ impl Display for Dimensions {
         Ok(())
     }
 }
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DimensionsRepr {
+    rows: usize,
+    cols: usize,
+}
+
+/// Dimensions are serialized as `{rows, cols}`.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::dims::Dimensions;
+/// let json = serde_json::to_string(&Dimensions::new(2, 3)).unwrap();
+/// assert_eq!(json, r#"{"rows":2,"cols":3}"#);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Dimensions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        DimensionsRepr {
+            rows: self.rows,
+            cols: self.cols,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializing fails with a [DimensionError](crate::err::DimensionError) message if a dimension is zero.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Dimensions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Dimensions, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = DimensionsRepr::deserialize(deserializer)?;
+        if repr.rows == 0 || repr.cols == 0 {
+            return Err(serde::de::Error::custom(
+                crate::err::DimensionError::InvalidDimensions,
+            ));
+        }
+        Ok(Dimensions::new(repr.rows, repr.cols))
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// Write the rows of a matrix with every column right-aligned to its widest entry.
+///
+/// Entries are separated by a single space and rows by a newline, without a newline after the last row.
+/// A precision given to the formatter, like in `{:.3}`, is applied to every entry.
+pub(crate) fn write_aligned<'a, T, R>(f: &mut Formatter, rows: R) -> Result
+where
+    T: Display + 'a,
+    R: Iterator<Item = &'a [T]>,
+{
+    let precision = f.precision();
+    let cells: Vec<Vec<String>> = rows
+        .map(|row| {
+            row.iter()
+                .map(|e| match precision {
+                    Some(p) => format!("{e:.p$}"),
+                    None => e.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    let cols = cells.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..cols)
+        .map(|j| {
+            cells
+                .iter()
+                .map(|row| row[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for (i, row) in cells.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        for (j, cell) in row.iter().enumerate() {
+            if j > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{cell:>width$}", width = widths[j])?;
+        }
+    }
+    Ok(())
+}
+
+/// Format the rows of a matrix on a single line, like `[[1, 2], [3, 4]]`.
+pub(crate) fn flat_string<'a, T, R>(rows: R) -> String
+where
+    T: Display + 'a,
+    R: Iterator<Item = &'a [T]>,
+{
+    let rows: Vec<String> = rows
+        .map(|row| {
+            let entries: Vec<String> = row.iter().map(ToString::to_string).collect();
+            format!("[{}]", entries.join(", "))
+        })
+        .collect();
+    format!("[{}]", rows.join(", "))
+}
@@ -0,0 +1,164 @@
+//! Content fingerprints that are stable across program runs and platforms, like [Matrix::content_hash].
+//!
+//! The fingerprint is the 64 bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of the row count and
+//! the column count, each as a little-endian `u64`, followed by the little-endian bytes of every entry in
+//! row-major order. Labels are ignored. A [Matrix], a [Vector] and an [SMatrix] with the same dimensions and entries
+//! have the same fingerprint.
+//!
+//! Floating point entries are hashed by their bit pattern, with `-0.0` replaced by `0.0` and every `NaN` by the
+//! canonical `NaN`, so matrices that compare equal have the same fingerprint.
+
+use crate::mat::{Matrix, SMatrix, Vector};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Entries that can be hashed by [content_hash](Matrix::content_hash).
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::fingerprint::Fingerprint;
+/// let mut bytes = Vec::new();
+/// 0x0102_i16.write_bytes(&mut |b| bytes.extend_from_slice(b));
+/// assert_eq!(bytes, vec![0x02, 0x01]);
+/// ```
+pub trait Fingerprint {
+    /// Pass the platform independent byte representation of `self` to `write`.
+    fn write_bytes(&self, write: &mut dyn FnMut(&[u8]));
+}
+
+macro_rules! impl_fingerprint_int {
+    ($($t:ty),*) => {
+        $(
+            impl Fingerprint for $t {
+                fn write_bytes(&self, write: &mut dyn FnMut(&[u8])) {
+                    write(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_fingerprint_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+// usize and isize are hashed as 64 bit integers, so the fingerprint doesn't depend on the pointer width.
+impl Fingerprint for usize {
+    fn write_bytes(&self, write: &mut dyn FnMut(&[u8])) {
+        (*self as u64).write_bytes(write);
+    }
+}
+
+impl Fingerprint for isize {
+    fn write_bytes(&self, write: &mut dyn FnMut(&[u8])) {
+        (*self as i64).write_bytes(write);
+    }
+}
+
+impl Fingerprint for bool {
+    fn write_bytes(&self, write: &mut dyn FnMut(&[u8])) {
+        write(&[*self as u8]);
+    }
+}
+
+macro_rules! impl_fingerprint_float {
+    ($($t:ty),*) => {
+        $(
+            impl Fingerprint for $t {
+                fn write_bytes(&self, write: &mut dyn FnMut(&[u8])) {
+                    let canonical = if self.is_nan() {
+                        <$t>::NAN
+                    } else if *self == 0.0 {
+                        0.0
+                    } else {
+                        *self
+                    };
+                    write(&canonical.to_bits().to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_fingerprint_float!(f32, f64);
+
+fn fnv1a<'a, T>(rows: usize, cols: usize, entries: impl Iterator<Item = &'a T>) -> u64
+where
+    T: Fingerprint + 'a,
+{
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut write = |bytes: &[u8]| {
+        for &b in bytes {
+            hash ^= u64::from(b);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+    rows.write_bytes(&mut write);
+    cols.write_bytes(&mut write);
+    entries.for_each(|x| x.write_bytes(&mut write));
+    hash
+}
+
+impl<T> Matrix<T>
+where
+    T: Fingerprint,
+{
+    /// Fingerprint of the dimensions and entries that is stable across program runs and platforms.
+    ///
+    /// See the [module documentation](crate::mat::fingerprint) for the algorithm. Unlike [Hash](std::hash::Hash)
+    /// this also works for floating point matrices and may be stored, for example as a cache key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// assert_eq!(mat_a.content_hash(), mat_a.clone().with_label("A").content_hash());
+    /// assert_ne!(mat_a.content_hash(), matrix!{1.0, 2.0, 3.0, 4.0}.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        fnv1a(self.rows(), self.cols(), self.matrix.iter())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Fingerprint,
+{
+    /// Fingerprint of the dimensions and entries, the same as [Matrix::content_hash] of the
+    /// equivalent one column or one row matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let vec_a = vector![1, 2, 3];
+    /// assert_eq!(vec_a.content_hash(), matrix!{1; 2; 3}.content_hash());
+    /// assert_ne!(vec_a.content_hash(), vec_a.to_row_vector().content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        fnv1a(self.dims.rows(), self.dims.cols(), self.entries.iter())
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Fingerprint,
+{
+    /// Fingerprint of the dimensions and entries, the same as [Matrix::content_hash] of a dynamic matrix
+    /// with the same entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, SMatrix};
+    /// # use libmat::matrix;
+    /// let mat_a = SMatrix::from_rows([[1_u8, 2], [3, 4]]);
+    /// assert_eq!(mat_a.content_hash(), matrix!{1_u8, 2; 3, 4}.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        fnv1a(M, N, self.data.iter().flatten())
+    }
+}
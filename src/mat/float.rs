@@ -0,0 +1,61 @@
+//! The floating point type that numerical routines like
+//! [Matrix::gram_schmidt](crate::mat::Matrix::gram_schmidt) or [Vector::norm](crate::mat::Vector::norm) compute in.
+//!
+//! Floating point entries are used as they are, so `f32` matrices stay in single precision, and integers
+//! are converted to `f64`. Tolerances of these routines are relative to the [epsilon](Float::epsilon) of that type.
+
+use num_traits::{Float, Signed};
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+/// Entries that can be converted to the floating point type [Float](ToFloat::Float).
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::float::ToFloat;
+/// let x: f32 = 1.5_f32.to_float();
+/// let y: f64 = 3_i32.to_float();
+/// assert_eq!((x, y), (1.5, 3.0));
+/// ```
+pub trait ToFloat: Copy {
+    /// The floating point type computations on `Self` are done in.
+    type Float: Float
+        + Signed
+        + Sum
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + DivAssign
+        + Debug
+        + Display
+        + ToFloat<Float = Self::Float>
+        + 'static;
+
+    /// Whether `Self` is a floating point type itself, so its arithmetic rounds and comparisons with zero
+    /// need a tolerance.
+    const IS_FLOAT: bool = false;
+
+    /// Convert `self` to [Float](ToFloat::Float), rounding if it can't be represented exactly.
+    fn to_float(&self) -> Self::Float;
+}
+
+macro_rules! impl_to_float {
+    ($float:ty, $is_float:literal; $($t:ty),*) => {
+        $(
+            impl ToFloat for $t {
+                type Float = $float;
+                const IS_FLOAT: bool = $is_float;
+
+                fn to_float(&self) -> $float {
+                    *self as $float
+                }
+            }
+        )*
+    };
+}
+
+impl_to_float!(f32, true; f32);
+impl_to_float!(f64, true; f64);
+impl_to_float!(f64, false; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,182 @@
+//! Preconditioners for the iterative solvers, like [Matrix::conjugate_gradient_preconditioned].
+//!
+//! A preconditioner `M` approximates a matrix `A`, such that `M^-1 * r` is cheap to compute
+//! and `M^-1 * A` is better conditioned than `A`.
+
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::{Matrix, Vector};
+
+/// Approximate inverse of a matrix, applied to residual vectors by the iterative solvers.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Vector;
+/// # use libmat::mat::precond::Preconditioner;
+/// # use libmat::vector;
+/// # use libmat::err::DimensionError;
+/// /// Does nothing, so the preconditioned solvers behave like the plain ones.
+/// struct Identity;
+///
+/// impl Preconditioner for Identity {
+///     fn apply(&self, r: &Vector<f64>) -> Result<Vector<f64>, DimensionError> {
+///         Ok(r.clone())
+///     }
+/// }
+///
+/// assert_eq!(Identity.apply(&vector![1.0, 2.0]), Ok(vector![1.0, 2.0]));
+/// ```
+pub trait Preconditioner {
+    /// Calculate `M^-1 * r`.
+    ///
+    /// Returns [DimensionError::NoMatch] if `r` doesn't have the size of the matrix `M` approximates.
+    fn apply(&self, r: &Vector<f64>) -> Result<Vector<f64>, DimensionError>;
+}
+
+/// Jacobi preconditioner, which multiplies with the inverse of the diagonal of a matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JacobiPreconditioner {
+    inv_diag: Vec<f64>,
+}
+
+impl JacobiPreconditioner {
+    /// Create a Jacobi preconditioner from the diagonal of a square matrix.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices and [DimensionError::InvalidValue]
+    /// if a diagonal entry is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::mat::precond::{JacobiPreconditioner, Preconditioner};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let precond = JacobiPreconditioner::from_matrix(&matrix!{2.0, 1.0; 1.0, 4.0})?;
+    /// assert_eq!(precond.apply(&vector![1.0, 1.0])?, vector![0.5, 0.25]);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_matrix(mat: &Matrix<f64>) -> Result<JacobiPreconditioner, DimensionError> {
+        if !mat.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let mut inv_diag = Vec::with_capacity(mat.rows());
+        for i in 0..mat.rows() {
+            if mat[i][i] == 0.0 {
+                return Err(DimensionError::InvalidValue(format!(
+                    "diagonal entry {} is zero",
+                    i
+                )));
+            }
+            inv_diag.push(1.0 / mat[i][i]);
+        }
+        Ok(JacobiPreconditioner { inv_diag })
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &Vector<f64>) -> Result<Vector<f64>, DimensionError> {
+        let n = self.inv_diag.len();
+        if r.size() != n {
+            return Err(DimensionError::NoMatch(
+                Dimensions::new(n, n),
+                r.dims,
+                "multiply".to_owned(),
+            ));
+        }
+        Ok(Vector::<f64> {
+            dims: r.dims,
+            entries: r
+                .iter()
+                .zip(self.inv_diag.iter())
+                .map(|(x, d)| x * d)
+                .collect(),
+        })
+    }
+}
+
+/// Incomplete LU preconditioner without fill-in (ILU(0)).
+///
+/// The factors `L` and `U` have nonzero entries only where the original matrix has them,
+/// so for banded matrices they stay banded. The factors are stored densely for now.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ilu0Preconditioner {
+    lu: Matrix<f64>,
+}
+
+impl Ilu0Preconditioner {
+    /// Calculate the incomplete LU factorization of a square matrix.
+    ///
+    /// Returns [DimensionError::NoSquare] for non-square matrices and [DimensionError::InvalidValue]
+    /// if a zero pivot occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::mat::precond::{Ilu0Preconditioner, Preconditioner};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // for tridiagonal matrices ILU(0) is the exact LU decomposition
+    /// let mat_a = matrix!{2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0};
+    /// let precond = Ilu0Preconditioner::from_matrix(&mat_a)?;
+    /// let x = precond.apply(&vector![1.0, 0.0, 1.0])?;
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12 && (x[2] - 1.0).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_matrix(mat: &Matrix<f64>) -> Result<Ilu0Preconditioner, DimensionError> {
+        if !mat.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let n = mat.rows();
+        let mut lu = mat.clone();
+        for i in 0..n {
+            for k in 0..i {
+                if mat[i][k] == 0.0 {
+                    continue;
+                }
+                if lu[k][k] == 0.0 {
+                    return Err(DimensionError::InvalidValue(format!("pivot {} is zero", k)));
+                }
+                lu[i][k] /= lu[k][k];
+                for j in (k + 1)..n {
+                    if mat[i][j] != 0.0 {
+                        lu[i][j] -= lu[i][k] * lu[k][j];
+                    }
+                }
+            }
+        }
+        if let Some(i) = (0..n).find(|&i| lu[i][i] == 0.0) {
+            return Err(DimensionError::InvalidValue(format!("pivot {} is zero", i)));
+        }
+        Ok(Ilu0Preconditioner { lu })
+    }
+}
+
+impl Preconditioner for Ilu0Preconditioner {
+    fn apply(&self, r: &Vector<f64>) -> Result<Vector<f64>, DimensionError> {
+        let n = self.lu.rows();
+        if r.size() != n {
+            return Err(self.lu.no_match(r.dims, None, "multiply"));
+        }
+        let mut x = r.entries.clone();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] -= self.lu[i][k] * x[k];
+            }
+        }
+        for i in (0..n).rev() {
+            for k in (i + 1)..n {
+                x[i] -= self.lu[i][k] * x[k];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        Ok(Vector::<f64> {
+            dims: r.dims,
+            entries: x,
+        })
+    }
+}
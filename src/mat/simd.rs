@@ -0,0 +1,413 @@
+//! SIMD kernels for entrywise arithmetic on the storage of matrices.
+//!
+//! The kernels use AVX for `f32` and `f64` and AVX2 for `i64`. Whether the CPU supports them is checked at runtime,
+//! so the crate runs on every machine and callers fall back to their generic loop if a function returns `false`.
+//! Every lane does the same IEEE operation as the scalar code, so the results are bit for bit the same.
+//!
+//! The `i64` kernels check the full vectors for overflow and return `false` with `a` unchanged if one of them
+//! overflows, so the generic loop panics or wraps, depending on whether overflow checks are enabled.
+//!
+//! The generic entry points pick the kernel by comparing type ids. Stable Rust can't specialize the generic
+//! operators for `f64`, `f32` and `i64`, and [TypeId::of] would need `T: 'static`, which the operators don't
+//! require, so [type_id] computes it without that bound.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+/// Helper for [type_id]: the method only needs `Self: 'static` when it is called.
+trait NonStaticAny {
+    fn get_type_id(&self) -> TypeId
+    where
+        Self: 'static;
+}
+
+impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+    fn get_type_id(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<T>()
+    }
+}
+
+/// The [TypeId] of `T` with all lifetimes erased, also if `T` isn't `'static`.
+///
+/// A type that borrows data never has the same id as `f64`, `f32` or `i64`, so comparing with
+/// their ids tells exactly whether `T` is one of them.
+fn type_id<T: ?Sized>() -> TypeId {
+    let phantom = PhantomData::<T>;
+    let erased: &dyn NonStaticAny = &phantom;
+    // SAFETY: lifetimes don't exist at runtime, so extending the lifetime of the trait object only changes
+    // which methods the compiler lets us call. `get_type_id` doesn't touch any data of `T`.
+    let erased: &(dyn NonStaticAny + 'static) = unsafe { std::mem::transmute(erased) };
+    erased.get_type_id()
+}
+
+/// Reinterpret `a` as a slice of `U`, if `T` and `U` are the same type.
+fn cast_mut<T, U: 'static>(a: &mut [T]) -> Option<&mut [U]> {
+    if type_id::<T>() == TypeId::of::<U>() {
+        // SAFETY: `T` and `U` are the same type.
+        Some(unsafe { &mut *(a as *mut [T] as *mut [U]) })
+    } else {
+        None
+    }
+}
+
+/// Reinterpret `a` as a slice of `U`, if `T` and `U` are the same type.
+fn cast<T, U: 'static>(a: &[T]) -> Option<&[U]> {
+    if type_id::<T>() == TypeId::of::<U>() {
+        // SAFETY: `T` and `U` are the same type.
+        Some(unsafe { &*(a as *const [T] as *const [U]) })
+    } else {
+        None
+    }
+}
+
+/// Compute `a[i] += b[i]` for all `i`. Returns `false` without changing `a` if there is no kernel for `T`
+/// or it can't be used, see the typed functions like [add_assign_f64].
+pub(crate) fn add_assign<T>(a: &mut [T], b: &[T]) -> bool {
+    if let (Some(a), Some(b)) = (cast_mut::<T, f64>(a), cast::<T, f64>(b)) {
+        return add_assign_f64(a, b);
+    }
+    if let (Some(a), Some(b)) = (cast_mut::<T, f32>(a), cast::<T, f32>(b)) {
+        return add_assign_f32(a, b);
+    }
+    if let (Some(a), Some(b)) = (cast_mut::<T, i64>(a), cast::<T, i64>(b)) {
+        return add_assign_i64(a, b);
+    }
+    false
+}
+
+/// Compute `a[i] -= b[i]` for all `i`. Returns `false` without changing `a` if there is no kernel for `T`
+/// or it can't be used, see the typed functions like [sub_assign_f64].
+pub(crate) fn sub_assign<T>(a: &mut [T], b: &[T]) -> bool {
+    if let (Some(a), Some(b)) = (cast_mut::<T, f64>(a), cast::<T, f64>(b)) {
+        return sub_assign_f64(a, b);
+    }
+    if let (Some(a), Some(b)) = (cast_mut::<T, f32>(a), cast::<T, f32>(b)) {
+        return sub_assign_f32(a, b);
+    }
+    if let (Some(a), Some(b)) = (cast_mut::<T, i64>(a), cast::<T, i64>(b)) {
+        return sub_assign_i64(a, b);
+    }
+    false
+}
+
+/// Compute `a[i] *= b` for all `i`. Returns `false` without changing `a` if there is no kernel for `T`
+/// or AVX isn't available.
+pub(crate) fn mul_assign_scalar<T>(a: &mut [T], b: &T) -> bool {
+    let b = std::slice::from_ref(b);
+    if let (Some(a), Some(b)) = (cast_mut::<T, f64>(a), cast::<T, f64>(b)) {
+        return mul_assign_scalar_f64(a, b[0]);
+    }
+    if let (Some(a), Some(b)) = (cast_mut::<T, f32>(a), cast::<T, f32>(b)) {
+        return mul_assign_scalar_f32(a, b[0]);
+    }
+    false
+}
+
+macro_rules! dispatch_binary {
+    ($(#[$doc:meta])* $name:ident, $t:ty, $feature:tt, $kernel:ident) => {
+        $(#[$doc])*
+        fn $name(a: &mut [$t], b: &[$t]) -> bool {
+            assert_eq!(a.len(), b.len());
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                if is_x86_feature_detected!($feature) {
+                    // SAFETY: the feature is available and the slices have the same length.
+                    return unsafe { x86::$kernel(a, b) };
+                }
+            }
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            let _ = (a, b);
+            false
+        }
+    };
+}
+
+dispatch_binary!(
+    /// Compute `a[i] += b[i]` for all `i`. Returns `false` without changing `a` if AVX isn't available.
+    add_assign_f64, f64, "avx", add_assign_f64
+);
+dispatch_binary!(
+    /// Compute `a[i] -= b[i]` for all `i`. Returns `false` without changing `a` if AVX isn't available.
+    sub_assign_f64, f64, "avx", sub_assign_f64
+);
+dispatch_binary!(
+    /// Compute `a[i] += b[i]` for all `i`. Returns `false` without changing `a` if AVX isn't available.
+    add_assign_f32, f32, "avx", add_assign_f32
+);
+dispatch_binary!(
+    /// Compute `a[i] -= b[i]` for all `i`. Returns `false` without changing `a` if AVX isn't available.
+    sub_assign_f32, f32, "avx", sub_assign_f32
+);
+dispatch_binary!(
+    /// Compute `a[i] += b[i]` for all `i`. Returns `false` without changing `a` if AVX2 isn't available
+    /// or a sum in the full vectors overflows.
+    add_assign_i64, i64, "avx2", add_assign_i64
+);
+dispatch_binary!(
+    /// Compute `a[i] -= b[i]` for all `i`. Returns `false` without changing `a` if AVX2 isn't available
+    /// or a difference in the full vectors overflows.
+    sub_assign_i64, i64, "avx2", sub_assign_i64
+);
+
+macro_rules! dispatch_scalar {
+    ($(#[$doc:meta])* $name:ident, $t:ty, $kernel:ident) => {
+        $(#[$doc])*
+        fn $name(a: &mut [$t], b: $t) -> bool {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                if is_x86_feature_detected!("avx") {
+                    // SAFETY: AVX is available.
+                    unsafe { x86::$kernel(a, b) };
+                    return true;
+                }
+            }
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            let _ = (a, b);
+            false
+        }
+    };
+}
+
+// There is no kernel for `i64`, because AVX2 can't multiply 64 bit integers.
+dispatch_scalar!(
+    /// Compute `a[i] *= b` for all `i`. Returns `false` without changing `a` if AVX isn't available.
+    mul_assign_scalar_f64, f64, mul_assign_scalar_f64
+);
+dispatch_scalar!(
+    /// Compute `a[i] *= b` for all `i`. Returns `false` without changing `a` if AVX isn't available.
+    mul_assign_scalar_f32, f32, mul_assign_scalar_f32
+);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    /// Define a kernel that applies `$op` to full vectors of `a` and `b` with unaligned loads and stores
+    /// and `$scalar` to the remaining entries at the end. The caller ensures `a.len() == b.len()`.
+    macro_rules! binary_kernel {
+        ($name:ident, $t:ty, $feature:literal, $lanes:literal, $load:ident, $store:ident, $cast:ty, $op:ident, $scalar:tt) => {
+            #[target_feature(enable = $feature)]
+            pub(super) unsafe fn $name(a: &mut [$t], b: &[$t]) -> bool {
+                let full = a.len() / $lanes * $lanes;
+                for i in (0..full).step_by($lanes) {
+                    let pa = a.as_mut_ptr().add(i) as *mut $cast;
+                    let pb = b.as_ptr().add(i) as *const $cast;
+                    $store(pa, $op($load(pa), $load(pb)));
+                }
+                for (x, y) in a[full..].iter_mut().zip(&b[full..]) {
+                    *x $scalar *y;
+                }
+                true
+            }
+        };
+    }
+
+    binary_kernel!(add_assign_f64, f64, "avx", 4, _mm256_loadu_pd, _mm256_storeu_pd, f64, _mm256_add_pd, +=);
+    binary_kernel!(sub_assign_f64, f64, "avx", 4, _mm256_loadu_pd, _mm256_storeu_pd, f64, _mm256_sub_pd, -=);
+    binary_kernel!(add_assign_f32, f32, "avx", 8, _mm256_loadu_ps, _mm256_storeu_ps, f32, _mm256_add_ps, +=);
+    binary_kernel!(sub_assign_f32, f32, "avx", 8, _mm256_loadu_ps, _mm256_storeu_ps, f32, _mm256_sub_ps, -=);
+
+    /// Define an `i64` kernel like `binary_kernel`, which also collects the lanes whose result overflowed in
+    /// the sign bits of the `$overflow` expression. If any full vector overflowed, the wrapped results are
+    /// undone with `$undo` and the kernel returns `false` before touching the remaining entries.
+    macro_rules! checked_kernel {
+        ($name:ident, $op:ident, |$x:ident, $y:ident, $res:ident| $overflow:expr, $undo:ident, $scalar:tt) => {
+            #[target_feature(enable = "avx2")]
+            pub(super) unsafe fn $name(a: &mut [i64], b: &[i64]) -> bool {
+                let full = a.len() / 4 * 4;
+                let mut overflow = _mm256_setzero_si256();
+                for i in (0..full).step_by(4) {
+                    let pa = a.as_mut_ptr().add(i) as *mut __m256i;
+                    let $x = _mm256_loadu_si256(pa);
+                    let $y = _mm256_loadu_si256(b.as_ptr().add(i) as *const __m256i);
+                    let $res = $op($x, $y);
+                    overflow = _mm256_or_si256(overflow, $overflow);
+                    _mm256_storeu_si256(pa, $res);
+                }
+                if _mm256_movemask_pd(_mm256_castsi256_pd(overflow)) != 0 {
+                    // wrapping arithmetic is undone exactly
+                    for (x, y) in a[..full].iter_mut().zip(&b[..full]) {
+                        *x = x.$undo(*y);
+                    }
+                    return false;
+                }
+                for (x, y) in a[full..].iter_mut().zip(&b[full..]) {
+                    *x $scalar *y;
+                }
+                true
+            }
+        };
+    }
+
+    // a sum overflows if it has a different sign than both operands
+    checked_kernel!(add_assign_i64, _mm256_add_epi64,
+        |x, y, res| _mm256_and_si256(_mm256_xor_si256(x, res), _mm256_xor_si256(y, res)),
+        wrapping_sub, +=);
+    // a difference overflows if the operands have different signs and it has a different sign than `x`
+    checked_kernel!(sub_assign_i64, _mm256_sub_epi64,
+        |x, y, res| _mm256_and_si256(_mm256_xor_si256(x, y), _mm256_xor_si256(x, res)),
+        wrapping_add, -=);
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn mul_assign_scalar_f64(a: &mut [f64], b: f64) {
+        let full = a.len() / 4 * 4;
+        let vb = _mm256_set1_pd(b);
+        for i in (0..full).step_by(4) {
+            let pa = a.as_mut_ptr().add(i);
+            _mm256_storeu_pd(pa, _mm256_mul_pd(_mm256_loadu_pd(pa), vb));
+        }
+        a[full..].iter_mut().for_each(|x| *x *= b);
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn mul_assign_scalar_f32(a: &mut [f32], b: f32) {
+        let full = a.len() / 8 * 8;
+        let vb = _mm256_set1_ps(b);
+        for i in (0..full).step_by(8) {
+            let pa = a.as_mut_ptr().add(i);
+            _mm256_storeu_ps(pa, _mm256_mul_ps(_mm256_loadu_ps(pa), vb));
+        }
+        a[full..].iter_mut().for_each(|x| *x *= b);
+    }
+}
+
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod tests {
+    use super::x86;
+
+    /// Lengths with and without a partial vector at the end.
+    const LENGTHS: [usize; 6] = [0, 1, 3, 4, 9, 17];
+
+    fn floats(len: usize, seed: f64) -> Vec<f64> {
+        (0..len)
+            .map(|i| ((i as f64 + seed) * 0.731).sin() * 1e3)
+            .collect()
+    }
+
+    #[test]
+    fn f64_kernels_match_scalar_loop() {
+        if !is_x86_feature_detected!("avx") {
+            return;
+        }
+        for &len in LENGTHS.iter() {
+            let (a, b) = (floats(len, 0.0), floats(len, 0.5));
+            let mut res = a.clone();
+            unsafe { x86::add_assign_f64(&mut res, &b) };
+            assert_eq!(
+                res,
+                a.iter().zip(&b).map(|(x, y)| x + y).collect::<Vec<_>>()
+            );
+            let mut res = a.clone();
+            unsafe { x86::sub_assign_f64(&mut res, &b) };
+            assert_eq!(
+                res,
+                a.iter().zip(&b).map(|(x, y)| x - y).collect::<Vec<_>>()
+            );
+            let mut res = a.clone();
+            unsafe { x86::mul_assign_scalar_f64(&mut res, -0.3) };
+            assert_eq!(res, a.iter().map(|x| x * -0.3).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn f32_kernels_match_scalar_loop() {
+        if !is_x86_feature_detected!("avx") {
+            return;
+        }
+        for &len in LENGTHS.iter() {
+            let a: Vec<f32> = floats(len, 1.0).iter().map(|&x| x as f32).collect();
+            let b: Vec<f32> = floats(len, 2.0).iter().map(|&x| x as f32).collect();
+            let mut res = a.clone();
+            unsafe { x86::add_assign_f32(&mut res, &b) };
+            assert_eq!(
+                res,
+                a.iter().zip(&b).map(|(x, y)| x + y).collect::<Vec<_>>()
+            );
+            let mut res = a.clone();
+            unsafe { x86::sub_assign_f32(&mut res, &b) };
+            assert_eq!(
+                res,
+                a.iter().zip(&b).map(|(x, y)| x - y).collect::<Vec<_>>()
+            );
+            let mut res = a.clone();
+            unsafe { x86::mul_assign_scalar_f32(&mut res, 1.5) };
+            assert_eq!(res, a.iter().map(|x| x * 1.5).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn i64_kernels_match_scalar_loop() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        for &len in LENGTHS.iter() {
+            let a: Vec<i64> = (0..len as i64).map(|i| i * 7919 - 10005).collect();
+            let b: Vec<i64> = (0..len as i64).map(|i| 20011 - i * 31).collect();
+            let mut res = a.clone();
+            assert!(unsafe { x86::add_assign_i64(&mut res, &b) });
+            assert_eq!(
+                res,
+                a.iter().zip(&b).map(|(x, y)| x + y).collect::<Vec<_>>()
+            );
+            let mut res = a.clone();
+            assert!(unsafe { x86::sub_assign_i64(&mut res, &b) });
+            assert_eq!(
+                res,
+                a.iter().zip(&b).map(|(x, y)| x - y).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn i64_kernels_leave_overflowing_input_unchanged() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        // the entries at index 6 are in the second full vector, after the first one was already stored
+        let operands = |x, y| {
+            let mut a: Vec<i64> = (0..9).collect();
+            let mut b: Vec<i64> = (0..9).map(|i| i * 3).collect();
+            a[6] = x;
+            b[6] = y;
+            (a, b)
+        };
+        for &(x, y) in [
+            (i64::MAX, 1),
+            (i64::MIN, -1),
+            (-2, i64::MIN),
+            (i64::MAX, -1),
+        ]
+        .iter()
+        {
+            let (a, b) = operands(x, y);
+            let mut res = a.clone();
+            let done = unsafe { x86::add_assign_i64(&mut res, &b) };
+            match x.checked_add(y) {
+                Some(sum) => assert!(done && res[6] == sum),
+                None => assert!(!done && res == a),
+            }
+        }
+        for &(x, y) in [
+            (i64::MAX, -1),
+            (i64::MIN, 1),
+            (-2, i64::MAX),
+            (i64::MIN, -1),
+        ]
+        .iter()
+        {
+            let (a, b) = operands(x, y);
+            let mut res = a.clone();
+            let done = unsafe { x86::sub_assign_i64(&mut res, &b) };
+            match x.checked_sub(y) {
+                Some(diff) => assert!(done && res[6] == diff),
+                None => assert!(!done && res == a),
+            }
+        }
+    }
+}
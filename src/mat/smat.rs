@@ -1,3 +1,6 @@
 mod smat_impl;
 mod smat_ops;
+mod smat_rref;
+#[cfg(feature = "serde")]
+mod smat_serde;
 mod smat_traits;
@@ -1,8 +1,61 @@
-use crate::mat::SMatrix;
-use num_traits::cast::ToPrimitive;
+use crate::mat::float::ToFloat;
+use crate::mat::{Matrix, SColVector, SMatrix};
+use num_traits::cast::{NumCast, ToPrimitive};
+use num_traits::float::Float;
 use num_traits::identities::{One, Zero};
 use num_traits::sign::Signed;
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Construction from arrays.
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N> {
+    /// Create a matrix from an array of rows.
+    ///
+    /// This is the same as [From], but the dimensions are always those of the array. `SMatrix::from` can also
+    /// build a column vector from a flat array, so it needs a type annotation where this method doesn't.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// let mat_a = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!((mat_a.rows(), mat_a.cols()), (2, 3));
+    /// ```
+    pub fn from_rows(rows: [[T; N]; M]) -> SMatrix<T, M, N> {
+        SMatrix::<T, M, N> { data: rows }
+    }
+}
+
+/// Conversion from dynamic matrices.
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Clone,
+{
+    /// Copy a dynamic matrix into the top left corner of an `M x N` matrix.
+    ///
+    /// Entries outside of `mat` are set to `fill`, rows and columns of `mat` beyond `M` and `N` are dropped.
+    /// This never fails, so it's meant for the cases where differing dimensions are expected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, SMatrix};
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// let padded: SMatrix<i32, 3, 2> = SMatrix::from_matrix_padded(&mat_a, 0);
+    /// assert_eq!(padded, SMatrix::from_rows([[1, 2], [4, 5], [0, 0]]));
+    /// ```
+    pub fn from_matrix_padded(mat: &Matrix<T>, fill: T) -> SMatrix<T, M, N> {
+        SMatrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                if i < mat.rows() && j < mat.cols() {
+                    mat[i][j].clone()
+                } else {
+                    fill.clone()
+                }
+            })
+        }))
+    }
+}
 
 /// Methods for matrices with general dimensions.
 impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
@@ -27,7 +80,7 @@ where
     pub fn new(init: T) -> SMatrix<T, M, N> {
         if M > 0 && N > 0 {
             SMatrix::<T, M, N> {
-                data: Box::new([[init; N]; M]),
+                data: [[init; N]; M],
             }
         } else {
             panic!("NOOO")
@@ -44,6 +97,19 @@ where
         N
     }
 
+    /// Get the number of rows and columns as a tuple, `(M, N)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// let mat_a = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat_a.shape(), (2, 3));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        (M, N)
+    }
+
     /// Returns true if the matrix is a square matrix, false otherwise.
     pub fn is_square(&self) -> bool {
         M == N
@@ -76,109 +142,162 @@ where
         }
         res
     }
+
+    /// Multiply two matrices entry by entry (Hadamard product), see [Matrix::hadamard](crate::mat::Matrix::hadamard).
+    ///
+    /// The dimensions are checked at compile time, so there is no error case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<i32, 2, 3> = smatrix!{1, 2, 3; 4, 5, 6};
+    /// let mat_b: SMatrix<i32, 2, 3> = smatrix!{2, 0, -1; 1, 1, 0};
+    /// assert_eq!(mat_a.hadamard(&mat_b), smatrix!{2, 0, -3; 4, 5, 0});
+    /// ```
+    pub fn hadamard(&self, other: &SMatrix<T, M, N>) -> SMatrix<T, M, N>
+    where
+        T: Mul<Output = T>,
+    {
+        SMatrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self[i][j] * other[i][j])
+        }))
+    }
 }
 
 impl<T, const N: usize> SMatrix<T, N, N>
 where
     T: Sub<Output = T> + Add<Output = T> + Mul<Output = T> + ToPrimitive,
 {
-    pub fn lupdecompose(&self) -> Option<(SMatrix<f64, N, N>, Vec<usize>)>
+    /// Calculate the LUP decomposition of a square matrix with partial pivoting, in the
+    /// [floating point type](ToFloat) of the entries.
+    ///
+    /// Returns the matrix holding `L` and `U`, the permutation `p` and the number of swaps that were needed,
+    /// or `None` if a pivot is not larger than `N` times the epsilon of the floating point type times the largest
+    /// absolute entry of its column, so the matrix is singular up to rounding. Like [Matrix::lupdecompose](crate::mat::Matrix::lupdecompose),
+    /// row `i` of `L * U` is row `p[i]` of `self`. Nothing is allocated on the heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<f32, 2, 2> = smatrix!{1.0, 2.0; 4.0, 2.0};
+    /// let (lu, p, swaps): (SMatrix<f32, 2, 2>, _, _) = mat_a.lupdecompose().unwrap();
+    /// assert_eq!(lu, smatrix!{4.0, 2.0; 0.25, 1.5});
+    /// assert_eq!((p, swaps), ([1, 0], 1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn lupdecompose(&self) -> Option<(SMatrix<T::Float, N, N>, [usize; N], usize)>
     where
-        T: Signed,
+        T: Signed + ToFloat,
     {
-        let mut a: SMatrix<f64, N, N> = SMatrix::new(f64::default());
-        for rs in a.iter_mut() {
-            for es in rs.iter_mut() {
-                *es = es.to_f64().unwrap();
-            }
-        }
+        let mut a: SMatrix<T::Float, N, N> = SMatrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self[i][j].to_float())
+        }));
         let dim = N;
         let mut imax: usize;
-        let mut max_a: f64;
-        let mut p: Vec<usize> = (0..=dim).collect();
+        let mut max_a: T::Float;
+        let mut p = [0; N];
+        for (i, pi) in p.iter_mut().enumerate() {
+            *pi = i;
+        }
+        let mut swaps = 0;
+        // row swaps keep the columns, so every pivot is compared with the largest entry of its column
+        let scale = T::Float::epsilon() * <T::Float as NumCast>::from(N).unwrap();
+        let tol: [T::Float; N] = std::array::from_fn(|j| {
+            a.data
+                .iter()
+                .fold(T::Float::zero(), |acc, row| acc.max(row[j].abs()))
+                * scale
+        });
 
         for i in 0..dim {
-            max_a = f64::default();
+            max_a = T::Float::zero();
             imax = i;
 
             for k in i..dim {
-                if a[i][k].abs() > max_a {
-                    max_a = a[i][k].abs();
+                if a[k][i].abs() > max_a {
+                    max_a = a[k][i].abs();
                     imax = k;
                 }
             }
 
-            if max_a < 0.000001 {
+            if max_a <= tol[i] {
                 return None;
             }
 
             if imax != i {
                 p.swap(i, imax);
-
-                let mut t_ij: SMatrix<f64, N, N> = SMatrix::one();
-                t_ij[i][i] = f64::zero();
-                t_ij[imax][imax] = f64::zero();
-                t_ij[i][imax] = f64::one();
-                t_ij[imax][i] = f64::one();
-                // switch rows i and imax
-                a = a * t_ij;
-
-                p[dim] += 1;
+                a.swap(i, imax);
+                swaps += 1;
             }
 
             for j in (i + 1)..dim {
-                a[j][i] /= a[i][i];
+                a[j][i] = a[j][i] / a[i][i];
                 for k in (i + 1)..dim {
-                    a[j][k] -= a[j][i] * a[i][k];
+                    a[j][k] = a[j][k] - a[j][i] * a[i][k];
                 }
             }
         }
-        Some((a, p))
+        Some((a, p, swaps))
     }
-    fn det_approx(&self) -> f64
+    #[allow(clippy::manual_is_multiple_of)]
+    fn det_approx(&self) -> T::Float
     where
-        T: Signed,
+        T: Signed + ToFloat,
     {
-        if let Some((mat, p)) = self.lupdecompose() {
+        if let Some((mat, _, swaps)) = self.lupdecompose() {
             let mut det = mat[0][0];
             for i in 1..N {
                 det *= mat[i][i];
             }
-            if (p[N] - N) % 2 == 0 {
+            if swaps % 2 == 0 {
                 det
             } else {
                 -det
             }
         } else {
-            f64::zero()
+            T::Float::zero()
         }
     }
-    pub fn det(&self) -> f64
+
+    /// Calculate the determinant in the [floating point type](ToFloat) of the entries.
+    ///
+    /// Matrices up to `3x3` use the explicit formula in `T`, so integer determinants are exact there,
+    /// larger ones the [LUP decomposition](SMatrix::lupdecompose).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<f32, 2, 2> = smatrix!{1.0, 2.0; 3.0, 4.0};
+    /// let det: f32 = mat_a.det();
+    /// assert_eq!(det, -2.0);
+    /// ```
+    pub fn det(&self) -> T::Float
     where
-        T: Copy + Signed,
+        T: Copy + Signed + ToFloat,
     {
         if N < 4 {
-            match {
-                if N == 1 {
-                    self[0][0].to_f64()
-                } else if N == 2 {
-                    { self[0][0] * self[1][1] - self[0][1] * self[1][0] }.to_f64()
-                } else if N == 3 {
-                    {
-                        self[0][0] * self[1][1] * self[2][2]
-                            + self[1][0] * self[2][1] * self[0][2]
-                            + self[2][0] * self[0][1] * self[1][2]
-                            - self[0][2] * self[1][1] * self[2][0]
-                            - self[0][1] * self[1][0] * self[2][2]
-                            - self[0][0] * self[1][2] * self[2][1]
-                    }
-                    .to_f64()
-                } else {
-                    Some(f64::default())
+            if N == 1 {
+                self[0][0].to_float()
+            } else if N == 2 {
+                { self[0][0] * self[1][1] - self[0][1] * self[1][0] }.to_float()
+            } else if N == 3 {
+                {
+                    self[0][0] * self[1][1] * self[2][2]
+                        + self[1][0] * self[2][1] * self[0][2]
+                        + self[2][0] * self[0][1] * self[1][2]
+                        - self[0][2] * self[1][1] * self[2][0]
+                        - self[0][1] * self[1][0] * self[2][2]
+                        - self[0][0] * self[1][2] * self[2][1]
                 }
-            } {
-                Some(n) => n,
-                None => f64::default(),
+                .to_float()
+            } else {
+                T::Float::zero()
             }
         } else {
             self.det_approx()
@@ -209,3 +328,246 @@ where
         res
     }
 }
+
+/// Approximate comparison of floating point matrices.
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Float,
+{
+    /// Check whether all entries of `self` and `other` differ by at most `epsilon`, see [Matrix::approx_eq](crate::mat::Matrix::approx_eq).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<f32, 1, 2> = smatrix!{0.1 + 0.2, 1.0};
+    /// assert!(mat_a.approx_eq(&smatrix!{0.3, 1.0}, 1e-6));
+    /// ```
+    pub fn approx_eq(&self, other: &SMatrix<T, M, N>, epsilon: T) -> bool {
+        self.iter()
+            .flatten()
+            .zip(other.iter().flatten())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Check whether all entries of `self` and `other` differ by at most `epsilon` times the larger of their absolute values,
+    /// see [Matrix::approx_eq_relative](crate::mat::Matrix::approx_eq_relative).
+    pub fn approx_eq_relative(&self, other: &SMatrix<T, M, N>, epsilon: T) -> bool {
+        self.iter()
+            .flatten()
+            .zip(other.iter().flatten())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon * a.abs().max(b.abs()))
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Copy + One + Zero + std::iter::Sum,
+{
+    /// Raise a square matrix to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// `exp == 0` gives the identity matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let fib: SMatrix<u64, 2, 2> = smatrix!{1, 1; 1, 0};
+    /// assert_eq!(fib.pow(10), smatrix!{89, 55; 55, 34});
+    /// ```
+    pub fn pow(&self, exp: u32) -> SMatrix<T, N, N> {
+        let mut result = SMatrix::one();
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result * base.clone();
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        result
+    }
+}
+
+/// Exact determinants.
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Copy + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T>,
+{
+    /// Calculate the determinant in the element type with the fraction-free Bareiss algorithm,
+    /// see [Matrix::det_bareiss](crate::mat::Matrix::det_bareiss).
+    ///
+    /// Every division is exact, so for integer matrices the result is exact as long as no intermediate value
+    /// overflows `T`, while [det](SMatrix::det) goes through `f64` and is rounded. Nothing is allocated on the heap.
+    /// The determinant of the empty `0x0` matrix is one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<i64, 3, 3> = smatrix!{2, 7, 1; 3, 5, 8; 4, 6, 9};
+    /// assert_eq!(mat_a.det_exact(), 27);
+    /// ```
+    pub fn det_exact(&self) -> T {
+        if N == 0 {
+            return T::one();
+        }
+        let mut a = self.data;
+        let mut prev = T::one();
+        let mut negate = false;
+        for k in 0..N - 1 {
+            if a[k][k].is_zero() {
+                match ((k + 1)..N).find(|&r| !a[r][k].is_zero()) {
+                    Some(r) => {
+                        a.swap(k, r);
+                        negate = !negate;
+                    }
+                    None => return T::zero(),
+                }
+            }
+            for i in (k + 1)..N {
+                for j in (k + 1)..N {
+                    a[i][j] = (a[i][j] * a[k][k] - a[i][k] * a[k][j]) / prev;
+                }
+            }
+            prev = a[k][k];
+        }
+        let det = a[N - 1][N - 1];
+        if negate {
+            -det
+        } else {
+            det
+        }
+    }
+}
+
+/// Orientation helpers for plane geometry.
+impl SMatrix<f64, 2, 2> {
+    /// Signed area of the parallelogram spanned by the two columns (or, equivalently, the two rows).
+    ///
+    /// The area is positive if the second vector is counter-clockwise from the first one,
+    /// negative if it is clockwise and zero if they are parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let ccw: SMatrix<f64, 2, 2> = smatrix!{2.0, 0.0; 0.0, 3.0};
+    /// assert_eq!(ccw.signed_area(), 6.0);
+    /// let cw: SMatrix<f64, 2, 2> = smatrix!{0.0, 2.0; 3.0, 0.0};
+    /// assert_eq!(cw.signed_area(), -6.0);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        self.det()
+    }
+}
+
+/// Orientation helpers for space geometry.
+impl SMatrix<f64, 3, 3> {
+    /// Signed volume of the parallelepiped spanned by the three columns (or, equivalently, the three rows).
+    ///
+    /// The volume is positive if the vectors form a right-handed system,
+    /// negative if they form a left-handed one and zero if they lie in a common plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let right: SMatrix<f64, 3, 3> = smatrix!{1.0, 0.0, 0.0; 0.0, 2.0, 0.0; 0.0, 0.0, 3.0};
+    /// assert_eq!(right.signed_volume(), 6.0);
+    /// let left: SMatrix<f64, 3, 3> = smatrix!{0.0, 1.0, 0.0; 2.0, 0.0, 0.0; 0.0, 0.0, 3.0};
+    /// assert_eq!(left.signed_volume(), -6.0);
+    /// ```
+    pub fn signed_volume(&self) -> f64 {
+        self.det()
+    }
+}
+
+/// Methods for column vectors.
+impl<T, const M: usize> SColVector<T, M>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Outer product of two column vectors, `self * other^T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{SColVector, SMatrix};
+    /// # use libmat::smatrix;
+    /// let vec_a: SColVector<i32, 2> = smatrix!{1; 2};
+    /// let vec_b: SColVector<i32, 3> = smatrix!{3; 4; 5};
+    /// assert_eq!(vec_a.outer(&vec_b), smatrix!{3, 4, 5; 6, 8, 10});
+    /// ```
+    pub fn outer<const N: usize>(&self, other: &SColVector<T, N>) -> SMatrix<T, M, N> {
+        SMatrix::from(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self[i] * other[j])
+        }))
+    }
+}
+
+/// Dot products and norms of column vectors.
+impl<T, const N: usize> SColVector<T, N>
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    /// Dot product of two column vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SColVector;
+    /// let vec_a = SColVector::from([1, 2, 3]);
+    /// let vec_b = SColVector::from([4, -5, 6]);
+    /// assert_eq!(vec_a.dot(&vec_b), 12);
+    /// ```
+    pub fn dot(&self, other: &SColVector<T, N>) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self[i] * other[i])
+    }
+
+    /// Euclidean norm (L2 norm) of a column vector, see [Vector::norm](crate::mat::Vector::norm).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SColVector;
+    /// assert_eq!(SColVector::from([3, 4]).norm(), 5.0);
+    /// ```
+    pub fn norm(&self) -> f64
+    where
+        T: ToPrimitive,
+    {
+        self.dot(self).to_f64().unwrap_or(f64::NAN).sqrt()
+    }
+}
+
+impl<T> SColVector<T, 3>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Cross product of two column vectors with three entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SColVector;
+    /// let vec_x = SColVector::from([1, 0, 0]);
+    /// let vec_y = SColVector::from([0, 1, 0]);
+    /// assert_eq!(vec_x.cross(&vec_y), SColVector::from([0, 0, 1]));
+    /// ```
+    pub fn cross(&self, other: &SColVector<T, 3>) -> SColVector<T, 3> {
+        SColVector::from([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+}
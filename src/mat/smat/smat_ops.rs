@@ -4,47 +4,6 @@ use std::ops::{
     Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-// impl<const M: usize, const N: usize> SMatrix<i64, M, N> {
-//     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//     #[target_feature(enable = "avx2")]
-//     pub unsafe fn add_assign_avx2(&mut self, rhs: SMatrix<i64, M, N>) {
-//         #[cfg(target_arch = "x86")]
-//         use std::arch::x86::{__m256i, _mm256_add_epi64, _mm256_set_epi64x};
-//         #[cfg(target_arch = "x86_64")]
-//         use std::arch::x86_64::{__m256i, _mm256_add_epi64, _mm256_set_epi64x};
-
-//         const INTS_PER_MM: usize = std::mem::size_of::<__m256i>() / std::mem::size_of::<i64>();
-
-//         for (row, row_add) in self.iter_mut().zip(rhs.iter()) {
-//             let (head, middle, tail) = row.align_to_mut::<__m256i>();
-//             let head_len = head.len();
-
-//             add_slices(head, &row_add[..head_len]);
-
-//             let middle_add_chunks =
-//                 row_add[head_len..=(head_len + middle.len() * INTS_PER_MM)].chunks(INTS_PER_MM);
-//             for (row_data, add_data) in middle.iter_mut().zip(middle_add_chunks) {
-//                 let add_mm = _mm256_set_epi64x(add_data[0], add_data[1], add_data[2], add_data[3]);
-//                 *row_data = _mm256_add_epi64(*row_data, add_mm);
-//             }
-
-//             add_slices(tail, &row_add[(head_len + middle.len() * INTS_PER_MM)..]);
-//         }
-
-//         fn add_slices(a: &mut [i64], b: &[i64]) {
-//             if a.len() >= 1 {
-//                 a[0] += b[0];
-//             }
-//             if a.len() >= 2 {
-//                 a[1] += b[1];
-//             }
-//             if a.len() >= 3 {
-//                 a[2] += b[2];
-//             }
-//         }
-//     }
-// }
-
 impl<T, const M: usize, const N: usize> Add<SMatrix<T, M, N>> for SMatrix<T, M, N>
 where
     T: Add<Output = T> + Zero + Copy,
@@ -63,6 +22,15 @@ where
     T: Add<Output = T> + Zero + Copy,
 {
     fn add_assign(&mut self, rhs: SMatrix<T, M, N>) {
+        *self += &rhs;
+    }
+}
+
+impl<T, const M: usize, const N: usize> AddAssign<&SMatrix<T, M, N>> for SMatrix<T, M, N>
+where
+    T: Add<Output = T> + Zero + Copy,
+{
+    fn add_assign(&mut self, rhs: &SMatrix<T, M, N>) {
         self.iter_mut().zip(rhs.iter()).for_each(|(row, sub_row)| {
             row.iter_mut()
                 .zip(sub_row.iter())
@@ -89,6 +57,15 @@ where
     T: Sub<Output = T> + Copy,
 {
     fn sub_assign(&mut self, rhs: SMatrix<T, M, N>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T, const M: usize, const N: usize> SubAssign<&SMatrix<T, M, N>> for SMatrix<T, M, N>
+where
+    T: Sub<Output = T> + Copy,
+{
+    fn sub_assign(&mut self, rhs: &SMatrix<T, M, N>) {
         self.iter_mut().zip(rhs.iter()).for_each(|(row, sub_row)| {
             row.iter_mut()
                 .zip(sub_row.iter())
@@ -99,13 +76,13 @@ where
 
 impl<T, const L: usize, const M: usize, const N: usize> Mul<SMatrix<T, M, N>> for SMatrix<T, L, M>
 where
-    T: Add<Output = T> + Mul<Output = T> + One + Zero + Copy + std::iter::Sum,
+    T: Add<Output = T> + Mul<Output = T> + Zero + Copy + std::iter::Sum,
 {
     type Output = SMatrix<T, L, N>;
 
     fn mul(self, rhs: SMatrix<T, M, N>) -> Self::Output {
         let r_rhs = rhs.transpose();
-        let mut result_matrix = SMatrix::<T, L, N>::new(T::one());
+        let mut result_matrix = SMatrix::<T, L, N>::new(T::zero());
 
         result_matrix
             .iter_mut()
@@ -150,6 +127,42 @@ where
     }
 }
 
+/// Scaling with the scalar on the left, for the primitive number types.
+/// The result is the same as with the scalar on the right.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::SMatrix;
+/// # use libmat::smatrix;
+/// let mat_a: SMatrix<u32, 2, 2> = smatrix!{1, 2; 3, 4};
+/// assert_eq!(3 * &mat_a, smatrix!{3, 6; 9, 12});
+/// assert_eq!(3 * mat_a.clone(), mat_a * 3);
+/// ```
+macro_rules! impl_scalar_mul_smatrix {
+    ($($t:ty),*) => {
+        $(
+            impl<const M: usize, const N: usize> Mul<SMatrix<$t, M, N>> for $t {
+                type Output = SMatrix<$t, M, N>;
+
+                fn mul(self, rhs: SMatrix<$t, M, N>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl<const M: usize, const N: usize> Mul<&SMatrix<$t, M, N>> for $t {
+                type Output = SMatrix<$t, M, N>;
+
+                fn mul(self, rhs: &SMatrix<$t, M, N>) -> Self::Output {
+                    rhs.clone() * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul_smatrix!(i32, i64, u32, u64, f32, f64);
+
 impl<T, const M: usize, const N: usize> Div<T> for SMatrix<T, M, N>
 where
     T: Div<Output = T> + Copy,
@@ -0,0 +1,78 @@
+use crate::mat::{PivotStrategy, SMatrix};
+use num_traits::identities::Zero;
+use std::ops::{Div, Mul, Sub};
+
+/// Row echelon forms.
+///
+/// Like for [Matrix](crate::mat::Matrix), these methods divide by pivots, so they are meant for fields
+/// like floating point or rational numbers. The elimination works on the inline storage and doesn't allocate.
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Copy + Zero + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + PartialEq,
+{
+    /// Calculate the reduced row echelon form of a matrix with Gauss-Jordan elimination.
+    ///
+    /// The pivot of every column is its first non-zero entry at or below the current row,
+    /// the same as in [Matrix::rref](crate::mat::Matrix::rref).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::SMatrix;
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<f64, 3, 3> = smatrix!{2.0, 4.0, 2.0; 1.0, 3.0, 2.0; 1.0, 1.0, 0.0};
+    /// assert_eq!(mat_a.rref(), smatrix!{1.0, 0.0, -1.0; 0.0, 1.0, 1.0; 0.0, 0.0, 0.0});
+    /// ```
+    pub fn rref(&self) -> SMatrix<T, M, N> {
+        self.rref_with(PivotStrategy::FirstNonZero)
+    }
+
+    /// Calculate the reduced row echelon form of a matrix, choosing pivots with `strategy`,
+    /// see [Matrix::rref_with](crate::mat::Matrix::rref_with).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{PivotStrategy, SMatrix};
+    /// # use libmat::smatrix;
+    /// let mat_a: SMatrix<f64, 2, 3> = smatrix!{1e-20, 1.0, 1.0; 1.0, 1.0, 2.0};
+    /// let partial = mat_a.rref_with(PivotStrategy::Largest(|x: &f64| x.abs()));
+    /// assert_eq!(partial, smatrix!{1.0, 0.0, 1.0; 0.0, 1.0, 1.0});
+    /// ```
+    pub fn rref_with(&self, strategy: PivotStrategy<T>) -> SMatrix<T, M, N> {
+        let mut mat = self.clone();
+        let mut row = 0;
+        for col in 0..N {
+            if row == M {
+                break;
+            }
+            let pivot = match strategy {
+                PivotStrategy::FirstNonZero => (row..M).find(|&r| !mat[r][col].is_zero()),
+                PivotStrategy::Largest(magnitude) => (row..M)
+                    .filter(|&r| !mat[r][col].is_zero())
+                    .max_by(|&r, &s| magnitude(&mat[r][col]).total_cmp(&magnitude(&mat[s][col]))),
+            };
+            let pivot_row = match pivot {
+                Some(r) => r,
+                None => continue,
+            };
+            mat.swap(row, pivot_row);
+
+            let pivot = mat[row][col];
+            for c in col..N {
+                mat[row][c] = mat[row][c] / pivot;
+            }
+            for r in 0..M {
+                if r == row || mat[r][col].is_zero() {
+                    continue;
+                }
+                let factor = mat[r][col];
+                for c in col..N {
+                    mat[r][c] = mat[r][c] - factor * mat[row][c];
+                }
+            }
+            row += 1;
+        }
+        mat
+    }
+}
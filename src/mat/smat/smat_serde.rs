@@ -0,0 +1,59 @@
+use crate::mat::SMatrix;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+/// A static matrix is serialized like its nested array, as a sequence of `M` rows with `N` entries each.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::SMatrix;
+/// # use libmat::smatrix;
+/// let mat_a: SMatrix<i32, 2, 3> = smatrix!{1, 2, 3; 4, 5, 6};
+/// let json = serde_json::to_string(&mat_a).unwrap();
+/// assert_eq!(json, "[[1,2,3],[4,5,6]]");
+/// assert_eq!(serde_json::from_str::<SMatrix<i32, 2, 3>>(&json).unwrap(), mat_a);
+/// ```
+impl<T, const M: usize, const N: usize> Serialize for SMatrix<T, M, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.data.iter().map(|row| &row[..]))
+    }
+}
+
+/// Deserializing fails if there are not exactly `M` rows with `N` entries each.
+impl<'de, T, const M: usize, const N: usize> Deserialize<'de> for SMatrix<T, M, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<SMatrix<T, M, N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+        if rows.len() != M {
+            return Err(D::Error::invalid_length(
+                rows.len(),
+                &format!("{} rows", M).as_str(),
+            ));
+        }
+        if let Some(row) = rows.iter().find(|row| row.len() != N) {
+            return Err(D::Error::invalid_length(
+                row.len(),
+                &format!("{} entries per row", N).as_str(),
+            ));
+        }
+        let mut rows = rows.into_iter().map(|row| row.into_iter());
+        Ok(SMatrix {
+            data: std::array::from_fn(|_| {
+                let mut row = rows.next().unwrap();
+                std::array::from_fn(|_| row.next().unwrap())
+            }),
+        })
+    }
+}
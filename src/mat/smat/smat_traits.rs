@@ -1,42 +1,61 @@
-use crate::mat::SMatrix;
+use crate::mat::float::ToFloat;
+use crate::mat::{display, SColVector, SMatrix};
 use num_traits::cast::ToPrimitive;
 use num_traits::identities::{One, Zero};
 use num_traits::ops::inv::Inv;
 use num_traits::sign::Signed;
 use std::fmt::{Display, Formatter, Result};
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
 
 impl<T, const M: usize, const N: usize> Display for SMatrix<T, M, N>
 where
     T: Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let mut rs = self.iter().peekable();
-        while let Some(r) = rs.next() {
-            let mut es = r.iter().peekable();
-            while let Some(e) = es.next() {
-                write!(f, "{e}")?;
-                if rs.peek().is_some() {
-                    write!(f, "")?;
-                } else if es.peek().is_some() {
-                    write!(f, "\t")?;
-                } else {
-                    writeln!(f)?;
-                }
-            }
-        }
-        Ok(())
+        display::write_aligned(f, self.iter().map(|row| &row[..]))
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Display,
+{
+    /// Format the matrix on a single line, see [Matrix::to_string_flat](crate::mat::Matrix::to_string_flat).
+    pub fn to_string_flat(&self) -> String {
+        display::flat_string(self.iter().map(|row| &row[..]))
     }
 }
 
 impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for SMatrix<T, M, N> {
     fn from(arr: [[T; N]; M]) -> Self {
-        SMatrix::<T, M, N> {
-            data: Box::new(arr),
+        SMatrix::<T, M, N> { data: arr }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SColVector<T, N> {
+    fn from(arr: [T; N]) -> Self {
+        SMatrix::<T, N, 1> {
+            data: arr.map(|x| [x]),
         }
     }
 }
 
+/// Column vectors are indexed by a single index, so `vec_a[i]` is the entry in row `i`.
+/// Note that this includes 1x1 matrices, where `mat_a[0]` is the only entry and not the first row.
+impl<T, const N: usize> Index<usize> for SColVector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index][0]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SColVector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index][0]
+    }
+}
+
 impl<T, const N: usize> One for SMatrix<T, N, N>
 where
     T: Add<Output = T> + Copy + Zero + One + std::iter::Sum,
@@ -72,41 +91,39 @@ where
     }
 }
 
+/// The inverse is computed in the [floating point type](ToFloat) of the entries.
 impl<T, const N: usize> Inv for SMatrix<T, N, N>
 where
-    T: Sub<Output = T> + Add<Output = T> + Mul<Output = T> + ToPrimitive + Signed,
+    T: Sub<Output = T> + Add<Output = T> + Mul<Output = T> + ToPrimitive + Signed + ToFloat,
 {
-    type Output = Option<SMatrix<f64, N, N>>;
+    type Output = Option<SMatrix<T::Float, N, N>>;
 
     fn inv(self) -> Self::Output {
-        if let Some((mat, p)) = self.lupdecompose() {
+        if let Some((mat, p, _)) = self.lupdecompose() {
             let dim = mat.rows();
-            let mut mat_inv = SMatrix::<f64, N, N>::zero();
+            let mut mat_inv = SMatrix::<T::Float, N, N>::zero();
             for j in 0..dim {
                 for i in 0..dim {
                     mat_inv[i][j] = {
                         if p[i] == j {
-                            1.0
+                            T::Float::one()
                         } else {
-                            0.0
+                            T::Float::zero()
                         }
                     };
 
                     for k in 0..i {
-                        mat_inv[i][j] -= mat[i][k] * mat_inv[k][j];
+                        mat_inv[i][j] = mat_inv[i][j] - mat[i][k] * mat_inv[k][j];
                     }
                 }
 
                 for i in (0..dim).rev() {
                     for k in (i + 1)..dim {
-                        mat_inv[i][j] -= mat[i][k] * mat_inv[k][j];
+                        mat_inv[i][j] = mat_inv[i][j] - mat[i][k] * mat_inv[k][j];
                     }
                     mat_inv[i][j] /= mat[i][i];
                 }
             }
-            if (p[dim] - dim) % 2 != 0 {
-                mat_inv.data.reverse();
-            }
             Some(mat_inv)
         } else {
             None
@@ -1,2 +1,6 @@
+#[cfg(feature = "fast-dot")]
+mod vec_fast;
 mod vec_impl;
 mod vec_ops;
+#[cfg(feature = "serde")]
+mod vec_serde;
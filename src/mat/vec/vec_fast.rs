@@ -0,0 +1,57 @@
+use crate::err::DimensionError;
+use crate::mat::Vector;
+
+/// Number of independent accumulators of [dot_fast](Vector::dot_fast).
+const LANES: usize = 8;
+
+/// Unrolled kernels, available with the `fast-dot` feature.
+impl Vector<f64> {
+    /// Dot product of two vectors of the same length, unrolled into eight independent partial sums.
+    ///
+    /// The partial sums have no dependency on each other, so the compiler can keep them in vector registers
+    /// and the loop isn't limited by the latency of a single chain of additions. The entries that don't fill
+    /// a whole chunk of eight are added one by one. Because the summation order differs from the
+    /// dot product `self * other`, the result can differ from it by round-off.
+    ///
+    /// Returns [DimensionError::NoMatch] if the vectors have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let vec_a: Vector<f64> = (1..=10).map(f64::from).collect();
+    /// let vec_b = Vector::new(10, 2.0);
+    /// assert_eq!(vec_a.dot_fast(&vec_b)?, 110.0);
+    /// assert!(vec_a.dot_fast(&vector![1.0, 2.0]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn dot_fast(&self, other: &Vector<f64>) -> Result<f64, DimensionError> {
+        if self.len() != other.len() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                other.dims,
+                "multiply".to_owned(),
+            ));
+        }
+        let (a, b) = (
+            self.entries.chunks_exact(LANES),
+            other.entries.chunks_exact(LANES),
+        );
+        let tail: f64 = a
+            .remainder()
+            .iter()
+            .zip(b.remainder().iter())
+            .map(|(x, y)| x * y)
+            .sum();
+        let mut acc = [0.0; LANES];
+        for (x, y) in a.zip(b) {
+            for lane in 0..LANES {
+                acc[lane] += x[lane] * y[lane];
+            }
+        }
+        Ok(acc.iter().sum::<f64>() + tail)
+    }
+}
@@ -1,6 +1,12 @@
+use crate::err::{DimensionError, ParseMatrixError};
 use crate::mat::dims::Dimensions;
+use crate::mat::float::ToFloat;
 use crate::mat::{Matrix, Vector};
+use num_traits::{Float, One, ToPrimitive, Zero};
 use std::convert::From;
+use std::iter::FromIterator;
+use std::ops::{Mul, Sub};
+use std::str::FromStr;
 
 impl<T> Vector<T>
 where
@@ -42,12 +48,772 @@ where
     pub fn is_row_vector(&self) -> bool {
         self.dims.cols() >= self.dims.rows()
     }
+
+    /// Clamp every entry into the interval `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// let vec_a = vector![-3, 0, 2, 7];
+    /// assert_eq!(vec_a.clamp(-1, 5), vector![-1, 0, 2, 5]);
+    /// ```
+    pub fn clamp(&self, min: T, max: T) -> Vector<T>
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max, "min needs to be less than or equal to max.");
+        Vector::<T> {
+            dims: self.dims,
+            entries: self
+                .iter()
+                .map(|x| {
+                    if *x < min {
+                        min.clone()
+                    } else if *x > max {
+                        max.clone()
+                    } else {
+                        x.clone()
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// The entries of a vector in reverse order, keeping the orientation (row or column) of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3].reverse(), vector![3, 2, 1]);
+    /// ```
+    pub fn reverse(&self) -> Vector<T> {
+        Vector::<T> {
+            dims: self.dims,
+            entries: self.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// The distinct entries of a vector, in the order of their first occurrence.
+    ///
+    /// The result keeps the orientation (row or column) of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 2, 3, 1].unique(), vector![1, 2, 3]);
+    /// ```
+    pub fn unique(&self) -> Vector<T>
+    where
+        T: PartialEq,
+    {
+        let mut entries: Vec<T> = Vec::new();
+        for x in self.iter() {
+            if !entries.contains(x) {
+                entries.push(x.clone());
+            }
+        }
+        let dims = if self.dims.rows() == 1 && self.dims.cols() != 1 {
+            Dimensions::new(1, entries.len())
+        } else {
+            Dimensions::new(entries.len(), 1)
+        };
+        Vector::<T> { dims, entries }
+    }
+}
+
+/// Standard basis vectors.
+impl<T> Vector<T>
+where
+    T: Zero + One,
+{
+    /// Create the column vector of length `size` that is `1` at `index` and `0` everywhere else.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] if `size` is zero and [DimensionError::InvalidValue]
+    /// if `index` is not smaller than `size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(Vector::<f64>::unit(3, 1)?, vector![0.0, 1.0, 0.0]);
+    /// assert!(Vector::<f64>::unit(3, 3).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn unit(size: usize, index: usize) -> Result<Vector<T>, DimensionError> {
+        if size == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        if index >= size {
+            return Err(DimensionError::InvalidValue(format!(
+                "index {} is out of bounds for a vector of length {}",
+                index, size
+            )));
+        }
+        Ok(Vector::<T> {
+            dims: Dimensions::new(size, 1),
+            entries: (0..size)
+                .map(|i| if i == index { T::one() } else { T::zero() })
+                .collect(),
+        })
+    }
+}
+
+/// Reshaping into matrices.
+impl<T> Vector<T> {
+    /// Reshape the vector into a `rows x cols` matrix, filling it row by row.
+    ///
+    /// The entries are moved into the matrix without copying, the orientation of the vector doesn't matter.
+    /// [Matrix::into_vector] is the inverse.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] if `rows` or `cols` is zero or `rows x cols` overflows `usize`,
+    /// and [DimensionError::InvalidInputDimensions] with the length of the vector and `rows x cols` if they differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let vec_a = vector![1, 2, 3, 4, 5, 6];
+    /// assert_eq!(vec_a.clone().into_matrix(2, 3)?, matrix!{1, 2, 3; 4, 5, 6});
+    /// assert_eq!(
+    ///     vec_a.into_matrix(4, 2),
+    ///     Err(DimensionError::InvalidInputDimensions(6, 8))
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn into_matrix(self, rows: usize, cols: usize) -> Result<Matrix<T>, DimensionError> {
+        let len = match rows.checked_mul(cols) {
+            Some(len) if len > 0 => len,
+            _ => return Err(DimensionError::InvalidDimensions),
+        };
+        if self.entries.len() != len {
+            return Err(DimensionError::InvalidInputDimensions(
+                self.entries.len(),
+                len,
+            ));
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows, cols),
+            matrix: self.entries,
+            label: None,
+        })
+    }
+}
+
+/// Sequences.
+impl Vector<f64> {
+    /// Create the column vector of `n` geometrically spaced values from `start` to `end`.
+    ///
+    /// Consecutive entries have the same ratio, so the entries are evenly spaced on a logarithmic scale.
+    /// The first entry is `start`, the last one `end`, and for `n == 1` the only entry is `start`.
+    ///
+    /// Returns [DimensionError::InvalidDimensions] if `n` is zero and [DimensionError::InvalidValue] if
+    /// an endpoint is not positive or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let decades = Vector::geomspace(1.0, 1000.0, 4)?;
+    /// assert!(decades.approx_eq(&vector![1.0, 10.0, 100.0, 1000.0], 1e-12));
+    /// assert!(Vector::geomspace(0.0, 1.0, 4).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn geomspace(start: f64, end: f64, n: usize) -> Result<Vector<f64>, DimensionError> {
+        if n == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        if !(start > 0.0 && end > 0.0 && start.is_finite() && end.is_finite()) {
+            return Err(DimensionError::InvalidValue(format!(
+                "the endpoints {} and {} of a geometric sequence need to be positive and finite",
+                start, end
+            )));
+        }
+        let ratio = end / start;
+        let mut entries: Vec<f64> = (0..n)
+            .map(|i| start * ratio.powf(i as f64 / (n - 1).max(1) as f64))
+            .collect();
+        if n > 1 {
+            entries[n - 1] = end;
+        }
+        Ok(Vector::from(entries))
+    }
+
+    /// Create the column vector of the values from `start` up to, but not including, `stop` in steps of `step`.
+    ///
+    /// The `i`-th entry is `start + i * step`, so round-off doesn't accumulate. Like numpy's `arange` the
+    /// number of entries is `ceil((stop - start) / step)`.
+    ///
+    /// Returns [DimensionError::InvalidValue] if an argument is not finite or `step` is zero or points away from
+    /// `stop`, and [DimensionError::InvalidDimensions] if `start == stop`, because a vector can't be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(Vector::arange(0.0, 1.0, 0.25)?, vector![0.0, 0.25, 0.5, 0.75]);
+    /// assert_eq!(Vector::arange(3.0, 0.0, -1.0)?, vector![3.0, 2.0, 1.0]);
+    /// assert!(Vector::arange(0.0, 1.0, -0.25).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn arange(start: f64, stop: f64, step: f64) -> Result<Vector<f64>, DimensionError> {
+        if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+            return Err(DimensionError::InvalidValue(format!(
+                "the range from {} to {} in steps of {} needs finite arguments",
+                start, stop, step
+            )));
+        }
+        if step == 0.0 || (stop - start) * step < 0.0 {
+            return Err(DimensionError::InvalidValue(format!(
+                "a step of {} never gets from {} to {}",
+                step, start, stop
+            )));
+        }
+        let n = ((stop - start) / step).ceil() as usize;
+        if n == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        Ok((0..n).map(|i| start + i as f64 * step).collect())
+    }
+}
+
+/// Comparison masks.
+impl<T> Vector<T>
+where
+    T: PartialOrd,
+{
+    fn mask(&self, f: impl Fn(&T) -> bool) -> Vector<bool> {
+        Vector::<bool> {
+            dims: self.dims,
+            entries: self.iter().map(f).collect(),
+        }
+    }
+
+    /// Mask of the entries that are greater than `scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3].gt(1), vector![false, true, true]);
+    /// ```
+    pub fn gt(&self, scalar: T) -> Vector<bool> {
+        self.mask(|x| *x > scalar)
+    }
+
+    /// Mask of the entries that are less than `scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3].lt(2), vector![true, false, false]);
+    /// ```
+    pub fn lt(&self, scalar: T) -> Vector<bool> {
+        self.mask(|x| *x < scalar)
+    }
+
+    /// Mask of the entries that are greater than or equal to `scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3].ge(2), vector![false, true, true]);
+    /// ```
+    pub fn ge(&self, scalar: T) -> Vector<bool> {
+        self.mask(|x| *x >= scalar)
+    }
+
+    /// Mask of the entries that are less than or equal to `scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3].le(2), vector![true, true, false]);
+    /// ```
+    pub fn le(&self, scalar: T) -> Vector<bool> {
+        self.mask(|x| *x <= scalar)
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Clone,
+{
+    /// Replace the entries where `mask` is `true` with `value`.
+    ///
+    /// The mask needs as many entries as `self`, its orientation doesn't matter.
+    /// Returns [DimensionError::InvalidInputDimensions] with the length of the mask and of `self` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let vec_a = vector![0.5, 3.0, -1.0, 7.5];
+    /// assert_eq!(vec_a.select(&vec_a.gt(2.0), 2.0)?, vector![0.5, 2.0, -1.0, 2.0]);
+    /// # Ok(()) }
+    /// ```
+    pub fn select(&self, mask: &Vector<bool>, value: T) -> Result<Vector<T>, DimensionError> {
+        if mask.size() != self.size() {
+            return Err(DimensionError::InvalidInputDimensions(
+                mask.size(),
+                self.size(),
+            ));
+        }
+        Ok(Vector::<T> {
+            dims: self.dims,
+            entries: self
+                .iter()
+                .zip(mask.iter())
+                .map(|(x, m)| if *m { value.clone() } else { x.clone() })
+                .collect(),
+        })
+    }
 }
 
 impl<T> Vector<T> {
     pub fn size(&self) -> usize {
         self.entries.len()
     }
+
+    /// Get the number of rows and columns as a tuple, `(size, 1)` for column and `(1, size)` for row vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// let vec_a = vector![1, 2, 3];
+    /// assert_eq!(vec_a.shape(), (3, 1));
+    /// assert_eq!(vec_a.to_row_vector().shape(), (1, 3));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        self.dims.as_tuple()
+    }
+}
+
+/// Approximate comparison of floating point vectors.
+impl<T> Vector<T>
+where
+    T: Float,
+{
+    /// Check whether all entries of `self` and `other` differ by at most `epsilon`.
+    ///
+    /// Returns `false` if the dimensions differ, so a row vector is never approximately equal
+    /// to a column vector, or if an entry is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert!(vector![0.1 + 0.2, 1.0].approx_eq(&vector![0.3, 1.0], 1e-12));
+    /// assert!(!vector![0.1, 1.0].approx_eq(&vector![0.3, 1.0], 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Vector<T>, epsilon: T) -> bool {
+        self.dims == other.dims
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Check whether all entries of `self` and `other` differ by at most `epsilon` times the larger of their absolute values,
+    /// see [Matrix::approx_eq_relative].
+    pub fn approx_eq_relative(&self, other: &Vector<T>, epsilon: T) -> bool {
+        self.dims == other.dims
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| (*a - *b).abs() <= epsilon * a.abs().max(b.abs()))
+    }
+}
+
+/// Norms and distances.
+impl<T> Vector<T>
+where
+    T: ToPrimitive,
+{
+    /// Euclidean norm (length) of a vector, computed in the [floating point type](ToFloat) of the entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![3, 4].norm(), 5.0);
+    /// let norm: f32 = vector![3.0_f32, 4.0].norm();
+    /// assert_eq!(norm, 5.0);
+    /// ```
+    pub fn norm(&self) -> T::Float
+    where
+        T: ToFloat,
+    {
+        self.iter()
+            .map(|x| {
+                let x = x.to_float();
+                x * x
+            })
+            .sum::<T::Float>()
+            .sqrt()
+    }
+
+    /// Manhattan norm (L1 norm) of a vector, the sum of the absolute values of the entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![3, -4, 1].norm_l1(), 8.0);
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN).abs())
+            .sum()
+    }
+
+    /// Maximum norm (infinity norm) of a vector, the largest absolute value of the entries.
+    ///
+    /// A NaN entry makes the norm NaN, like for the other norms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![3, -4, 1].norm_inf(), 4.0);
+    /// assert!(vector![3.0, f64::NAN, 1.0].norm_inf().is_nan());
+    /// ```
+    pub fn norm_inf(&self) -> f64 {
+        self.iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN).abs())
+            .fold(0.0, |max, x| if x.is_nan() || x > max { x } else { max })
+    }
+
+    /// The `p`-norm of a vector, that is `(|x_1|^p + ... + |x_n|^p)^(1/p)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, -2, 3].p_norm(1.0), 6.0);
+    /// assert_eq!(vector![3.0, 4.0].p_norm(2.0), 5.0);
+    /// ```
+    pub fn p_norm(&self, p: f64) -> f64 {
+        self.iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN).abs().powf(p))
+            .sum::<f64>()
+            .powf(1.0 / p)
+    }
+
+    /// Returns the unit vector pointing in the same direction, or `None` for the zero vector.
+    ///
+    /// The result is built on [norm](Vector::norm) and keeps the orientation (row or column) of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![3.0, 4.0].normalize(), Some(vector![0.6, 0.8]));
+    /// assert_eq!(vector![3, 4].normalize(), Some(vector![0.6, 0.8]));
+    /// assert_eq!(vector![0, 0].normalize(), None);
+    /// ```
+    pub fn normalize(&self) -> Option<Vector<T::Float>>
+    where
+        T: ToFloat,
+    {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        Some(Vector::<T::Float> {
+            dims: self.dims,
+            entries: self.iter().map(|x| x.to_float() / norm).collect(),
+        })
+    }
+
+    /// Euclidean distance between two vectors of the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(vector![1, 1].distance(&vector![4, 5])?, 5.0);
+    /// assert!(vector![1, 1].distance(&vector![1, 1, 1]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn distance(&self, other: &Vector<T>) -> Result<f64, DimensionError> {
+        if self.len() != other.len() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                other.dims,
+                "subtract".to_owned(),
+            ));
+        }
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| {
+                let d = a.to_f64().unwrap_or(f64::NAN) - b.to_f64().unwrap_or(f64::NAN);
+                d * d
+            })
+            .sum::<f64>()
+            .sqrt())
+    }
+}
+
+/// Statistics.
+impl<T> Vector<T>
+where
+    T: ToPrimitive,
+{
+    fn to_f64_entries(&self) -> Vec<f64> {
+        self.iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN))
+            .collect()
+    }
+
+    /// Arithmetic mean of the entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3, 6].mean(), 3.0);
+    /// ```
+    pub fn mean(&self) -> f64 {
+        self.to_f64_entries().iter().sum::<f64>() / self.size() as f64
+    }
+
+    /// Population variance of the entries, that is the mean of the squared distances to the [mean](Vector::mean).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![2, 4, 4, 4, 5, 5, 7, 9].variance(), 4.0);
+    /// ```
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.to_f64_entries()
+            .iter()
+            .map(|x| (x - mean) * (x - mean))
+            .sum::<f64>()
+            / self.size() as f64
+    }
+
+    /// Population standard deviation of the entries, the square root of the [variance](Vector::variance).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![2, 4, 4, 4, 5, 5, 7, 9].std_dev(), 2.0);
+    /// ```
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Median of the entries. For an even number of entries this is the mean of the two middle ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![3, 1, 2].median(), 2.0);
+    /// assert_eq!(vector![4, 1, 3, 2].median(), 2.5);
+    /// ```
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn median(&self) -> f64 {
+        let mut entries = self.to_f64_entries();
+        entries.sort_by(f64::total_cmp);
+        let mid = entries.len() / 2;
+        if entries.len() % 2 == 0 {
+            (entries[mid - 1] + entries[mid]) / 2.0
+        } else {
+            entries[mid]
+        }
+    }
+
+    /// Count the entries in `bins` equally wide bins over the range of the data.
+    ///
+    /// Returns the `bins + 1` bin edges and the counts of each bin. Every bin includes its left edge,
+    /// the last one also includes its right edge. If all entries are the same, the range is widened to
+    /// one around that value, so all entries end up in the same bin. Entries that are NaN are not counted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// let (edges, counts) = vector![1.0, 2.0, 2.5, 3.0, 5.0].histogram(2);
+    /// assert_eq!(edges, vec![1.0, 3.0, 5.0]);
+    /// assert_eq!(counts, vec![3, 2]);
+    /// ```
+    pub fn histogram(&self, bins: usize) -> (Vec<f64>, Vec<usize>) {
+        assert!(bins > 0, "A histogram needs at least one bin.");
+        let entries: Vec<f64> = self
+            .to_f64_entries()
+            .into_iter()
+            .filter(|x| !x.is_nan())
+            .collect();
+        let min = entries.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = entries.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let (min, max) = if entries.is_empty() {
+            (0.0, 1.0)
+        } else if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        };
+        let width = (max - min) / bins as f64;
+        let edges = (0..=bins)
+            .map(|i| {
+                if i == bins {
+                    max
+                } else {
+                    min + width * i as f64
+                }
+            })
+            .collect();
+        let mut counts = vec![0; bins];
+        for x in entries {
+            let bin = (((x - min) / width) as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+        (edges, counts)
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + Zero + Clone,
+{
+    /// Squared euclidean norm of a vector, computed in the element type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// assert_eq!(vector![1, 2, 3].norm_squared(), 14);
+    /// ```
+    pub fn norm_squared(&self) -> T {
+        self.iter()
+            .fold(T::zero(), |acc, x| acc + x.clone() * x.clone())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + Clone,
+{
+    /// Outer product of two vectors.
+    ///
+    /// The result is a `m x n` matrix, where `m = self.size()` and `n = other.size()`,
+    /// no matter whether the vectors are row or column vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// let vec_a = vector![1, 2];
+    /// let vec_b = vector![3, 4, 5];
+    /// assert_eq!(vec_a.outer(&vec_b), matrix!{3, 4, 5; 6, 8, 10});
+    /// ```
+    pub fn outer(&self, other: &Vector<T>) -> Matrix<T> {
+        Matrix::<T> {
+            dims: Dimensions::new(self.size(), other.size()),
+            matrix: self
+                .iter()
+                .flat_map(|a| other.iter().map(move |b| a.clone() * b.clone()))
+                .collect(),
+            label: None,
+        }
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Clone,
+{
+    /// Cross product of two vectors of length 3.
+    ///
+    /// The result has the orientation (row or column) of `self`. If one of the vectors does not have
+    /// length 3, [DimensionError::InvalidInputDimensions] with its length is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(vector![1, 0, 0].cross(&vector![0, 1, 0])?, vector![0, 0, 1]);
+    /// assert!(vector![1, 0].cross(&vector![0, 1, 0]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn cross(&self, other: &Vector<T>) -> Result<Vector<T>, DimensionError> {
+        for vec in [self, other] {
+            if vec.size() != 3 {
+                return Err(DimensionError::InvalidInputDimensions(vec.size(), 3));
+            }
+        }
+        let (a, b) = (&self.entries, &other.entries);
+        Ok(Vector::<T> {
+            dims: self.dims,
+            entries: vec![
+                a[1].clone() * b[2].clone() - a[2].clone() * b[1].clone(),
+                a[2].clone() * b[0].clone() - a[0].clone() * b[2].clone(),
+                a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone(),
+            ],
+        })
+    }
 }
 
 impl<T> From<Vec<T>> for Vector<T>
@@ -62,6 +828,59 @@ where
     }
 }
 
+/// Collects an iterator into a column vector.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty, just like converting an empty [Vec].
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Vector;
+/// # use libmat::vector;
+/// let vec_a: Vector<u32> = (1..=4).map(|x| x * x).collect();
+/// assert_eq!(vec_a, vector![1, 4, 9, 16]);
+/// ```
+impl<T> FromIterator<T> for Vector<T>
+where
+    T: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vector<T> {
+        Vector::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+/// Parses a column vector from entries separated by commas, semicolons and/or whitespace.
+///
+/// Errors are reported like for [Matrix], with the position of an entry as its column.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Vector;
+/// # use libmat::vector;
+/// # use libmat::err::ParseMatrixError;
+/// # fn main() -> Result<(), ParseMatrixError> {
+/// assert_eq!("1.5, 2 -3".parse::<Vector<f64>>()?, vector![1.5, 2.0, -3.0]);
+/// assert_eq!("".parse::<Vector<f64>>(), Err(ParseMatrixError::Empty));
+/// # Ok(()) }
+/// ```
+impl<T> FromStr for Vector<T>
+where
+    T: FromStr,
+{
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Vector<T>, ParseMatrixError> {
+        let mat: Matrix<T> = s.replace([';', '\n'], ",").parse()?;
+        Ok(Vector::<T> {
+            dims: Dimensions::new(mat.cols(), 1),
+            entries: mat.matrix,
+        })
+    }
+}
+
 impl<T> From<Matrix<T>> for Vector<T>
 where
     T: Clone,
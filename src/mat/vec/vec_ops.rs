@@ -1,7 +1,6 @@
 use crate::err::DimensionError;
 use crate::mat::{Matrix, Vector};
 use num_traits::{One, Zero};
-use std::fmt::Display;
 use std::ops::{
     Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
 };
@@ -17,16 +16,17 @@ use std::ops::{
 /// # fn main () -> Result<(), DimensionError> {
 /// let vec_a = vector![1, 2, 3];
 /// let vec_b = vector![3, 2, 1];
+/// assert_eq!((&vec_a + &vec_b)?, Vector::new(3, 4));
 /// assert_eq!((vec_a + vec_b)?, Vector::new(3, 4));
 /// # Ok(()) }
 /// ```
-impl<T> Add for Vector<T>
+impl<T> Add<&Vector<T>> for Vector<T>
 where
     T: AddAssign + Clone,
 {
     type Output = Result<Vector<T>, DimensionError>;
 
-    fn add(self, vector: Vector<T>) -> Self::Output {
+    fn add(self, vector: &Vector<T>) -> Self::Output {
         if self.len() != vector.len() {
             Err(DimensionError::NoMatch(
                 self.dims,
@@ -41,17 +41,36 @@ where
     }
 }
 
+impl_binop!(impl Add::add for Vector<T>, Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a + &b; where T: AddAssign + Clone);
+impl_binop!(impl Add::add for &Vector<T>, Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a.clone() + &b; where T: AddAssign + Clone);
+impl_binop!(impl Add::add for &Vector<T>, &Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a.clone() + b; where T: AddAssign + Clone);
+
 impl<T> AddAssign<Vector<T>> for Vector<T>
 where
     T: AddAssign + Clone,
 {
     fn add_assign(&mut self, vector: Vector<T>) {
-        if self.len() != vector.len() {
-            panic!("Dimensions do not match");
+        *self += &vector;
+    }
+}
+
+/// In-place elementwise addition. Adding a reference doesn't clone the right vector.
+///
+/// # Panics
+///
+/// Panics with the message of the [DimensionError] if the lengths differ.
+/// [try_add_assign](Vector::try_add_assign) returns the error instead.
+impl<T> AddAssign<&Vector<T>> for Vector<T>
+where
+    T: AddAssign + Clone,
+{
+    fn add_assign(&mut self, vector: &Vector<T>) {
+        if let Err(err) = self.try_add_assign(vector) {
+            panic!("{}", err);
         }
-        self.iter_mut()
-            .zip(vector.iter())
-            .for_each(|(a, b)| *a += b.clone());
     }
 }
 
@@ -101,16 +120,17 @@ where
 /// # fn main() -> Result<(), DimensionError> {
 /// let vec_a = vector![1_i32, 2, 3];
 /// let vec_b = vector![3_i32, 2, 1];
+/// assert_eq!((&vec_a - &vec_b)?, vector![-2, 0, 2]);
 /// assert_eq!((vec_a - vec_b)?, vector![-2, 0, 2]);
 /// # Ok(()) }
 /// ```
-impl<T> Sub for Vector<T>
+impl<T> Sub<&Vector<T>> for Vector<T>
 where
     T: SubAssign + Zero + One + Clone,
 {
     type Output = Result<Vector<T>, DimensionError>;
 
-    fn sub(self, vector: Vector<T>) -> Self::Output {
+    fn sub(self, vector: &Vector<T>) -> Self::Output {
         if self.len() != vector.len() {
             Err(DimensionError::NoMatch(
                 self.dims,
@@ -125,17 +145,106 @@ where
     }
 }
 
+impl_binop!(impl Sub::sub for Vector<T>, Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a - &b; where T: SubAssign + Zero + One + Clone);
+impl_binop!(impl Sub::sub for &Vector<T>, Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a.clone() - &b; where T: SubAssign + Zero + One + Clone);
+impl_binop!(impl Sub::sub for &Vector<T>, &Vector<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a.clone() - b; where T: SubAssign + Zero + One + Clone);
+
 impl<T> SubAssign<Vector<T>> for Vector<T>
 where
     T: SubAssign + Zero + One + Clone,
 {
     fn sub_assign(&mut self, vector: Vector<T>) {
+        *self -= &vector;
+    }
+}
+
+/// In-place elementwise subtraction. Subtracting a reference doesn't clone the right vector.
+///
+/// # Panics
+///
+/// Panics with the message of the [DimensionError] if the lengths differ.
+/// [try_sub_assign](Vector::try_sub_assign) returns the error instead.
+impl<T> SubAssign<&Vector<T>> for Vector<T>
+where
+    T: SubAssign + Zero + One + Clone,
+{
+    fn sub_assign(&mut self, vector: &Vector<T>) {
+        if let Err(err) = self.try_sub_assign(vector) {
+            panic!("{}", err);
+        }
+    }
+}
+
+/// Fallible in-place arithmetic.
+impl<T> Vector<T> {
+    /// Add `vector` to `self` entrywise, like `+=`, but return an error instead of panicking.
+    ///
+    /// Returns [DimensionError::NoMatch] if the lengths differ, `self` is unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut vec_a = vector![1, 2, 3];
+    /// vec_a.try_add_assign(&vector![1, 1, 1])?;
+    /// assert_eq!(vec_a, vector![2, 3, 4]);
+    /// assert!(vec_a.try_add_assign(&vector![1, 1]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn try_add_assign(&mut self, vector: &Vector<T>) -> Result<(), DimensionError>
+    where
+        T: AddAssign + Clone,
+    {
+        if self.len() != vector.len() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                vector.dims,
+                "add".to_owned(),
+            ));
+        }
+        self.iter_mut()
+            .zip(vector.iter())
+            .for_each(|(a, b)| *a += b.clone());
+        Ok(())
+    }
+
+    /// Subtract `vector` from `self` entrywise, like `-=`, but return an error instead of panicking.
+    ///
+    /// Returns [DimensionError::NoMatch] if the lengths differ, `self` is unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Vector;
+    /// # use libmat::vector;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut vec_a = vector![1, 2, 3];
+    /// vec_a.try_sub_assign(&vector![1, 1, 1])?;
+    /// assert_eq!(vec_a, vector![0, 1, 2]);
+    /// # Ok(()) }
+    /// ```
+    pub fn try_sub_assign(&mut self, vector: &Vector<T>) -> Result<(), DimensionError>
+    where
+        T: SubAssign + Clone,
+    {
         if self.len() != vector.len() {
-            panic!("Dimensions do not match")
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                vector.dims,
+                "subtract".to_owned(),
+            ));
         }
         self.iter_mut()
             .zip(vector.iter())
             .for_each(|(a, b)| *a -= b.clone());
+        Ok(())
     }
 }
 
@@ -187,6 +296,31 @@ where
     }
 }
 
+/// Negating a reference to a vector returns a new negated vector and leaves the original untouched.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Vector;
+/// # use libmat::vector;
+/// let vec_a = vector![1, -2, 3];
+/// assert_eq!(-&vec_a, vector![-1, 2, -3]);
+/// assert_eq!(vec_a, vector![1, -2, 3]);
+/// ```
+impl<T> Neg for &Vector<T>
+where
+    T: Neg<Output = T> + Clone,
+{
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector::<T> {
+            dims: self.dims,
+            entries: self.iter().map(|a| -(a.clone())).collect(),
+        }
+    }
+}
+
 /// Dot product of two vectors. Both vectors need to have the same length.
 ///
 /// # Example
@@ -198,17 +332,17 @@ where
 /// # fn main() -> Result<(), DimensionError> {
 /// let vec_a = Vector::new(4, 3);
 /// let vec_b = vector![5, 6, 7, 8];
-/// assert_eq!((vec_a.clone() * vec_b.clone())?, 78);
+/// assert_eq!((&vec_a * &vec_b)?, 78);
 /// assert_eq!((vec_b * vec_a)?, 78);
 /// # Ok(()) }
 /// ```
-impl<T> Mul for Vector<T>
+impl<T> Mul<&Vector<T>> for &Vector<T>
 where
     T: Mul<Output = T> + Clone + Zero + std::iter::Sum,
 {
     type Output = Result<T, DimensionError>;
 
-    fn mul(self, vector: Vector<T>) -> Self::Output {
+    fn mul(self, vector: &Vector<T>) -> Self::Output {
         if self.len() != vector.len() {
             Err(DimensionError::NoMatch(
                 self.dims,
@@ -226,6 +360,13 @@ where
     }
 }
 
+impl_binop!(impl Mul::mul for Vector<T>, Vector<T> => Result<T, DimensionError>;
+    |a, b| &a * &b; where T: Mul<Output = T> + Clone + Zero + std::iter::Sum);
+impl_binop!(impl Mul::mul for &Vector<T>, Vector<T> => Result<T, DimensionError>;
+    |a, b| a * &b; where T: Mul<Output = T> + Clone + Zero + std::iter::Sum);
+impl_binop!(impl Mul::mul for Vector<T>, &Vector<T> => Result<T, DimensionError>;
+    |a, b| &a * b; where T: Mul<Output = T> + Clone + Zero + std::iter::Sum);
+
 /// Vectors can be multiplied with matrices. The result will be a vector.
 ///
 /// # Example
@@ -239,25 +380,30 @@ where
 /// let mat_b = matrix!{1, 2, 3; 4, 4, 3; 2, 1, 3; 4, 1, 2};
 /// let vec_a = vector![4, 5, 6, 7].to_row_vector();
 /// let vec_b = vector![64, 41, 59].to_row_vector();
-/// assert_eq!((vec_a.clone() * mat_a)?, vec_a);
+/// assert_eq!((&vec_a * &mat_a)?, vec_a);
 /// assert_eq!((vec_a * mat_b)?, vec_b);
 /// # Ok(()) }
 /// ```
-impl<T> Mul<Matrix<T>> for Vector<T>
+impl<T> Mul<&Matrix<T>> for Vector<T>
 where
-    T: One + Zero + Clone + std::iter::Sum + Display,
-    Vector<T>: Into<Matrix<T>>,
+    T: One + Zero + Clone + std::iter::Sum,
 {
     type Output = Result<Vector<T>, DimensionError>;
 
-    fn mul(self, mat: Matrix<T>) -> Self::Output {
-        let vector: Vector<T> = self;
-        let mat_v: Matrix<T> = vector.into();
-        let res = (mat_v * mat)?;
+    fn mul(self, mat: &Matrix<T>) -> Self::Output {
+        let mat_v: Matrix<T> = self.into();
+        let res = (&mat_v * mat)?;
         Ok(res.into())
     }
 }
 
+impl_binop!(impl Mul::mul for Vector<T>, Matrix<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a * &b; where T: One + Zero + Clone + std::iter::Sum);
+impl_binop!(impl Mul::mul for &Vector<T>, &Matrix<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a.clone() * b; where T: One + Zero + Clone + std::iter::Sum);
+impl_binop!(impl Mul::mul for &Vector<T>, Matrix<T> => Result<Vector<T>, DimensionError>;
+    |a, b| a.clone() * &b; where T: One + Zero + Clone + std::iter::Sum);
+
 /// Elementwise multiplication.
 ///
 /// # Example
@@ -289,6 +435,42 @@ where
     }
 }
 
+/// Elementwise multiplication with the scalar on the left, for the primitive number types.
+/// The result is the same as with the scalar on the right.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Vector;
+/// # use libmat::vector;
+/// let vec_a = vector![1.0, -2.0];
+/// assert_eq!(0.5 * &vec_a, vector![0.5, -1.0]);
+/// assert_eq!(0.5 * vec_a.clone(), vec_a * 0.5);
+/// ```
+macro_rules! impl_scalar_mul_vector {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<Vector<$t>> for $t {
+                type Output = Vector<$t>;
+
+                fn mul(self, rhs: Vector<$t>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl Mul<&Vector<$t>> for $t {
+                type Output = Vector<$t>;
+
+                fn mul(self, rhs: &Vector<$t>) -> Self::Output {
+                    rhs.clone() * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul_vector!(i32, i64, u32, u64, f32, f64);
+
 /// Elementwise division. Same as multiplying with the inverse.
 ///
 /// # Example
@@ -0,0 +1,60 @@
+use crate::err::DimensionError;
+use crate::mat::{Matrix, Vector};
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+#[derive(serde::Serialize)]
+struct VectorRef<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T],
+}
+
+/// A vector is serialized like a [Matrix] with one row or one column, as `{rows, cols, data}`,
+/// so column and row vectors keep their orientation.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Vector;
+/// # use libmat::vector;
+/// let json = serde_json::to_string(&vector![1.5, 2.0]).unwrap();
+/// assert_eq!(json, r#"{"rows":2,"cols":1,"data":[1.5,2.0]}"#);
+/// ```
+impl<T> Serialize for Vector<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        VectorRef {
+            rows: self.dims.rows(),
+            cols: self.dims.cols(),
+            data: &self.entries,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializing fails with a [DimensionError] message for the same inputs as a [Matrix]
+/// and if neither `rows` nor `cols` is one.
+impl<'de, T> Deserialize<'de> for Vector<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Vector<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mat = Matrix::<T>::deserialize(deserializer)?;
+        if mat.rows() != 1 && mat.cols() != 1 {
+            return Err(D::Error::custom(DimensionError::InvalidDimensions));
+        }
+        Ok(Vector::<T> {
+            dims: mat.dims,
+            entries: mat.matrix,
+        })
+    }
+}
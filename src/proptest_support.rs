@@ -0,0 +1,164 @@
+//! [proptest](mod@proptest) strategies for matrices and vectors, available with the `proptest-support` feature.
+//!
+//! The strategies generate inputs for property tests of algebraic laws. Sizes are given as ranges,
+//! entries by another strategy, e.g. a range of numbers.
+//!
+//! # Example
+//!
+//! ```
+//! # use libmat::proptest_support::square_matrix;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     // in a test module, this would be marked with #[test]
+//!     fn transpose_is_an_involution(mat in square_matrix(-10_i64..10, 1..5)) {
+//!         prop_assert_eq!(mat.transpose().transpose(), mat);
+//!     }
+//! }
+//! # transpose_is_an_involution();
+//! ```
+
+use crate::mat::{Matrix, Vector};
+use num_traits::{One, Zero};
+use proptest::collection;
+use proptest::prelude::*;
+use std::ops::Range;
+
+/// Generate matrices with a number of rows in `rows`, a number of columns in `cols` and entries from `element`.
+///
+/// # Panics
+///
+/// If one of the ranges contains zero or is empty.
+pub fn matrix<S>(
+    element: S,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> impl Strategy<Value = Matrix<S::Value>>
+where
+    S: Strategy + Clone,
+    S::Value: Clone + Zero + One,
+{
+    assert!(
+        rows.start > 0 && cols.start > 0,
+        "matrices need at least one row and column."
+    );
+    (rows, cols).prop_flat_map(move |(rows, cols)| matrix_with_dims(element.clone(), rows, cols))
+}
+
+/// Generate `rows x cols` matrices with entries from `element`.
+///
+/// Combined with [prop_flat_map](Strategy::prop_flat_map) this creates matrices with matching dimensions,
+/// e.g. for products.
+///
+/// # Panics
+///
+/// If `rows` or `cols` is zero.
+pub fn matrix_with_dims<S>(
+    element: S,
+    rows: usize,
+    cols: usize,
+) -> impl Strategy<Value = Matrix<S::Value>>
+where
+    S: Strategy,
+    S::Value: Clone + Zero + One,
+{
+    assert!(
+        rows > 0 && cols > 0,
+        "matrices need at least one row and column."
+    );
+    collection::vec(element, rows * cols)
+        .prop_map(move |entries| Matrix::from_vec(rows, cols, entries).unwrap())
+}
+
+/// Generate square matrices with a dimension in `dim` and entries from `element`.
+///
+/// # Panics
+///
+/// If `dim` contains zero or is empty.
+pub fn square_matrix<S>(element: S, dim: Range<usize>) -> impl Strategy<Value = Matrix<S::Value>>
+where
+    S: Strategy + Clone,
+    S::Value: Clone + Zero + One,
+{
+    assert!(dim.start > 0, "matrices need at least one row and column.");
+    dim.prop_flat_map(move |dim| matrix_with_dims(element.clone(), dim, dim))
+}
+
+/// Generate column vectors with a length in `len` and entries from `element`.
+///
+/// # Panics
+///
+/// If `len` contains zero or is empty.
+pub fn vector<S>(element: S, len: Range<usize>) -> impl Strategy<Value = Vector<S::Value>>
+where
+    S: Strategy,
+    S::Value: Clone,
+{
+    assert!(len.start > 0, "vectors need at least one entry.");
+    collection::vec(element, len).prop_map(Vector::from)
+}
+
+/// Generate invertible matrices with a dimension in `dim`.
+///
+/// The matrices are products `L * U` of a unit lower triangular `L` and an upper triangular `U`
+/// with `1` or `-1` on the diagonal, with off-diagonal entries in `[-1, 1]`.
+/// So the determinant is `1` or `-1` and the matrices are invertible by construction.
+/// The rows are shuffled afterwards, so decompositions of these matrices need to pivot.
+///
+/// # Panics
+///
+/// If `dim` contains zero or is empty.
+pub fn invertible_matrix(dim: Range<usize>) -> impl Strategy<Value = Matrix<f64>> {
+    assert!(dim.start > 0, "matrices need at least one row and column.");
+    dim.prop_flat_map(|dim| {
+        (
+            matrix_with_dims(-1.0..=1.0, dim, dim),
+            matrix_with_dims(-1.0..=1.0, dim, dim),
+            collection::vec(any::<bool>(), dim),
+            Just((0..dim).collect::<Vec<usize>>()).prop_shuffle(),
+        )
+            .prop_map(move |(l_entries, u_entries, signs, perm)| {
+                let mut l = Matrix::one(dim).unwrap();
+                let mut u = Matrix::zero(dim, dim).unwrap();
+                for i in 0..dim {
+                    for j in 0..dim {
+                        if j < i {
+                            l[i][j] = l_entries[i][j];
+                        } else if j > i {
+                            u[i][j] = u_entries[i][j];
+                        }
+                    }
+                    u[i][i] = if signs[i] { 1.0 } else { -1.0 };
+                }
+                let lu = (l * u).unwrap();
+                let mut res = Matrix::zero(dim, dim).unwrap();
+                for (i, &p) in perm.iter().enumerate() {
+                    for j in 0..dim {
+                        res[i][j] = lu[p][j];
+                    }
+                }
+                res
+            })
+    })
+}
+
+/// Generate symmetric positive definite matrices with a dimension in `dim`.
+///
+/// The matrices are `B * B^T + dim * I` for a matrix `B` with entries in `[-1, 1]`,
+/// so all eigenvalues are at least `dim`.
+///
+/// # Panics
+///
+/// If `dim` contains zero or is empty.
+pub fn spd_matrix(dim: Range<usize>) -> impl Strategy<Value = Matrix<f64>> {
+    assert!(dim.start > 0, "matrices need at least one row and column.");
+    dim.prop_flat_map(|dim| {
+        matrix_with_dims(-1.0..=1.0, dim, dim).prop_map(move |b| {
+            let mut res = (b.clone() * b.transpose()).unwrap();
+            for i in 0..dim {
+                res[i][i] += dim as f64;
+            }
+            res
+        })
+    })
+}
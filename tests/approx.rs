@@ -0,0 +1,88 @@
+use libmat::mat::{Matrix, SMatrix, Vector};
+use libmat::{assert_matrix_approx_eq, matrix, smatrix, vector};
+
+#[test]
+fn matrix_approx_eq() {
+    let mat_a = matrix! {1.0, 2.0; 3.0, 4.0};
+    let close = matrix! {1.0 + 1e-10, 2.0; 3.0, 4.0 - 1e-10};
+    let far = matrix! {1.0, 2.0; 3.0, 4.001};
+    assert!(mat_a.approx_eq(&close, 1e-9));
+    assert!(!mat_a.approx_eq(&far, 1e-9));
+    assert!(mat_a.approx_eq(&far, 1e-2));
+    // different dimensions with the same entries
+    assert!(!mat_a.approx_eq(&matrix! {1.0, 2.0, 3.0, 4.0}, 1.0));
+    assert!(!matrix! {f64::NAN}.approx_eq(&matrix! {f64::NAN}, 1.0));
+
+    let big = matrix! {1e15_f32, -1e15};
+    let big_close = matrix! {1.0000001e15_f32, -1e15};
+    assert!(big.approx_eq_relative(&big_close, 1e-6));
+    assert!(!big.approx_eq_relative(&big_close, 1e-8));
+    assert!(!big.approx_eq(&big_close, 1.0));
+    assert!(matrix! {0.0, 1.0}.approx_eq_relative(&matrix! {0.0, 1.0}, 0.0));
+}
+
+#[test]
+fn vector_approx_eq() {
+    let vec_a = vector![0.5, -0.25];
+    assert!(vec_a.approx_eq(&vector![0.5 + 1e-12, -0.25], 1e-11));
+    assert!(!vec_a.approx_eq(&vector![0.5 + 1e-10, -0.25], 1e-11));
+    assert!(!vec_a.approx_eq(&vec_a.to_row_vector(), 1.0));
+    assert!(!vec_a.approx_eq(&vector![0.5, -0.25, 0.0], 1.0));
+    assert!(vec_a.approx_eq_relative(&vector![0.5000001, -0.25], 1e-6));
+    assert!(!vec_a.approx_eq_relative(&vector![0.5001, -0.25], 1e-6));
+}
+
+#[test]
+fn smatrix_approx_eq() {
+    let mat_a: SMatrix<f64, 2, 2> = smatrix! {1.0, 2.0; 3.0, 4.0};
+    assert!(mat_a.approx_eq(&smatrix! {1.0, 2.0; 3.0, 4.0 + 1e-13}, 1e-12));
+    assert!(!mat_a.approx_eq(&smatrix! {1.0, 2.0; 3.0, 4.0 + 1e-11}, 1e-12));
+    assert!(mat_a.approx_eq_relative(&smatrix! {1.0, 2.0; 3.0, 4.0 + 1e-11}, 1e-11));
+}
+
+#[test]
+fn assert_macro_accepts_close_matrices() {
+    assert_matrix_approx_eq!(matrix! {0.1 + 0.2; 1.0}, matrix! {0.3; 1.0}, 1e-12);
+    let mat_a: SMatrix<f32, 1, 2> = smatrix! {0.1 + 0.2, 1.0};
+    let mat_b: SMatrix<f32, 1, 2> = smatrix! {0.3, 1.0};
+    assert_matrix_approx_eq!(mat_a, mat_b, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "first difference at (0, 1): 2 vs 2.1")]
+fn assert_macro_reports_first_difference() {
+    assert_matrix_approx_eq!(matrix! {1.0, 2.0, 3.0}, matrix! {1.0, 2.1, 3.1}, 0.01);
+}
+
+#[test]
+#[should_panic(expected = "dimensions differ, 1x2 vs 2x1")]
+fn assert_macro_reports_dimensions() {
+    assert_matrix_approx_eq!(matrix! {1.0, 2.0}, matrix! {1.0; 2.0}, 0.01);
+}
+
+#[test]
+fn snap_noisy_product_to_identity() {
+    let mat_a = matrix! {4.0, 7.0, 2.0; 3.0, 6.0, 1.0; 2.0, 5.0, 3.0};
+    let one = Matrix::<f64>::one(3).unwrap();
+    let (_, inv) = mat_a.rref_augmented(&one).unwrap();
+    let product = (mat_a * inv).unwrap();
+    assert!(product.approx_eq(&one, 1e-12));
+    assert_eq!(product.snap_to_identity(1e-12), one);
+
+    // entries outside the tolerance are kept
+    let off = matrix! {1.0, 1e-3; 0.0, 0.999};
+    assert_eq!(off.snap_to_identity(1e-6), off);
+    assert_eq!(off.snap_to_identity(1e-2), Matrix::one(2).unwrap());
+}
+
+#[test]
+fn symmetric_up_to_round_off() {
+    let mat_a = matrix! {1.0, 2.0, 3.0; 4.0, 5.0, 6.0};
+    // A^T A is symmetric, but its entries are not necessarily computed in the same order
+    let gram = (mat_a.transpose() * mat_a.clone()).unwrap().scaled(0.1);
+    assert!(gram.is_symmetric_approx(1e-12));
+    assert!(!matrix! {1.0, 2.0; 2.1, 1.0}.is_symmetric_approx(0.05));
+    assert!(matrix! {1.0, 2.0; 2.1, 1.0}.is_symmetric_approx(0.2));
+    assert!(!matrix! {1.0, f64::NAN; f64::NAN, 1.0}.is_symmetric_approx(1.0));
+    assert!(!mat_a.is_symmetric_approx(10.0));
+}
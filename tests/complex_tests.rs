@@ -1,11 +1,108 @@
-use libmat::{err::DimensionError, mat::Matrix, matrix};
-use num_traits::Inv;
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, SMatrix},
+    matrix, smatrix,
+};
+use num_traits::{Inv, One};
 
 #[test]
 fn double_inverse() -> Result<(), DimensionError> {
     let mat_a = matrix! {{1.0, 2.0},{3.0,4.0}};
     let mat_b = matrix! {{-2.0,1.0},{1.5,-0.5}};
-    assert_eq!(mat_a.clone().inv()?, Some(mat_b.clone()));
-    assert_eq!(mat_b.inv()?, Some(mat_a));
+    // partial pivoting swaps the rows of mat_a, so the inverse is only exact up to rounding
+    assert!(mat_a.clone().inv()?.unwrap().approx_eq(&mat_b, 1e-12));
+    assert!(mat_b.inv()?.unwrap().approx_eq(&mat_a, 1e-12));
     Ok(())
 }
+
+fn assert_inverse(mat_a: &Matrix<f64>, swaps: usize) -> Result<(), DimensionError> {
+    let (_, p) = mat_a.lupdecompose()?.unwrap();
+    let dim = mat_a.rows();
+    assert_eq!(p[dim] - dim, swaps);
+    let mat_inv = mat_a.clone().inv()?.unwrap();
+    let product = (mat_a.clone() * mat_inv.clone())?;
+    assert!(product.approx_eq(&Matrix::one(dim)?, 1e-12), "{}", product);
+    let product = (mat_inv * mat_a.clone())?;
+    assert!(product.approx_eq(&Matrix::one(dim)?, 1e-12), "{}", product);
+    Ok(())
+}
+
+#[test]
+fn inverse_with_one_row_swap() -> Result<(), DimensionError> {
+    let swap = matrix! {0.0, 1.0; 1.0, 0.0};
+    assert_inverse(&swap, 1)?;
+    assert_eq!(swap.clone().inv()?, Some(swap));
+    assert_inverse(&matrix! {0.0, 2.0, 1.0; 3.0, 0.0, 2.0; 1.0, 1.0, 0.0}, 1)?;
+    Ok(())
+}
+
+#[test]
+fn inverse_with_two_row_swaps() -> Result<(), DimensionError> {
+    let cycle = matrix! {0.0, 1.0, 0.0; 0.0, 0.0, 1.0; 1.0, 0.0, 0.0};
+    assert_inverse(&cycle, 2)?;
+    assert_eq!(cycle.clone().inv()?, Some(cycle.transpose()));
+    assert_eq!(cycle.det()?, 1.0);
+    assert_inverse(&matrix! {1.0, 2.0, 0.0; 2.0, 1.0, 4.0; 4.0, 0.0, 1.0}, 2)?;
+    Ok(())
+}
+
+#[test]
+fn lupdecompose_swaps_rows() -> Result<(), DimensionError> {
+    let mat_a = matrix! {0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 2.0};
+    let (lu, p) = mat_a.lupdecompose()?.unwrap();
+    let mut mat_l = Matrix::one(3)?;
+    let mut mat_u = Matrix::zero(3, 3)?;
+    let mut mat_pa = Matrix::zero(3, 3)?;
+    for i in 0..3 {
+        for j in 0..3 {
+            if j < i {
+                mat_l[i][j] = lu[i][j];
+            } else {
+                mat_u[i][j] = lu[i][j];
+            }
+            mat_pa[i][j] = mat_a[p[i]][j];
+        }
+    }
+    assert!((mat_l * mat_u)?.approx_eq(&mat_pa, 1e-12));
+    Ok(())
+}
+
+#[test]
+fn lupdecompose_results_are_unchanged() -> Result<(), DimensionError> {
+    // rows are swapped in place and only recorded in `p`, the results are the same as with permutation matrices
+    let mat_a = matrix! {0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 2.0};
+    let (lu, p) = mat_a.lupdecompose()?.unwrap();
+    assert_eq!(p, vec![2, 0, 1, 5]);
+    assert!(lu.approx_eq(
+        &matrix! {3.0, 0.0, 2.0; 0.0, 2.0, 1.0; 1.0 / 3.0, 0.5, -7.0 / 6.0},
+        1e-15
+    ));
+    assert!((mat_a.det()? + 7.0_f64).abs() < 1e-12);
+
+    let (lu, p) = matrix! {1.0, 2.0; 4.0, 2.0}.lupdecompose()?.unwrap();
+    assert_eq!(lu, matrix! {4.0, 2.0; 0.25, 1.5});
+    assert_eq!(p, vec![1, 0, 3]);
+
+    let (lu, p) = Matrix::<f64>::one(4)?.lupdecompose()?.unwrap();
+    assert_eq!(lu, Matrix::one(4)?);
+    assert_eq!(p, vec![0, 1, 2, 3, 4]);
+    Ok(())
+}
+
+#[test]
+fn smatrix_inverse_of_4x4() {
+    let mat_a: SMatrix<f64, 4, 4> = smatrix! {
+        0.0, -1.0, 0.0, 3.0;
+        1.0, 4.0, 2.0, 0.0;
+        0.0, 3.0, -2.0, 1.0;
+        5.0, 0.0, 1.0, 2.0
+    };
+    let (_, _, swaps) = mat_a.lupdecompose().unwrap();
+    assert!(swaps > 0);
+    let mat_inv = mat_a.clone().inv().unwrap();
+    assert!((mat_a.clone() * mat_inv.clone()).approx_eq(&SMatrix::one(), 1e-12));
+    assert!((mat_inv * mat_a).approx_eq(&SMatrix::one(), 1e-12));
+
+    let one: SMatrix<i32, 4, 4> = SMatrix::one();
+    assert_eq!(one.inv(), Some(SMatrix::one()));
+}
@@ -0,0 +1,58 @@
+use libmat::{
+    err::{CsvError, ParseMatrixError},
+    mat::Matrix,
+    matrix,
+};
+
+#[test]
+fn round_trip() -> Result<(), CsvError> {
+    let mat_a = matrix! {0.1, -2.5, 1e-12; 3.0, 1e300, -0.0};
+    let mut out = Vec::new();
+    mat_a.to_csv(&mut out)?;
+    assert_eq!(Matrix::<f64>::from_csv(out.as_slice())?, mat_a);
+
+    let mat_b = matrix! {1, 2; 3, 4; 5, 6};
+    let mut out = Vec::new();
+    mat_b.to_csv(&mut out)?;
+    assert_eq!(String::from_utf8(out).unwrap(), "1,2\n3,4\n5,6\n");
+    Ok(())
+}
+
+#[test]
+fn read_spreadsheet_export() -> Result<(), CsvError> {
+    let data = "1.5, 2\r\n\"3\",4\r\n\r\n";
+    assert_eq!(
+        Matrix::<f64>::from_csv(data.as_bytes())?,
+        matrix! {1.5, 2.0; 3.0, 4.0}
+    );
+    Ok(())
+}
+
+#[test]
+fn reject_invalid_records() {
+    let parse_error = |data: &str| match Matrix::<i32>::from_csv(data.as_bytes()) {
+        Err(CsvError::Parse(err)) => err,
+        other => panic!("expected a parse error, got {:?}", other),
+    };
+    assert_eq!(
+        parse_error("1,2,3\n4,5\n"),
+        ParseMatrixError::RaggedRow {
+            row: 1,
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(
+        parse_error("1,2\n3,x\n"),
+        ParseMatrixError::InvalidEntry {
+            row: 1,
+            col: 1,
+            token: "x".to_owned()
+        }
+    );
+    assert_eq!(parse_error(""), ParseMatrixError::Empty);
+    assert!(matches!(
+        Matrix::<i32>::from_csv(&[b'1', b',', 0xff][..]),
+        Err(CsvError::Csv(_))
+    ));
+}
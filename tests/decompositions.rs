@@ -0,0 +1,443 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, Vector},
+    matrix, vector,
+};
+use num_traits::Inv;
+
+fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, eps: f64) {
+    assert_eq!(a.dims(), b.dims());
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert!(
+                (a[i][j] - b[i][j]).abs() < eps,
+                "entry ({}, {}) differs: {} vs {}",
+                i,
+                j,
+                a[i][j],
+                b[i][j]
+            );
+        }
+    }
+}
+
+#[test]
+fn cholesky_reconstructs() -> Result<(), DimensionError> {
+    let mat_a = matrix! {
+        6.0, 3.0, 4.0, 8.0;
+        3.0, 6.0, 5.0, 1.0;
+        4.0, 5.0, 10.0, 7.0;
+        8.0, 1.0, 7.0, 25.0
+    };
+    let mat_l = mat_a.cholesky()?.expect("matrix is positive-definite");
+    for i in 0..4 {
+        for j in (i + 1)..4 {
+            assert_eq!(mat_l[i][j], 0.0);
+        }
+    }
+    assert_close(&(mat_l.clone() * mat_l.transpose())?, &mat_a, 1e-12);
+    Ok(())
+}
+
+#[test]
+fn cholesky_not_positive_definite() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1.0, 2.0; 2.0, 4.0};
+    assert_eq!(mat_a.cholesky()?, None);
+    let mat_b: Matrix<f32> = matrix! {-1.0, 0.0; 0.0, 1.0};
+    assert_eq!(mat_b.cholesky()?, None);
+    Ok(())
+}
+
+#[test]
+fn cholesky_not_square() -> Result<(), DimensionError> {
+    let mat_a = Matrix::new(2, 3, 1.0_f64)?;
+    assert_eq!(mat_a.cholesky(), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+fn hilbert(n: usize) -> Matrix<f64> {
+    Matrix::from_iter(n, n, (0..n * n).map(|k| 1.0 / ((k / n + k % n + 1) as f64))).unwrap()
+}
+
+fn binomial(n: i64, k: i64) -> f64 {
+    (1..=k).fold(1.0, |acc, i| acc * (n - k + i) as f64 / i as f64)
+}
+
+fn residual(mat_a: &Matrix<f64>, mat_x: &Matrix<f64>) -> f64 {
+    let r =
+        ((mat_a.clone() * mat_x.clone()).unwrap() - Matrix::one(mat_a.rows()).unwrap()).unwrap();
+    (0..r.rows())
+        .map(|i| r[i].iter().map(|x| x * x).sum::<f64>())
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// The symmetric Pascal matrix with entries `binomial(i + j, i)`. It is badly conditioned, but its inverse
+/// has integer entries, so an accurate inverse is exact.
+fn pascal(n: usize) -> Matrix<f64> {
+    Matrix::from_iter(
+        n,
+        n,
+        (0..n * n).map(|k| binomial((k / n + k % n) as i64, (k / n) as i64)),
+    )
+    .unwrap()
+}
+
+#[test]
+fn newton_schulz_decreases_residual() -> Result<(), DimensionError> {
+    let mat_a = pascal(12);
+    let mat_inv = mat_a.clone().inv()?.unwrap();
+    let r0 = residual(&mat_a, &mat_inv);
+    let r1 = residual(&mat_a, &mat_a.refine_inverse(&mat_inv, 1)?);
+    let r2 = residual(&mat_a, &mat_a.refine_inverse(&mat_inv, 2)?);
+    assert!(r0 > 0.0);
+    assert!(r1 <= r0, "{} > {}", r1, r0);
+    assert!(r2 <= r1, "{} > {}", r2, r1);
+    Ok(())
+}
+
+#[test]
+fn inv_refined_improves_inv() -> Result<(), DimensionError> {
+    let mat_a = pascal(10);
+    let mat_inv = mat_a.clone().inv()?.unwrap();
+    let refined = mat_a.inv_refined(2)?.unwrap();
+    assert!(residual(&mat_a, &refined) <= residual(&mat_a, &mat_inv));
+    assert!(mat_inv.into_iter().any(|x| x != x.round()));
+    assert!(refined.into_iter().all(|x| x == x.round()));
+    Ok(())
+}
+
+#[test]
+fn refine_inverse_dimension_errors() -> Result<(), DimensionError> {
+    let mat_a = Matrix::new(2, 3, 1.0_f64)?;
+    assert_eq!(
+        mat_a.refine_inverse(&Matrix::one(2)?, 1),
+        Err(DimensionError::NoSquare)
+    );
+    let mat_b = Matrix::<f64>::one(3)?;
+    assert_eq!(
+        mat_b.refine_inverse(&Matrix::one(2)?, 1),
+        Err(DimensionError::NoMatch(
+            mat_b.dims(),
+            Matrix::<f64>::one(2)?.dims(),
+            "multiply".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn qr_reconstructs() -> Result<(), DimensionError> {
+    let mat_a = matrix! {
+        12.0, -51.0, 4.0;
+        6.0, 167.0, -68.0;
+        -4.0, 24.0, -41.0;
+        -1.0, 1.0, 0.0;
+        2.0, 0.0, 3.0
+    };
+    let (q, r) = mat_a.qr()?;
+    assert_eq!(q.rows(), 5);
+    assert_eq!(q.cols(), 3);
+    assert_eq!(r.rows(), 3);
+    for i in 0..3 {
+        assert!(r[i][i] >= 0.0);
+        for j in 0..i {
+            assert_eq!(r[i][j], 0.0);
+        }
+    }
+    assert_close(&(q.clone() * r)?, &mat_a, 1e-10);
+    assert_close(&(q.transpose() * q)?, &Matrix::one(3)?, 1e-12);
+
+    let (q, r) = matrix! {0.0, 1.0; 1.0, 1.0}.qr()?;
+    assert_close(&(q * r)?, &matrix! {0.0, 1.0; 1.0, 1.0}, 1e-15);
+    assert_eq!(
+        matrix! {1.0, 2.0, 3.0}.qr(),
+        Err(DimensionError::InvalidInputDimensions(1, 3))
+    );
+    Ok(())
+}
+
+#[test]
+fn lstsq_fits_line() -> Result<(), DimensionError> {
+    // points scattered around y = 0.5 + 2 t
+    let ts = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys: Vector<f64> = vector![0.4, 2.6, 4.5, 6.4, 8.6];
+    let mat_a = Matrix::from_iter(5, 2, ts.iter().flat_map(|t| vec![1.0, *t]))?;
+    let x = mat_a.lstsq(&ys)?;
+    // normal equations solved by hand: slope = 20.2 / 10, intercept = mean(y) - slope * mean(t)
+    assert!((x[1] - 2.02).abs() < 1e-12);
+    assert!((x[0] - (4.5 - 2.02 * 2.0)).abs() < 1e-12);
+    assert!(x.is_col_vector());
+
+    // square and exactly solvable
+    let x: Vector<f64> = matrix! {2.0, 1.0; 1.0, 3.0}.lstsq(&vector![3.0, 5.0])?;
+    assert!((x[0] - 0.8).abs() < 1e-12);
+    assert!((x[1] - 1.4).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn weighted_least_squares() -> Result<(), DimensionError> {
+    let ts = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys: Vector<f64> = vector![0.4, 2.6, 4.5, 6.4, 8.6];
+    let mat_a = Matrix::from_iter(5, 2, ts.iter().flat_map(|t| vec![1.0, *t]))?;
+
+    let unit = mat_a.solve_weighted_least_squares(&ys, &Vector::new(5, 1.0))?;
+    assert!(unit.approx_eq(&mat_a.lstsq(&ys)?, 1e-12));
+
+    // doubling the weight of the last observation is the same as having it twice
+    let doubled = mat_a.solve_weighted_least_squares(&ys, &vector![1.0, 1.0, 1.0, 1.0, 2.0])?;
+    let duplicated = Matrix::from_iter(
+        6,
+        2,
+        [0.0, 1.0, 2.0, 3.0, 4.0, 4.0]
+            .iter()
+            .flat_map(|t| vec![1.0, *t]),
+    )?;
+    let ys_duplicated = vector![0.4, 2.6, 4.5, 6.4, 8.6, 8.6];
+    assert!(doubled.approx_eq(&duplicated.lstsq(&ys_duplicated)?, 1e-12));
+    assert!(!doubled.approx_eq(&unit, 1e-3));
+
+    // integer design matrices are converted
+    let ints: Matrix<i32> = matrix! {1, 0; 1, 1; 1, 2};
+    let x = ints.solve_weighted_least_squares(&vector![1.0, 3.0, 5.0], &vector![0.5, 2.0, 0.0])?;
+    assert!(x.approx_eq(&vector![1.0, 2.0], 1e-12));
+
+    assert_eq!(
+        mat_a.solve_weighted_least_squares(&ys, &vector![1.0, 1.0, -1.0, 1.0, 1.0]),
+        Err(DimensionError::InvalidValue(
+            "weights need to be finite and non-negative".to_owned()
+        ))
+    );
+    assert!(mat_a
+        .solve_weighted_least_squares(&ys, &vector![1.0, f64::NAN, 1.0, 1.0, 1.0])
+        .is_err());
+    assert!(matches!(
+        mat_a.solve_weighted_least_squares(&ys, &vector![1.0, 1.0]),
+        Err(DimensionError::NoMatch(..))
+    ));
+    // only one observation is left, which can't determine two parameters
+    assert!(matches!(
+        mat_a.solve_weighted_least_squares(&ys, &vector![0.0, 0.0, 0.0, 0.0, 1.0]),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn lstsq_errors() -> Result<(), DimensionError> {
+    let rank_one = matrix! {1.0, 2.0; 2.0, 4.0; 3.0, 6.0};
+    assert!(matches!(
+        rank_one.lstsq(&vector![1.0, 2.0, 3.0]),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        matrix! {1.0, 0.0; 0.0, 1.0}.lstsq(&Vector::from(vec![1.0, 2.0, 3.0])),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    Ok(())
+}
+
+#[test]
+fn solve_needs_row_swaps() -> Result<(), DimensionError> {
+    // the first pivot is zero, so the solution depends on the permutation being applied to b
+    let mat_a = matrix! {0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 2.0};
+    let expected = vector![1.0, -2.0, 3.0];
+    let b = (mat_a.clone() * expected.clone())?;
+    let x = mat_a.solve(&b)?.unwrap();
+    assert!(x.approx_eq(&expected, 1e-12), "{:?}", x);
+    assert_eq!(
+        matrix! {1.0, 2.0; 2.0, 4.0}.solve(&vector![1.0, 1.0])?,
+        None
+    );
+    assert!(matches!(
+        matrix! {1.0, 2.0}.solve(&vector![1.0]),
+        Err(DimensionError::NoSquare)
+    ));
+    Ok(())
+}
+
+/// Largest residual of a row relative to the magnitude of its terms, `|A x - b|_i / (|A| |x| + |b|)_i`.
+fn componentwise_residual(mat_a: &Matrix<f64>, x: &Vector<f64>, b: &Vector<f64>) -> f64 {
+    (0..mat_a.rows())
+        .map(|i| {
+            let terms = (0..mat_a.cols()).map(|j| mat_a[i][j] * x[j]);
+            let scale = terms.clone().map(f64::abs).sum::<f64>() + b[i].abs();
+            (terms.sum::<f64>() - b[i]).abs() / scale
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn solve_report_of_well_conditioned_system() -> Result<(), DimensionError> {
+    let mat_a = matrix! {4.0, 1.0, 0.0; 1.0, 4.0, 1.0; 0.0, 1.0, 4.0};
+    let b = vector![1.0, 2.0, 3.0];
+    let report = mat_a.solve_checked(&b)?;
+    assert!(!report.accuracy_warning);
+    assert!(report.residual_norm < 1e-14, "{}", report.residual_norm);
+    // the inverse is [15, -4, 1; -4, 16, -4; 1, -4, 15] / 56 with the 1-norm 3/7, so the condition number is 6 * 3/7
+    assert!((report.condition_estimate - 18.0 / 7.0).abs() < 1e-12);
+    assert_eq!(Some(report.solution.clone()), mat_a.solve(&b)?);
+
+    let residual = ((mat_a * report.solution)? - b)?;
+    assert_eq!(residual.norm(), report.residual_norm);
+    Ok(())
+}
+
+#[test]
+fn solve_report_warns_for_hilbert_matrix() -> Result<(), DimensionError> {
+    let mat_h = hilbert(10);
+    let expected = Vector::from(vec![1.0; 10]);
+    let b = (mat_h.clone() * expected.clone())?;
+    let report = mat_h.solve_checked(&b)?;
+    // the condition number of the 10x10 Hilbert matrix is about 3.5e13
+    assert!(report.accuracy_warning);
+    assert!(
+        report.condition_estimate > 1e12,
+        "{}",
+        report.condition_estimate
+    );
+
+    let residual = ((mat_h * report.solution.clone())? - b)?;
+    assert_eq!(residual.norm(), report.residual_norm);
+
+    assert!(matches!(
+        matrix! {1.0, 2.0; 2.0, 4.0}.solve_checked(&vector![1.0, 2.0]),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn equilibrated_solve_of_badly_scaled_system() -> Result<(), DimensionError> {
+    // the first equation is 1e-10 * x + y = 1 + 1e-10 scaled by 1e20, so partial pivoting
+    // chooses its tiny coefficient of x as pivot unless the rows are scaled back
+    let mat_a = matrix! {1e10, 1e20, 0.0; 1.0, 1.0, 1.0; 0.0, 3.0, 1.0};
+    let expected = vector![1.0, 1.0, 1.0];
+    let b = (mat_a.clone() * expected.clone())?;
+
+    let direct = mat_a.solve(&b)?.unwrap();
+    let equilibrated = mat_a.solve_equilibrated(&b)?.unwrap();
+    let direct_residual = componentwise_residual(&mat_a, &direct, &b);
+    let equilibrated_residual = componentwise_residual(&mat_a, &equilibrated, &b);
+    assert!(
+        equilibrated_residual * 1e6 < direct_residual,
+        "{} vs {}",
+        equilibrated_residual,
+        direct_residual
+    );
+    assert!(
+        equilibrated.approx_eq(&expected, 1e-9),
+        "{:?}",
+        equilibrated
+    );
+
+    let (scaled, row_scale, col_scale) = mat_a.equilibrate()?;
+    for factor in row_scale.iter().chain(col_scale.iter()) {
+        assert_eq!(
+            factor.log2().fract(),
+            0.0,
+            "{} is not a power of two",
+            factor
+        );
+    }
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_eq!(scaled[i][j], row_scale[i] * mat_a[i][j] * col_scale[j]);
+        }
+        let row_max = scaled[i].iter().fold(0.0, |max: f64, x| max.max(x.abs()));
+        assert!(
+            (0.5..=2.0).contains(&row_max),
+            "row {} has maximum {}",
+            i,
+            row_max
+        );
+    }
+
+    assert!(matches!(
+        matrix! {1.0, f64::NAN; 0.0, 1.0}.equilibrate(),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        matrix! {1.0, 2.0}.solve_equilibrated(&vector![1.0]),
+        Err(DimensionError::NoSquare)
+    ));
+    Ok(())
+}
+
+#[test]
+fn equilibrate_subnormal_row() -> Result<(), DimensionError> {
+    let tiny = 1e-310;
+    let mat_a = matrix! {tiny, 2.0 * tiny; 1.0, 3.0};
+    let (scaled, row_scale, col_scale) = mat_a.equilibrate()?;
+    assert!(row_scale
+        .iter()
+        .chain(col_scale.iter())
+        .all(|x| x.is_finite()));
+    assert!(scaled[0].iter().all(|x| x.is_normal()));
+    let x = mat_a
+        .solve_equilibrated(&vector![3.0 * tiny, 4.0])?
+        .unwrap();
+    assert!(x.approx_eq(&vector![1.0, 1.0], 1e-9), "{:?}", x);
+    Ok(())
+}
+
+#[test]
+fn checked_routines_reject_nonfinite_entries() -> Result<(), DimensionError> {
+    let nan = matrix! {1.0, 2.0; f64::NAN, 4.0};
+    assert!(nan.has_nonfinite());
+    assert!(matches!(
+        nan.solve_checked(&vector![1.0, 2.0]),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        nan.det_checked(),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        nan.inv_checked(),
+        Err(DimensionError::InvalidValue(_))
+    ));
+
+    let mat_a = matrix! {2.0, 1.0; 1.0, 3.0};
+    assert!(!mat_a.has_nonfinite());
+    assert!(matches!(
+        mat_a.solve_checked(&vector![1.0, f64::INFINITY]),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    let x = mat_a.solve_checked(&vector![3.0, 5.0])?.solution;
+    assert!(x.approx_eq(&vector![0.8, 1.4], 1e-12));
+    assert_eq!(mat_a.det_checked()?, mat_a.det()?);
+    Ok(())
+}
+
+#[test]
+fn gram_schmidt_orthonormal() -> Result<(), DimensionError> {
+    let mat_a = matrix! {
+        1.0, 2.0, 0.0;
+        1.0, 0.0, 3.0;
+        0.0, 1.0, 1.0;
+        1.0, 1.0, 2.0
+    };
+    let q = mat_a.gram_schmidt().unwrap();
+    assert_eq!((q.rows(), q.cols()), (4, 3));
+    assert_close(&(q.transpose() * q.clone())?, &Matrix::one(3)?, 1e-12);
+    // same column space: projecting A onto the columns of Q gives A back
+    let projected = (q.clone() * (q.transpose() * mat_a.clone())?)?;
+    assert_close(&projected, &mat_a, 1e-12);
+
+    // nearly dependent columns of the Hilbert matrix stay orthonormal
+    let q = hilbert(8).gram_schmidt().unwrap();
+    assert_close(&(q.transpose() * q.clone())?, &Matrix::one(8)?, 1e-10);
+    Ok(())
+}
+
+#[test]
+fn gram_schmidt_skips_dependent_columns() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 0, 2, 1; 0, 0, 0, 1; 0, 0, 0, 1};
+    let q = mat_a.gram_schmidt().unwrap();
+    let s = 0.5_f64.sqrt();
+    assert_close(&q, &matrix! {1.0, 0.0; 0.0, s; 0.0, s}, 1e-15);
+    assert_eq!(Matrix::new(3, 2, 0.0)?.gram_schmidt(), None);
+    Ok(())
+}
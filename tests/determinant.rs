@@ -1,4 +1,9 @@
-use libmat::{err::DimensionError, mat::Matrix};
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, SMatrix},
+    matrix, smatrix,
+};
+use num_traits::{Inv, One};
 
 #[test]
 fn not_square() -> Result<(), DimensionError> {
@@ -26,7 +31,8 @@ fn null_mat() -> Result<(), DimensionError> {
 #[test]
 fn some_dets() -> Result<(), DimensionError> {
     let a = Matrix::<f32>::from_vec(3, 3, vec![1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0])?;
-    assert_eq!(a.det()?, -12_f32);
+    // the pivots of the LUP decomposition are rounded to f32, so the determinants are close, not exact
+    assert!((a.det()? + 12.0).abs() <= 12.0 * 1e-6, "{}", a.det()?);
     let b = Matrix::<f32>::from_vec(
         8,
         8,
@@ -39,6 +45,281 @@ fn some_dets() -> Result<(), DimensionError> {
         .map(|x| (*x as i16).into())
         .collect(),
     )?;
-    assert_eq!(b.det()?, -15546220_f32);
+    assert!(
+        (b.det()? + 15546220.0).abs() <= 15546220.0 * 1e-5,
+        "{}",
+        b.det()?
+    );
     Ok(())
 }
+
+#[test]
+fn integer_dets() -> Result<(), DimensionError> {
+    // the row swaps of the LUP decomposition would truncate the multipliers of det()
+    assert_eq!(matrix! {2, 1; 4, 3}.det_bareiss()?, 2);
+    assert_eq!(matrix! {1, 2; 2, 4}.det_bareiss()?, 0);
+    Ok(())
+}
+
+#[test]
+fn integer_lupdecompose_doesnt_overflow() -> Result<(), DimensionError> {
+    // a determinant of these entries overflows i32, the decomposition itself doesn't
+    let entries = (0..36).map(|i| (i * 577 % 2001) - 1000).collect();
+    let mat_a = Matrix::<i32>::from_vec(6, 6, entries)?;
+    assert!(mat_a.lupdecompose()?.is_some());
+    Ok(())
+}
+
+#[test]
+fn bareiss() -> Result<(), DimensionError> {
+    let a = Matrix::<i64>::from_vec(3, 3, vec![1, 2, 3, 3, 2, 1, 2, 1, 3])?;
+    assert_eq!(a.det_bareiss()?, -12);
+    let b = Matrix::<i64>::from_vec(
+        8,
+        8,
+        vec![
+            8, 6, 1, 0, 1, 9, 5, 9, 9, 9, 0, 8, 4, 3, 4, 0, 5, 6, 5, 1, 0, 9, 4, 6, 4, 9, 8, 3, 5,
+            1, 10, 6, 3, 10, 7, 4, 9, 2, 0, 1, 2, 1, 6, 8, 7, 3, 2, 9, 1, 7, 1, 4, 4, 9, 0, 0, 7,
+            6, 4, 0, 10, 4, 5, 9,
+        ],
+    )?;
+    assert_eq!(b.det_bareiss()?, -15546220);
+    Ok(())
+}
+
+#[test]
+fn bareiss_pivoting() -> Result<(), DimensionError> {
+    let a = Matrix::<i64>::from_vec(3, 3, vec![0, 1, 2, 1, 0, 3, 4, -3, 8])?;
+    assert_eq!(a.det_bareiss()?, -2);
+    let singular = Matrix::<i64>::from_vec(3, 3, vec![1, 2, 3, 2, 4, 6, 1, 0, 1])?;
+    assert_eq!(singular.det_bareiss()?, 0);
+    assert_eq!(Matrix::<i64>::one(1)?.det_bareiss()?, 1);
+    assert_eq!(
+        Matrix::new(2, 3, 1_i64)?.det_bareiss(),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
+
+#[test]
+fn bareiss_large_integers() -> Result<(), DimensionError> {
+    // Vandermonde matrix, det = prod_{i<j} (x_j - x_i)
+    let xs: Vec<i64> = vec![2, 3, 5, 7, 11, 13];
+    let n = xs.len();
+    let entries = xs
+        .iter()
+        .flat_map(|&x| (0..n as u32).map(move |k| x.pow(k)))
+        .collect();
+    let v = Matrix::<i64>::from_vec(n, n, entries)?;
+    let mut expected = 1_i64;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            expected *= xs[j] - xs[i];
+        }
+    }
+    assert_eq!(v.det_bareiss()?, expected);
+    Ok(())
+}
+
+#[test]
+fn cofactors() -> Result<(), DimensionError> {
+    let a = Matrix::<i64>::from_vec(3, 3, vec![1, 2, 3, 0, 4, 5, 1, 0, 6])?;
+    assert_eq!(a.cofactor(0, 0)?, 24);
+    assert_eq!(a.cofactor(1, 2)?, 2);
+    assert_eq!(a.cofactor(2, 1)?, -5);
+    // Laplace expansion along the first row
+    let det: i64 = (0..3).map(|j| a[0][j] * a.cofactor(0, j).unwrap()).sum();
+    assert_eq!(det, a.det_bareiss()?);
+    assert_eq!(Matrix::<i64>::one(1)?.cofactor(0, 0)?, 1);
+    assert_eq!(
+        Matrix::new(2, 3, 1_i64)?.cofactor(0, 0),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
+
+#[test]
+fn adjugate() -> Result<(), DimensionError> {
+    let a = Matrix::<i64>::from_vec(3, 3, vec![1, 2, 3, 0, 4, 5, 1, 0, 6])?;
+    let adj = a.adjugate()?;
+    assert_eq!(
+        adj,
+        Matrix::from_vec(3, 3, vec![24, -12, -2, 5, 3, -5, -4, 2, 4])?
+    );
+    assert_eq!(
+        (a.clone() * adj.clone())?,
+        Matrix::diag(3, a.det_bareiss()?)?
+    );
+    assert_eq!((adj * a)?, Matrix::diag(3, 22)?);
+
+    // unimodular integer matrix, the adjugate is the exact inverse
+    let u = Matrix::<i64>::from_vec(3, 3, vec![2, 3, 1, 1, 2, 1, 1, 1, 1])?;
+    assert_eq!(u.det_bareiss()?, 1);
+    assert_eq!((u.clone() * u.adjugate()?)?, Matrix::one(3)?);
+
+    assert_eq!(
+        Matrix::new(3, 2, 1_i64)?.adjugate(),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn cofactor_out_of_bounds() {
+    let a = Matrix::<i64>::one(2).unwrap();
+    let _ = a.cofactor(2, 0);
+}
+
+#[test]
+fn orientation() {
+    let e1_e2: SMatrix<f64, 2, 2> = smatrix! {1.0, 0.0; 0.0, 1.0};
+    assert_eq!(e1_e2.signed_area(), 1.0);
+    // columns (1, 1) and (-1, 2): the second one is counter-clockwise from the first one
+    let ccw: SMatrix<f64, 2, 2> = smatrix! {1.0, -1.0; 1.0, 2.0};
+    assert_eq!(ccw.signed_area(), 3.0);
+    let cw: SMatrix<f64, 2, 2> = smatrix! {-1.0, 1.0; 2.0, 1.0};
+    assert_eq!(cw.signed_area(), -3.0);
+    let parallel: SMatrix<f64, 2, 2> = smatrix! {1.0, 2.0; 2.0, 4.0};
+    assert_eq!(parallel.signed_area(), 0.0);
+
+    let right: SMatrix<f64, 3, 3> = smatrix! {1.0, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1.0};
+    assert_eq!(right.signed_volume(), 1.0);
+    // swapping two vectors flips the handedness
+    let left: SMatrix<f64, 3, 3> = smatrix! {0.0, 1.0, 0.0; 1.0, 0.0, 0.0; 0.0, 0.0, 1.0};
+    assert_eq!(left.signed_volume(), -1.0);
+    let skew: SMatrix<f64, 3, 3> = smatrix! {2.0, 1.0, 0.0; 0.0, 3.0, 1.0; 1.0, 0.0, 1.0};
+    assert_eq!(skew.signed_volume(), 7.0);
+    assert_eq!(skew.transpose().signed_volume(), 7.0);
+    let planar: SMatrix<f64, 3, 3> = smatrix! {1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0};
+    assert_eq!(planar.signed_volume(), 0.0);
+}
+
+#[test]
+fn exact_integer_dets() -> Result<(), DimensionError> {
+    let entries = vec![
+        8, 6, 1, 0, 1, 9, 5, 9, 9, 9, 0, 8, 4, 3, 4, 0, 5, 6, 5, 1, 0, 9, 4, 6, 4, 9, 8, 3, 5, 1,
+        10, 6, 3, 10, 7, 4, 9, 2, 0, 1, 2, 1, 6, 8, 7, 3, 2, 9, 1, 7, 1, 4, 4, 9, 0, 0, 7, 6, 4, 0,
+        10, 4, 5, 9,
+    ];
+    let b = Matrix::<i64>::from_vec(8, 8, entries.clone())?;
+    assert_eq!(b.det_bareiss()?, -15546220);
+    let a = Matrix::<i32>::from_vec(3, 3, vec![1, 2, 3, 3, 2, 1, 2, 1, 3])?;
+    assert_eq!(a.det_bareiss()?, -12);
+    assert_eq!(Matrix::<i32>::one(3)?.det_bareiss()?, 1);
+    Ok(())
+}
+
+#[test]
+fn smatrix_det_exact_beyond_f64_precision() {
+    let mat_a: SMatrix<i128, 5, 5> = smatrix! {
+        -1852, -1929, -920, -1234, -2086;
+        2843, -919, -2956, 2280, 1357;
+        1437, -789, 1006, -2976, 1654;
+        1825, 2642, -2487, -2868, -1960;
+        1446, 865, 1894, 2377, -2935
+    };
+    let det = 1_274_611_249_575_526_525_i128;
+    // the determinant is odd and larger than 2^53, so no f64 can hold it
+    assert_ne!(det as f64 as i128, det);
+    assert_eq!(mat_a.det_exact(), det);
+
+    let dynamic = Matrix::from_vec(5, 5, mat_a.iter().flatten().copied().collect()).unwrap();
+    assert_eq!(dynamic.det_bareiss().unwrap(), det);
+}
+
+#[test]
+fn smatrix_det_exact_swaps_and_singular() {
+    let swapped: SMatrix<i64, 3, 3> = smatrix! {0, 1, 2; 1, 0, 3; 4, -3, 8};
+    assert_eq!(swapped.det_exact(), -2);
+    let singular: SMatrix<i64, 3, 3> = smatrix! {1, 2, 3; 2, 4, 6; 0, 1, 1};
+    assert_eq!(singular.det_exact(), 0);
+    let one: SMatrix<i8, 1, 1> = smatrix! {7};
+    assert_eq!(one.det_exact(), 7);
+    let empty: SMatrix<i64, 0, 0> = SMatrix::from([[0; 0]; 0]);
+    assert_eq!(empty.det_exact(), 1);
+}
+
+#[test]
+fn det_exact_with_nearly_zero_row() -> Result<(), DimensionError> {
+    // the third row has a single non-zero entry, so a cofactor expansion along it
+    // reduces the determinant to 5 * (-1)^(2 + 1) * det of the minor without row 2 and column 1
+    let mat_a: SMatrix<i64, 4, 4> = smatrix! {
+        3, 1, 4, 1;
+        5, 9, 2, 6;
+        0, 5, 0, 0;
+        5, 8, 9, 7
+    };
+    let minor: SMatrix<i64, 3, 3> = smatrix! {3, 4, 1; 5, 2, 6; 5, 9, 7};
+    assert_eq!(mat_a.det_exact(), -5 * minor.det_exact());
+    assert_eq!(minor.det_exact(), -105);
+
+    let dynamic = Matrix::from_vec(4, 4, mat_a.iter().flatten().copied().collect())?;
+    assert_eq!(dynamic.det_bareiss()?, 525);
+    assert_eq!(dynamic.cofactor(2, 1)?, 105);
+    Ok(())
+}
+
+#[test]
+fn smatrix_det_of_4x4() {
+    let id: SMatrix<i32, 4, 4> = SMatrix::one();
+    assert_eq!(id.det(), 1.0);
+    // the first pivot is zero, so the decomposition has to swap rows
+    let mat_a: SMatrix<i32, 4, 4> = smatrix! {
+        0, -1, 0, 3;
+        1, 4, 2, 0;
+        0, 3, -2, 1;
+        5, 0, 1, 2
+    };
+    let exact = mat_a.det_exact();
+    assert_eq!(exact, 206);
+    assert!((mat_a.det() - exact as f64).abs() < 1e-9, "{}", mat_a.det());
+    let singular: SMatrix<f64, 4, 4> = smatrix! {
+        1.0, 2.0, 3.0, 4.0;
+        2.0, 4.0, 6.0, 8.0;
+        0.0, 1.0, 0.0, 1.0;
+        1.0, 0.0, 1.0, 0.0
+    };
+    assert_eq!(singular.det(), 0.0);
+}
+
+#[test]
+fn singular_within_tolerance() -> Result<(), DimensionError> {
+    // the last pivot is tiny but not exactly zero
+    let mat_a = matrix! {1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0 + 1e-13};
+    assert_ne!(mat_a.det()?, 0.0);
+    assert!(mat_a.is_singular(1e-10)?);
+    assert!(!mat_a.is_singular(1e-16)?);
+    // scaling doesn't change the answer
+    assert!((mat_a.clone() * 1e6).is_singular(1e-10)?);
+    assert!(!matrix! {1e-8, 0.0; 0.0, 1e-8}.is_singular(1e-10)?);
+    // the pivots are compared with the largest entry of the whole matrix, so bad scaling looks singular
+    let badly_scaled = matrix! {1.0, 0.0; 0.0, 1e-12};
+    assert!(badly_scaled.is_singular(1e-10)?);
+    assert!(!badly_scaled.is_singular(1e-13)?);
+    assert!(!badly_scaled.equilibrate()?.0.is_singular(1e-10)?);
+    assert!(Matrix::<f64>::zero(2, 2)?.is_singular(0.0)?);
+    assert_eq!(
+        matrix! {1.0, 2.0}.is_singular(1e-10),
+        Err(DimensionError::NoSquare)
+    );
+    assert!(matrix! {1.0, f64::NAN; 0.0, 1.0}
+        .is_singular(1e-10)
+        .is_err());
+    assert!(mat_a.is_singular(-1.0).is_err());
+    Ok(())
+}
+
+#[test]
+fn static_lu_of_badly_scaled_matrix() {
+    // the pivots are compared with their own column, not with the largest entry of the matrix
+    let mat_a: SMatrix<f64, 4, 4> = smatrix! {
+        1e20, 0.0, 0.0, 0.0;
+        0.0, 1.0, 0.0, 0.0;
+        0.0, 0.0, 1.0, 0.0;
+        0.0, 0.0, 0.0, 1.0
+    };
+    assert!(mat_a.lupdecompose().is_some());
+    assert_eq!(mat_a.det(), 1e20);
+    assert_eq!(mat_a.inv().unwrap()[0][0], 1e-20);
+}
@@ -0,0 +1,36 @@
+use libmat::mat::{Matrix, SMatrix};
+use libmat::{matrix, smatrix};
+
+#[test]
+fn columns_are_aligned() {
+    let mat_a = matrix! {-12.5, 3.0, 0.0; 7.0, 1000.0, -1.0};
+    assert_eq!(mat_a.to_string(), "-12.5    3  0\n    7 1000 -1");
+    assert_eq!(
+        format!("{:.2}", mat_a),
+        "-12.50    3.00  0.00\n  7.00 1000.00 -1.00"
+    );
+    let column: Matrix<i32> = matrix! {1; -20; 300};
+    assert_eq!(column.to_string(), "  1\n-20\n300");
+}
+
+#[test]
+fn smatrix_prints_one_row_per_line() {
+    let mat_a: SMatrix<i32, 3, 3> = smatrix! {1, -2, 3; 40, 5, 6; 7, 8, 900};
+    assert_eq!(mat_a.to_string(), " 1 -2   3\n40  5   6\n 7  8 900");
+    let mat_b: SMatrix<f64, 2, 2> = smatrix! {1.0 / 3.0, 2.0; -0.5, 10.0};
+    assert_eq!(format!("{:.3}", mat_b), " 0.333  2.000\n-0.500 10.000");
+    assert_eq!(
+        mat_a.to_string(),
+        Matrix::from_vec(3, 3, mat_a.iter().flatten().copied().collect())
+            .unwrap()
+            .to_string()
+    );
+}
+
+#[test]
+fn flat_form() {
+    assert_eq!(matrix! {1, 2; 3, 4}.to_string_flat(), "[[1, 2], [3, 4]]");
+    assert_eq!(matrix! {-1.5}.to_string_flat(), "[[-1.5]]");
+    let mat_a: SMatrix<u8, 1, 3> = smatrix! {1, 2, 3};
+    assert_eq!(mat_a.to_string_flat(), "[[1, 2, 3]]");
+}
@@ -0,0 +1,140 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, Vector},
+    matrix,
+};
+
+fn assert_eigenpairs(mat_a: &Matrix<f64>, values: &Vector<f64>, vectors: &Matrix<f64>) {
+    let n = mat_a.rows();
+    for k in 0..n {
+        for i in 0..n {
+            let av: f64 = (0..n).map(|j| mat_a[i][j] * vectors[j][k]).sum();
+            assert!((av - values[k] * vectors[i][k]).abs() < 1e-10);
+        }
+    }
+    let vtv = (vectors.transpose() * vectors.clone()).unwrap();
+    for i in 0..n {
+        for j in 0..n {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((vtv[i][j] - expected).abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn eig_diagonal() -> Result<(), DimensionError> {
+    let mat_a = Matrix::diag_with(3, &[3.0, 1.0, 2.0])?;
+    let (values, vectors) = mat_a.eigen_sym()?;
+    assert_eq!(&values[..], &[1.0, 2.0, 3.0]);
+    assert_eigenpairs(&mat_a, &values, &vectors);
+    Ok(())
+}
+
+#[test]
+fn eig_two_by_two() -> Result<(), DimensionError> {
+    // rotation by 30 degrees of diag(1, 4)
+    let (c, s) = (30_f64.to_radians().cos(), 30_f64.to_radians().sin());
+    let rot = matrix! {c, -s; s, c};
+    let mat_a = ((rot.clone() * Matrix::diag_with(2, &[1.0, 4.0])?)? * rot.transpose())?;
+    let (values, vectors) = mat_a.eigen_sym()?;
+    assert!((values[0] - 1.0).abs() < 1e-12);
+    assert!((values[1] - 4.0).abs() < 1e-12);
+    assert!((vectors[0][0].abs() - c).abs() < 1e-12);
+    assert!((vectors[1][0].abs() - s).abs() < 1e-12);
+    assert_eigenpairs(&mat_a, &values, &vectors);
+    Ok(())
+}
+
+#[test]
+fn eig_larger() -> Result<(), DimensionError> {
+    let mat_b = Matrix::from_iter(5, 5, (0..25).map(|x| ((x * 7) % 11) as f64))?;
+    let mat_a = (mat_b.clone() + mat_b.transpose())?;
+    let (values, vectors) = mat_a.eigen_sym()?;
+    for i in 1..5 {
+        assert!(values[i - 1] <= values[i]);
+    }
+    assert_eigenpairs(&mat_a, &values, &vectors);
+    Ok(())
+}
+
+#[test]
+fn eig_not_square() -> Result<(), DimensionError> {
+    let mat_a = Matrix::new(2, 3, 1_i32)?;
+    assert_eq!(mat_a.eigen_sym(), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+#[test]
+fn eigen_sym_checks_symmetry() -> Result<(), DimensionError> {
+    let mat_a = matrix! {4.0, 1.0, -2.0; 1.0, 2.0, 0.0; -2.0, 0.0, 3.0};
+    assert!(mat_a.is_symmetric());
+    let (values, vectors) = mat_a.eigen_sym()?;
+    assert_eigenpairs(&mat_a, &values, &vectors);
+
+    let (values, _) = matrix! {2.0_f64, 1.0; 1.0, 2.0}.eigen_sym()?;
+    assert!((values[0] - 1.0).abs() < 1e-12);
+    assert!((values[1] - 3.0).abs() < 1e-12);
+
+    // symmetric up to rounding
+    assert!(matrix! {2.0_f64, 0.1 + 0.2; 0.3, 1.0}.eigen_sym().is_ok());
+    assert!(matches!(
+        matrix! {1.0, 2.0; 2.5, 1.0}.eigen_sym(),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert_eq!(
+        Matrix::new(2, 3, 1.0)?.eigen_sym(),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
+
+#[test]
+fn eigen_sym_with_tolerance() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f64> = matrix! {4.0, 1.0, -2.0; 1.0, 2.0, 0.0; -2.0, 0.0, 3.0};
+    let (exact, _) = mat_a.eigen_sym()?;
+    let (rough, _) = mat_a.eigen_sym_with(100, 1e-3)?;
+    for i in 0..3 {
+        assert!((exact[i] - rough[i]).abs() < 1e-2);
+    }
+    // no sweeps at all leaves the diagonal
+    let (values, vectors) = mat_a.eigen_sym_with(0, 1e-12)?;
+    assert_eq!(&values[..], &[2.0, 3.0, 4.0]);
+    assert_eq!(
+        vectors,
+        matrix! {0.0, 0.0, 1.0; 1.0, 0.0, 0.0; 0.0, 1.0, 0.0}
+    );
+    Ok(())
+}
+
+#[test]
+fn power_iteration() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f64> = matrix! {2.0, 1.0, 0.0; 1.0, 3.0, 1.0; 0.0, 1.0, 4.0};
+    let (lambda, v) = mat_a.power_iteration(10_000, 1e-12)?.unwrap();
+    let (values, _) = mat_a.eigen_sym()?;
+    assert!((lambda - values[2]).abs() < 1e-10);
+    assert!((v.norm() - 1.0).abs() < 1e-12);
+    let av = (mat_a * Matrix::from(v.clone()))?;
+    for i in 0..3 {
+        assert!((av[i][0] - lambda * v[i]).abs() < 1e-10);
+    }
+
+    // non-symmetric with a negative dominant eigenvalue
+    let (lambda, _) = matrix! {-5.0, 1.0; 0.0, 2.0}
+        .power_iteration(10_000, 1e-12)?
+        .unwrap();
+    assert!((lambda + 5.0).abs() < 1e-10);
+
+    // rotation: two eigenvalues of the same absolute value, no convergence
+    assert_eq!(
+        matrix! {0.0, -1.0; 1.0, 0.0}.power_iteration(100, 1e-12)?,
+        None
+    );
+    assert_eq!(
+        matrix! {0.0, 0.0; 0.0, 0.0}
+            .power_iteration(10, 1e-12)?
+            .unwrap()
+            .0,
+        0.0
+    );
+    Ok(())
+}
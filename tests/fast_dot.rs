@@ -0,0 +1,40 @@
+use libmat::err::DimensionError;
+use libmat::mat::Vector;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn random_vector(n: usize, rng: &mut StdRng) -> Vector<f64> {
+    (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect()
+}
+
+#[test]
+fn dot_fast_matches_scalar_dot() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(5);
+    for &n in [1, 3, 7, 8, 9, 16, 31, 100, 1027].iter() {
+        let (vec_a, vec_b) = (random_vector(n, &mut rng), random_vector(n, &mut rng));
+        let fast = vec_a.dot_fast(&vec_b)?;
+        let scalar = (vec_a.clone() * vec_b.clone())?;
+        let scale: f64 = vec_a
+            .iter()
+            .zip(vec_b.iter())
+            .map(|(x, y)| (x * y).abs())
+            .sum();
+        assert!(
+            (fast - scalar).abs() <= 1e-14 * scale,
+            "n = {}: {} vs {}",
+            n,
+            fast,
+            scalar
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn dot_fast_checks_lengths() {
+    let vec_a = Vector::new(9, 1.0);
+    assert!(matches!(
+        vec_a.dot_fast(&Vector::new(8, 1.0)),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    assert_eq!(vec_a.dot_fast(&vec_a.to_row_vector()), Ok(9.0));
+}
@@ -0,0 +1,65 @@
+use libmat::mat::{Matrix, SMatrix, Vector};
+use libmat::{matrix, vector};
+
+fn fixture() -> Matrix<f64> {
+    matrix! {1.0, -2.5, 0.0; 3.25, 1e-3, -7.0}
+}
+
+#[test]
+fn fixture_hashes_are_pinned() {
+    assert_eq!(fixture().content_hash(), 0x308d_3638_b1bc_8481);
+    assert_eq!(matrix! {1, 2; 3, 4}.content_hash(), 0xc687_9e1c_5764_bea1);
+}
+
+#[test]
+fn equal_contents_hash_equal() {
+    assert_eq!(fixture().content_hash(), fixture().content_hash());
+    assert_eq!(
+        fixture().content_hash(),
+        fixture().with_label("fixture").content_hash()
+    );
+    let smat: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, -2.5, 0.0], [3.25, 1e-3, -7.0]]);
+    assert_eq!(smat.content_hash(), fixture().content_hash());
+    let flat: Vector<f64> = vector![1.0, -2.5, 0.0, 3.25, 1e-3, -7.0];
+    assert_eq!(
+        flat.to_row_vector()
+            .into_matrix(2, 3)
+            .unwrap()
+            .content_hash(),
+        fixture().content_hash()
+    );
+}
+
+#[test]
+fn changes_flip_the_hash() {
+    let original = fixture().content_hash();
+    let mut changed = fixture();
+    changed[1][1] = 2e-3;
+    assert_ne!(changed.content_hash(), original);
+    changed[1][1] = 1e-3;
+    assert_eq!(changed.content_hash(), original);
+
+    let reshaped = Matrix::from_vec(3, 2, vec![1.0, -2.5, 0.0, 3.25, 1e-3, -7.0]).unwrap();
+    assert_ne!(reshaped.content_hash(), original);
+    assert_ne!(
+        vector![1, 2, 3].content_hash(),
+        vector![1, 2, 3].to_row_vector().content_hash()
+    );
+    assert_ne!(
+        matrix! {1_i32, 2}.content_hash(),
+        matrix! {1_i64, 2}.content_hash()
+    );
+}
+
+#[test]
+fn signed_zeros_and_nans_are_canonical() {
+    let mut negative_zero = fixture();
+    negative_zero[0][2] = -0.0;
+    assert_eq!(negative_zero, fixture());
+    assert_eq!(negative_zero.content_hash(), fixture().content_hash());
+
+    let nan_a = matrix! {f64::NAN, 1.0};
+    let nan_b = matrix! {-f64::NAN, 1.0};
+    assert_eq!(nan_a.content_hash(), nan_b.content_hash());
+    assert_ne!(nan_a.content_hash(), matrix! {0.0, 1.0}.content_hash());
+}
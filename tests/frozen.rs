@@ -0,0 +1,55 @@
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+use num_traits::Inv;
+
+#[test]
+fn cached_results_match() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2.0, 1.0; 7.0, 4.0};
+    let frozen = mat_a.clone().freeze();
+    assert_eq!(frozen.det()?, mat_a.det()?);
+    assert_eq!(frozen.inverse()?.cloned(), mat_a.clone().inv()?);
+    assert_eq!(frozen.lu()?.cloned(), mat_a.lupdecompose()?);
+    assert_eq!(frozen.rank(), 2);
+    assert!(std::ptr::eq(
+        frozen.inverse()?.unwrap(),
+        frozen.inverse()?.unwrap()
+    ));
+    assert_eq!(frozen.unfreeze(), mat_a);
+    Ok(())
+}
+
+#[test]
+fn singular_and_non_square() -> Result<(), DimensionError> {
+    // a float matrix, because the LUP decomposition of integer matrices truncates divisions
+    let frozen = matrix! {1.0, 2.0; 2.0, 4.0}.freeze();
+    assert_eq!(frozen.det()?, 0.0);
+    assert_eq!(frozen.rank(), 1);
+    assert_eq!(frozen.lu()?, None);
+    let frozen = Matrix::new(2, 3, 1.0)?.freeze();
+    assert_eq!(frozen.det(), Err(DimensionError::NoSquare));
+    assert_eq!(frozen.inverse(), Err(DimensionError::NoSquare));
+    assert_eq!(frozen.rank(), 1);
+    Ok(())
+}
+
+#[test]
+fn rank_of_larger_integer_and_nearly_singular_float_matrices() -> Result<(), DimensionError> {
+    // without dividing by the previous pivot, the entries of the elimination overflow i64
+    let entries: Vec<i64> = (0..64).map(|i| (i * 37 + i * i * 5) % 23 - 11).collect();
+    let mat_a = Matrix::from_vec(8, 8, entries.clone())?;
+    assert_eq!(mat_a.rank(), 8);
+    assert_ne!(mat_a.det_bareiss()?, 0);
+    assert_eq!(mat_a.freeze().rank(), 8);
+    let mut dependent = entries;
+    for c in 0..8 {
+        dependent[56 + c] = dependent[c] - 2 * dependent[8 + c];
+    }
+    assert_eq!(Matrix::from_vec(8, 8, dependent)?.rank(), 7);
+
+    // singular, but the elimination leaves a pivot of the order of the rounding errors
+    let singular = matrix! {0.1, 0.2, 0.3; 0.4, 0.5, 0.6; 0.7, 0.8, 0.9};
+    assert_eq!(singular.rank(), 2);
+    assert_eq!(singular.freeze().rank(), 2);
+    // badly scaled columns don't lose rank
+    assert_eq!(matrix! {1e-20, 1.0; 0.0, 1e20}.rank(), 2);
+    Ok(())
+}
@@ -1,4 +1,8 @@
-use libmat::{err::DimensionError, mat::Matrix};
+use libmat::{
+    err::{DimensionError, MatrixError},
+    mat::{dims::Dimensions, Matrix, SMatrix, Vector},
+    matrix, vector,
+};
 #[test]
 fn one_idx() -> Result<(), DimensionError> {
     let a = Matrix::<u32>::one(3)?;
@@ -16,3 +20,168 @@ fn double_idx() -> Result<(), DimensionError> {
     assert_eq!(a[1][1], 1);
     Ok(())
 }
+
+#[test]
+fn take_rows_until_one_remains() -> Result<(), MatrixError> {
+    let mut mat_a = matrix! {1, 2; 3, 4; 5, 6; 7, 8};
+    // the last row moves into the gap
+    assert_eq!(mat_a.take_row(1)?, vector![3, 4].to_row_vector());
+    assert_eq!(mat_a, matrix! {1, 2; 7, 8; 5, 6});
+    assert_eq!(mat_a.dims(), Dimensions::new(3, 2));
+    // taking the last row moves nothing
+    assert_eq!(mat_a.take_row(2)?, vector![5, 6].to_row_vector());
+    assert_eq!(mat_a, matrix! {1, 2; 7, 8});
+    assert_eq!(mat_a.take_row(0)?, vector![1, 2].to_row_vector());
+    assert_eq!(mat_a, matrix! {7, 8});
+    assert_eq!(mat_a.dims(), Dimensions::new(1, 2));
+
+    assert_eq!(mat_a.take_row(0), Err(MatrixError::WouldBeEmpty));
+    assert_eq!(mat_a.take_row(1), Err(MatrixError::IndexOutOfBounds(1)));
+    assert_eq!(mat_a, matrix! {7, 8});
+    Ok(())
+}
+
+#[test]
+fn remove_rows_in_order() -> Result<(), MatrixError> {
+    let mut mat_a = matrix! {1, 2; 3, 4; 5, 6; 7, 8};
+    assert_eq!(mat_a.remove_row_ordered(1)?, vector![3, 4].to_row_vector());
+    assert_eq!(mat_a, matrix! {1, 2; 5, 6; 7, 8});
+    assert_eq!(mat_a.remove_row_ordered(0)?, vector![1, 2].to_row_vector());
+    assert_eq!(mat_a, matrix! {5, 6; 7, 8});
+    assert_eq!(mat_a.dims(), Dimensions::new(2, 2));
+    assert_eq!(
+        mat_a.remove_row_ordered(2),
+        Err(MatrixError::IndexOutOfBounds(2))
+    );
+    mat_a.remove_row_ordered(1)?;
+    assert_eq!(mat_a.remove_row_ordered(0), Err(MatrixError::WouldBeEmpty));
+    assert_eq!(mat_a, matrix! {5, 6});
+
+    let mut single_col = matrix! {1; 2; 3};
+    let taken: Vector<i32> = single_col.take_row(0)?;
+    assert_eq!(taken.size(), 1);
+    assert_eq!(single_col, matrix! {3; 2});
+    Ok(())
+}
+
+#[test]
+fn iterate_rows_and_columns() {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    for (i, row) in mat_a.row_iter().enumerate() {
+        assert_eq!(row, &mat_a[i]);
+    }
+    assert_eq!(mat_a.row_iter().count(), 2);
+    let cols: Vec<Vec<i32>> = mat_a.col_iter().collect();
+    assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    assert!(mat_a
+        .col_iter()
+        .zip(mat_a.transpose().row_iter())
+        .all(|(col, row)| col == row));
+
+    let single_col = matrix! {7; 8; 9};
+    assert_eq!(
+        single_col.col_iter().collect::<Vec<_>>(),
+        vec![vec![7, 8, 9]]
+    );
+    assert_eq!(single_col.row_iter().map(|row| row.len()).sum::<usize>(), 3);
+}
+
+#[test]
+fn select_and_drop_columns() -> Result<(), MatrixError> {
+    let mat_a = matrix! {1, 2, 3, 4; 5, 6, 7, 8; 9, 10, 11, 12};
+    assert_eq!(
+        mat_a.select_cols(&[2, 0, 0])?,
+        matrix! {3, 1, 1; 7, 5, 5; 11, 9, 9}
+    );
+    let all: Vec<usize> = (0..mat_a.cols()).collect();
+    assert_eq!(mat_a.select_cols(&all)?, mat_a);
+    assert_eq!(
+        mat_a.select_cols(&[0, 4]),
+        Err(MatrixError::IndexOutOfBounds(4))
+    );
+
+    assert_eq!(
+        mat_a.select_rows(&[2, 1])?,
+        matrix! {9, 10, 11, 12; 5, 6, 7, 8}
+    );
+    assert_eq!(
+        mat_a.select_rows(&[3]),
+        Err(MatrixError::IndexOutOfBounds(3))
+    );
+    assert_eq!(mat_a.select_rows(&[]), Err(MatrixError::WouldBeEmpty));
+
+    assert_eq!(mat_a.drop_cols(&[3, 1, 3])?, mat_a.select_cols(&[0, 2])?);
+    assert_eq!(mat_a.drop_cols(&[])?, mat_a);
+    assert_eq!(mat_a.drop_cols(&[9]), Err(MatrixError::IndexOutOfBounds(9)));
+    assert_eq!(mat_a.drop_cols(&all), Err(MatrixError::WouldBeEmpty));
+    Ok(())
+}
+
+#[test]
+fn destructure_shapes() {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    let (r, c) = mat_a.shape();
+    assert_eq!((r, c), (mat_a.rows(), mat_a.cols()));
+    assert_eq!(mat_a.dims().as_tuple(), (2, 3));
+    assert_eq!(Dimensions::new(4, 1).as_tuple(), (4, 1));
+
+    let (r, c) = SMatrix::from_rows([[1.0; 4]; 2]).shape();
+    assert_eq!((r, c), (2, 4));
+
+    let vec_a = vector![1, 2, 3];
+    assert_eq!(vec_a.shape(), (3, 1));
+    let (r, c) = vec_a.to_row_vector().shape();
+    assert_eq!((r, c), (1, 3));
+}
+
+#[test]
+fn split_and_stack() -> Result<(), DimensionError> {
+    let mat_a = Matrix::from_iter(10, 3, 0..)?;
+    for mid in 1..10 {
+        let (top, bottom) = mat_a.split_rows_at(mid)?;
+        assert_eq!(top.dims(), Dimensions::new(mid, 3));
+        assert_eq!(top.vstack(&bottom)?, mat_a);
+    }
+    for mid in 1..3 {
+        let (left, right) = mat_a.split_cols_at(mid)?;
+        assert_eq!(left.hstack(&right)?, mat_a);
+    }
+    assert_eq!(
+        mat_a.split_rows_at(0),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        mat_a.split_rows_at(10),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        mat_a.split_cols_at(0),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        mat_a.split_cols_at(3),
+        Err(DimensionError::InvalidDimensions)
+    );
+
+    let sizes: Vec<usize> = mat_a.chunks_rows(3).map(|block| block.rows()).collect();
+    assert_eq!(sizes, vec![3, 3, 3, 1]);
+    let restacked = mat_a
+        .chunks_rows(3)
+        .reduce(|acc, block| acc.vstack(&block).unwrap())
+        .unwrap();
+    assert_eq!(restacked, mat_a);
+    assert_eq!(mat_a.chunks_rows(10).count(), 1);
+    assert_eq!(mat_a.chunks_rows(usize::MAX).next(), Some(mat_a.clone()));
+
+    let wide = matrix! {1, 2, 3, 4};
+    assert_eq!(
+        mat_a.vstack(&wide),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            wide.dims(),
+            "vertically stack".to_owned()
+        ))
+    );
+    assert!(mat_a.hstack(&wide).is_err());
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, Vector},
+    matrix, vector,
+};
+
+fn assert_smith_form(mat_a: &Matrix<i64>) -> Result<Matrix<i64>, DimensionError> {
+    let (u, d, v) = mat_a.smith_normal_form()?;
+    assert_eq!(((u.clone() * mat_a.clone())? * v.clone())?, d);
+    assert_eq!(u.det_bareiss()?.abs(), 1);
+    assert_eq!(v.det_bareiss()?.abs(), 1);
+    for i in 0..d.rows() {
+        for j in 0..d.cols() {
+            if i != j {
+                assert_eq!(d[i][j], 0, "D is not diagonal: {}", d);
+            }
+        }
+    }
+    let diag: Vec<i64> = (0..d.rows().min(d.cols())).map(|i| d[i][i]).collect();
+    for pair in diag.windows(2) {
+        assert!(pair[0] >= 0);
+        if pair[0] == 0 {
+            assert_eq!(pair[1], 0);
+        } else {
+            assert_eq!(pair[1] % pair[0], 0, "{:?} is no divisibility chain", diag);
+        }
+    }
+    Ok(d)
+}
+
+#[test]
+fn smith_normal_form_known_examples() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2, 4, 4; -6, 6, 12; 10, -4, -16};
+    assert_eq!(
+        assert_smith_form(&mat_a)?,
+        matrix! {2, 0, 0; 0, 6, 0; 0, 0, 12}
+    );
+
+    // boundary map from the edges to the vertices of a triangle, whose first homology is Z
+    let boundary = matrix! {-1, 0, -1; 1, -1, 0; 0, 1, 1};
+    assert_eq!(
+        assert_smith_form(&boundary)?,
+        matrix! {1, 0, 0; 0, 1, 0; 0, 0, 0}
+    );
+
+    // the torsion Z/2 shows up as a diagonal entry 2
+    let mat_b = matrix! {2, 0; 0, 1};
+    assert_eq!(assert_smith_form(&mat_b)?, matrix! {1, 0; 0, 2});
+    Ok(())
+}
+
+#[test]
+fn smith_normal_form_rectangular() -> Result<(), DimensionError> {
+    let wide = matrix! {4, 6, 10, -2; 8, 3, 5, 7; 12, 9, 15, 5};
+    assert_smith_form(&wide)?;
+    assert_smith_form(&wide.transpose())?;
+    let zero = Matrix::<i64>::zero(2, 3)?;
+    assert_eq!(assert_smith_form(&zero)?, zero);
+    assert_smith_form(&matrix! {-7})?;
+    Ok(())
+}
+
+#[allow(clippy::unnecessary_map_or)]
+fn assert_hermite_form(mat_a: &Matrix<i64>) -> Result<Matrix<i64>, DimensionError> {
+    let (h, u) = mat_a.hermite_normal_form()?;
+    assert_eq!((u.clone() * mat_a.clone())?, h);
+    assert_eq!(u.det_bareiss()?.abs(), 1);
+    let mut last_pivot = None;
+    for i in 0..h.rows() {
+        match (0..h.cols()).find(|&j| h[i][j] != 0) {
+            Some(p) => {
+                assert!(
+                    last_pivot.map_or(true, |l| l < p),
+                    "not in echelon form: {}",
+                    h
+                );
+                assert!(h[i][p] > 0);
+                for k in 0..i {
+                    assert!(0 <= h[k][p] && h[k][p] < h[i][p], "not reduced: {}", h);
+                }
+                last_pivot = Some(p);
+            }
+            None => last_pivot = Some(h.cols()),
+        }
+    }
+    Ok(h)
+}
+
+#[test]
+fn hermite_normal_form_known_example() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2, 3, 6, 2; 5, 6, 1, 6; 8, 3, 1, 1};
+    assert_eq!(
+        assert_hermite_form(&mat_a)?,
+        matrix! {1, 0, 50, -11; 0, 3, 28, -2; 0, 0, 61, -13}
+    );
+    Ok(())
+}
+
+#[test]
+fn hermite_normal_form_rank_deficient() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2, 4, 6; 1, 2, 3; -3, 1, 0; 0, 7, 9};
+    let h = assert_hermite_form(&mat_a)?;
+    assert_eq!(h[3], [0, 0, 0]);
+    assert_hermite_form(&mat_a.transpose())?;
+    assert_hermite_form(&matrix! {0, -4; 0, 6})?;
+    Ok(())
+}
+
+#[test]
+fn solve_integer_systems() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2, 3, 6, 2; 5, 6, 1, 6; 8, 3, 1, 1};
+    let b = vector![1, 2, 3];
+    let x = mat_a.solve_integer(&b)?.unwrap();
+    assert_eq!((mat_a.clone() * x)?, b);
+
+    // square system whose rational solution (1/2, 1/2) is not an integer
+    let mat_b = matrix! {1, 1; 1, -1};
+    assert_eq!(mat_b.solve_integer(&vector![1, 0])?, None);
+    assert_eq!(mat_b.solve_integer(&vector![4, 2])?, Some(vector![3, 1]));
+
+    // inconsistent overdetermined system
+    let mat_c = matrix! {1, 0; 0, 1; 1, 1};
+    assert_eq!(mat_c.solve_integer(&vector![1, 1, 3])?, None);
+    assert_eq!(mat_c.solve_integer(&vector![1, 1, 2])?, Some(vector![1, 1]));
+
+    assert!(matches!(
+        mat_c.solve_integer(&Vector::from(vec![1, 2])),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    Ok(())
+}
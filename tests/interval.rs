@@ -0,0 +1,228 @@
+use libmat::mat::{Matrix, PivotStrategy, Vector};
+use libmat::matrix;
+use num_traits::{One, Zero};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The smallest `f64` greater than `x`, like `f64::next_up`, which needs a newer Rust.
+fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// Minimal interval type with outward rounding, enough to get verified enclosures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl Interval {
+    fn point(x: f64) -> Interval {
+        Interval { lo: x, hi: x }
+    }
+
+    fn outward(lo: f64, hi: f64) -> Interval {
+        Interval {
+            lo: -next_up(-lo),
+            hi: next_up(hi),
+        }
+    }
+
+    fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, rhs: Interval) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, rhs: Interval) -> Interval {
+        let products = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        let lo = products.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+impl MulAssign for Interval {
+    fn mul_assign(&mut self, rhs: Interval) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+    fn div(self, rhs: Interval) -> Interval {
+        assert!(
+            !rhs.contains(0.0),
+            "division by an interval containing zero"
+        );
+        self * Interval::outward(1.0 / rhs.hi, 1.0 / rhs.lo)
+    }
+}
+
+impl Zero for Interval {
+    fn zero() -> Interval {
+        Interval::point(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+impl One for Interval {
+    fn one() -> Interval {
+        Interval::point(1.0)
+    }
+}
+
+impl Sum for Interval {
+    fn sum<I: Iterator<Item = Interval>>(iter: I) -> Interval {
+        iter.fold(Interval::zero(), |acc, x| acc + x)
+    }
+}
+
+fn to_intervals(mat: &Matrix<f64>) -> Matrix<Interval> {
+    Matrix::from_iter(
+        mat.rows(),
+        mat.cols(),
+        (0..mat.rows()).flat_map(|i| (0..mat.cols()).map(move |j| Interval::point(mat[i][j]))),
+    )
+    .unwrap()
+}
+
+#[test]
+fn interval_det_encloses_exact_value() {
+    let mat = to_intervals(&matrix! {1.0, 2.0, 3.0; 3.0, 2.0, 1.0; 2.0, 1.0, 3.0});
+    let det = mat.det_with(PivotStrategy::FirstNonZero).unwrap();
+    assert!(det.contains(-12.0), "{:?}", det);
+    assert!(det.width() < 1e-12, "{:?}", det);
+}
+
+#[test]
+fn interval_det_encloses_inexact_inputs() {
+    // 0.1 is not representable, so every entry is an interval enclosing the real decimal value.
+    let tenth = Interval::outward(0.1, 0.1);
+    let mat = to_intervals(&matrix! {1.0, 2.0, 3.0; 3.0, 2.0, 1.0; 2.0, 1.0, 3.0}) * tenth;
+    let det = mat.det_with(PivotStrategy::FirstNonZero).unwrap();
+    assert!(det.contains(-0.012), "{:?}", det);
+    assert!(det.width() < 1e-12, "{:?}", det);
+}
+
+#[test]
+fn interval_det_with_zero_leading_entry() {
+    let mat = to_intervals(&matrix! {0.0, 1.0, 2.0; 1.0, 0.0, 3.0; 4.0, -3.0, 8.0});
+    let det = mat.det_with(PivotStrategy::FirstNonZero).unwrap();
+    assert!(det.contains(-2.0), "{:?}", det);
+}
+
+#[test]
+fn interval_det_of_singular_matrix() {
+    let mat = to_intervals(&matrix! {0.0, 1.0; 0.0, 2.0});
+    assert_eq!(
+        mat.det_with(PivotStrategy::FirstNonZero).unwrap(),
+        Interval::zero()
+    );
+
+    // Rounding makes the eliminated row a tiny enclosure of zero instead of exactly zero.
+    let mat = to_intervals(&matrix! {1.0, 2.0; 2.0, 4.0});
+    let det = mat.det_with(PivotStrategy::FirstNonZero).unwrap();
+    assert!(det.contains(0.0), "{:?}", det);
+    assert!(det.width() < 1e-12, "{:?}", det);
+}
+
+#[test]
+fn interval_arithmetic() {
+    let mat_a = to_intervals(&matrix! {1.0, 2.0; 3.0, 4.0});
+    let mat_b = to_intervals(&matrix! {0.5, -1.0; 2.0, 0.0});
+    let product = (mat_a.clone() * mat_b.clone()).unwrap();
+    let expected = matrix! {4.5, -1.0; 9.5, -3.0};
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!(product[i][j].contains(expected[i][j]));
+        }
+    }
+    let sum = (mat_a.clone() + mat_b).unwrap();
+    assert!(sum[1][0].contains(5.0));
+    assert!(mat_a.transpose()[0][1].contains(3.0));
+    assert!(mat_a.trace().unwrap().contains(5.0));
+}
+
+#[test]
+fn pivot_strategies_agree_on_floats() {
+    let mat = matrix! {2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0};
+    let first = mat.det_with(PivotStrategy::FirstNonZero).unwrap();
+    let largest = mat
+        .det_with(PivotStrategy::Largest(|x: &f64| x.abs()))
+        .unwrap();
+    assert!((first - -16.0).abs() < 1e-12);
+    assert!((largest - -16.0).abs() < 1e-12);
+
+    let (lu, p) = mat
+        .lupdecompose_with(PivotStrategy::Largest(|x: &f64| x.abs()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(p[0], 1);
+    assert_eq!(lu[0][0], 4.0);
+}
+
+#[test]
+fn vector_times_matrix_without_display() {
+    // Interval doesn't implement Display, which the product doesn't need
+    let mat = to_intervals(&matrix! {1.0, 2.0; 3.0, 4.0});
+    let vec = Vector::from(vec![Interval::point(1.0), Interval::point(-1.0)]).to_row_vector();
+    let res = (vec * mat).unwrap();
+    assert!(res[0].contains(-2.0) && res[1].contains(-2.0), "{:?}", res);
+}
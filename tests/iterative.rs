@@ -0,0 +1,166 @@
+use libmat::err::DimensionError;
+use libmat::mat::dims::Dimensions;
+use libmat::mat::precond::{Ilu0Preconditioner, JacobiPreconditioner, Preconditioner};
+use libmat::mat::{Matrix, Vector};
+use libmat::{matrix, vector};
+
+/// Tridiagonal matrix of the 1-D Laplacian, `2` on the diagonal and `-1` next to it.
+fn laplacian(n: usize) -> Matrix<f64> {
+    let mut mat = Matrix::zero(n, n).unwrap();
+    for i in 0..n {
+        mat[i][i] = 2.0;
+        if i + 1 < n {
+            mat[i][i + 1] = -1.0;
+            mat[i + 1][i] = -1.0;
+        }
+    }
+    mat
+}
+
+fn assert_solves(mat: &Matrix<f64>, x: &Vector<f64>, b: &Vector<f64>, eps: f64) {
+    let ax = (mat.clone() * Matrix::from(x.clone())).unwrap();
+    for i in 0..b.size() {
+        assert!((ax[i][0] - b[i]).abs() < eps, "{} vs {}", ax[i][0], b[i]);
+    }
+}
+
+#[test]
+fn cg_solves_laplacian() -> Result<(), DimensionError> {
+    let n = 50;
+    let mat = laplacian(n);
+    let b: Vector<f64> = (0..n).map(|i| (i as f64 * 0.3).sin()).collect();
+    let result = mat.conjugate_gradient(&b, 1e-10, 1000)?;
+    assert!(result.converged);
+    assert!(result.iterations <= n);
+    assert!(result.residual_norm <= 1e-10 * b.norm());
+    assert_solves(&mat, &result.solution, &b, 1e-8);
+    Ok(())
+}
+
+#[test]
+fn preconditioning_needs_fewer_iterations() -> Result<(), DimensionError> {
+    let n = 100;
+    let mat = laplacian(n);
+    let b = Vector::new(n, 1.0);
+    let plain = mat.conjugate_gradient(&b, 1e-10, 1000)?;
+    let precond = Ilu0Preconditioner::from_matrix(&mat)?;
+    let ilu = mat.conjugate_gradient_preconditioned(&b, &precond, 1e-10, 1000)?;
+    assert!(plain.converged && ilu.converged);
+    assert!(ilu.iterations * 10 < plain.iterations);
+    assert_solves(&mat, &ilu.solution, &b, 1e-7);
+
+    // badly scaled diagonal, where the Jacobi preconditioner helps
+    let mut scaled = laplacian(n);
+    for i in 0..n {
+        scaled[i][i] += (i * i) as f64;
+    }
+    let plain = scaled.conjugate_gradient(&b, 1e-10, 1000)?;
+    let precond = JacobiPreconditioner::from_matrix(&scaled)?;
+    let jacobi = scaled.conjugate_gradient_preconditioned(&b, &precond, 1e-10, 1000)?;
+    assert!(plain.converged && jacobi.converged);
+    assert!(jacobi.iterations < plain.iterations);
+    assert_solves(&scaled, &jacobi.solution, &b, 1e-7);
+    Ok(())
+}
+
+/// Scales the residual by a constant, which doesn't change the CG iterates.
+struct Scaled(f64);
+
+impl Preconditioner for Scaled {
+    fn apply(&self, r: &Vector<f64>) -> Result<Vector<f64>, DimensionError> {
+        Ok(r.iter().map(|x| x * self.0).collect())
+    }
+}
+
+#[test]
+fn user_defined_preconditioner() -> Result<(), DimensionError> {
+    let mat = laplacian(10);
+    let b = Vector::new(10, 1.0);
+    let plain = mat.conjugate_gradient(&b, 1e-12, 100)?;
+    let scaled = mat.conjugate_gradient_preconditioned(&b, &Scaled(0.5), 1e-12, 100)?;
+    assert_eq!(plain.iterations, scaled.iterations);
+    assert_solves(&mat, &scaled.solution, &b, 1e-10);
+    Ok(())
+}
+
+#[test]
+fn iteration_limit() -> Result<(), DimensionError> {
+    let mat = laplacian(30);
+    let b = Vector::new(30, 1.0);
+    let result = mat.conjugate_gradient(&b, 1e-12, 3)?;
+    assert_eq!(result.iterations, 3);
+    assert!(!result.converged);
+    Ok(())
+}
+
+#[test]
+fn ilu0_keeps_sparsity_pattern() -> Result<(), DimensionError> {
+    // for a tridiagonal matrix ILU(0) is exact
+    let mat = laplacian(5);
+    let precond = Ilu0Preconditioner::from_matrix(&mat)?;
+    let b = vector![1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_solves(&mat, &precond.apply(&b)?, &b, 1e-12);
+
+    // the zeros of this matrix would be filled in by a full LU, so ILU(0) is only approximate
+    let mat = matrix! {4.0, 1.0, 1.0; 1.0, 4.0, 0.0; 1.0, 0.0, 4.0};
+    let b = vector![1.0, 1.0, 1.0];
+    let x = Matrix::from(Ilu0Preconditioner::from_matrix(&mat)?.apply(&b)?);
+    let ax = (mat * x)?;
+    assert!((0..3).any(|i| (ax[i][0] - b[i]).abs() > 1e-3));
+    Ok(())
+}
+
+#[test]
+fn preconditioner_errors() -> Result<(), DimensionError> {
+    assert!(matches!(
+        JacobiPreconditioner::from_matrix(&matrix! {1.0, 0.0; 0.0, 0.0}),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        Ilu0Preconditioner::from_matrix(&matrix! {0.0, 1.0; 1.0, 0.0}),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert_eq!(
+        JacobiPreconditioner::from_matrix(&Matrix::new(2, 3, 1.0)?),
+        Err(DimensionError::NoSquare)
+    );
+    assert!(matches!(
+        laplacian(3).conjugate_gradient(&vector![1.0, 2.0], 1e-10, 10),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    Ok(())
+}
+
+#[test]
+fn jacobi_of_wrong_size() -> Result<(), DimensionError> {
+    let precond = JacobiPreconditioner::from_matrix(&laplacian(3))?;
+    assert_eq!(
+        precond.apply(&vector![1.0, 2.0]),
+        Err(DimensionError::NoMatch(
+            Dimensions::new(3, 3),
+            Dimensions::new(2, 1),
+            "multiply".to_owned()
+        ))
+    );
+    let result =
+        laplacian(4).conjugate_gradient_preconditioned(&Vector::new(4, 1.0), &precond, 1e-10, 10);
+    assert!(matches!(result, Err(DimensionError::NoMatch(..))));
+    Ok(())
+}
+
+#[test]
+fn ilu0_of_wrong_size() -> Result<(), DimensionError> {
+    let precond = Ilu0Preconditioner::from_matrix(&laplacian(3))?;
+    assert_eq!(
+        precond.apply(&vector![1.0, 2.0, 3.0, 4.0]),
+        Err(DimensionError::NoMatch(
+            Dimensions::new(3, 3),
+            Dimensions::new(4, 1),
+            "multiply".to_owned()
+        ))
+    );
+    let result =
+        laplacian(2).conjugate_gradient_preconditioned(&Vector::new(2, 1.0), &precond, 1e-10, 10);
+    assert!(matches!(result, Err(DimensionError::NoMatch(..))));
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, Vector},
+    matrix, vector,
+};
+
+#[test]
+fn from_iter() -> Result<(), DimensionError> {
+    let mat_a = Matrix::from_iter(3, 2, 1..)?;
+    assert_eq!(mat_a, matrix! {1, 2; 3, 4; 5, 6});
+    Ok(())
+}
+
+#[test]
+fn from_iter_too_short() {
+    assert_eq!(
+        Matrix::from_iter(3, 3, vec![1, 2, 3, 4]),
+        Err(DimensionError::InvalidInputDimensions(4, 9))
+    );
+    assert_eq!(
+        Matrix::from_iter(0, 3, 1..),
+        Err(DimensionError::InvalidDimensions)
+    );
+    // the entry count overflows, which must not wrap into a huge but finite count
+    assert_eq!(
+        Matrix::from_iter(usize::MAX, 2, 1..),
+        Err(DimensionError::InvalidDimensions)
+    );
+}
+
+#[test]
+fn from_rows() -> Result<(), DimensionError> {
+    let mat_a = Matrix::from_rows((0..3).map(|i| (0..3).map(move |j| i * 3 + j)))?;
+    assert_eq!(mat_a, matrix! {0, 1, 2; 3, 4, 5; 6, 7, 8});
+    Ok(())
+}
+
+#[test]
+fn from_rows_ragged() {
+    assert_eq!(
+        Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]),
+        Err(DimensionError::InvalidInputDimensions(2, 3))
+    );
+    assert_eq!(
+        Matrix::from_rows(vec![vec![1, 2], vec![3, 4, 5]]),
+        Err(DimensionError::InvalidInputDimensions(3, 2))
+    );
+    assert_eq!(
+        Matrix::<i32>::from_rows(Vec::<Vec<i32>>::new()),
+        Err(DimensionError::InvalidDimensions)
+    );
+}
+
+#[test]
+fn collect_vector() {
+    let vec_a: Vector<i32> = (1..=3).collect();
+    assert_eq!(vec_a, vector![1, 2, 3]);
+    assert!(vec_a.is_col_vector());
+}
+
+#[test]
+fn into_iter_is_row_major() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    let borrowed: Vec<&i32> = (&mat_a).into_iter().collect();
+    assert_eq!(borrowed, vec![&1, &2, &3, &4, &5, &6]);
+    assert_eq!((&mat_a).into_iter().filter(|&&x| x % 2 == 0).count(), 3);
+
+    let squared = Matrix::from_iter_with_dims(2, 3, mat_a.clone().into_iter().map(|x| x * x))?;
+    assert_eq!(squared, matrix! {1, 4, 9; 16, 25, 36});
+    let transposed = Matrix::from_iter_with_dims(3, 2, mat_a.transpose())?;
+    assert_eq!(transposed, matrix! {1, 4; 2, 5; 3, 6});
+    Ok(())
+}
+
+#[test]
+fn from_iter_with_dims_checks_the_count() {
+    assert_eq!(
+        Matrix::from_iter_with_dims(2, 3, 0..5),
+        Err(DimensionError::InvalidInputDimensions(5, 6))
+    );
+    assert_eq!(
+        Matrix::from_iter_with_dims(2, 3, 0..7),
+        Err(DimensionError::InvalidInputDimensions(7, 6))
+    );
+    assert_eq!(
+        Matrix::from_iter_with_dims(0, 3, Vec::<i32>::new()),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        Matrix::from_iter_with_dims(usize::MAX, 2, 0..3),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        Matrix::from_iter(2, 3, 0..7),
+        Matrix::from_iter_with_dims(2, 3, 0..6)
+    );
+}
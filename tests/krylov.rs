@@ -0,0 +1,100 @@
+use libmat::err::DimensionError;
+use libmat::mat::dims::Dimensions;
+use libmat::mat::{Matrix, Vector};
+use libmat::{matrix, vector};
+
+fn pseudo_random(n: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed;
+    (0..n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 11) as f64 / (1_u64 << 53) as f64) * 2.0 - 1.0
+        })
+        .collect()
+}
+
+fn first_cols(mat: &Matrix<f64>, k: usize) -> Matrix<f64> {
+    let entries = (0..mat.rows()).flat_map(|i| mat[i][..k].to_vec()).collect();
+    Matrix::from_vec(mat.rows(), k, entries).unwrap()
+}
+
+fn max_abs_diff(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+    assert_eq!(a.dims(), b.dims());
+    (0..a.rows())
+        .flat_map(|i| (0..a.cols()).map(move |j| (i, j)))
+        .map(|(i, j)| (a[i][j] - b[i][j]).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn arnoldi_relation() -> Result<(), DimensionError> {
+    let n = 50;
+    let k = 20;
+    let a = Matrix::from_vec(n, n, pseudo_random(n * n, 7))?;
+    let v0 = Vector::from(pseudo_random(n, 11));
+    let (v, h) = a.arnoldi(&v0, k)?;
+    assert_eq!(v.dims(), Dimensions::new(n, k + 1));
+    assert_eq!(h.dims(), Dimensions::new(k + 1, k));
+
+    let lhs = (a * first_cols(&v, k))?;
+    let rhs = (v.clone() * h.clone())?;
+    assert!(max_abs_diff(&lhs, &rhs) < 1e-10);
+
+    let gram = (v.transpose() * v)?;
+    assert!(max_abs_diff(&gram, &Matrix::one(k + 1)?) < 1e-10);
+
+    for i in 0..(k + 1) {
+        for j in 0..k {
+            if i > j + 1 {
+                assert_eq!(h[i][j], 0.0);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn arnoldi_breakdown() -> Result<(), DimensionError> {
+    let a = matrix! {2.0, 0.0, 0.0; 0.0, 3.0, 0.0; 0.0, 0.0, 5.0};
+    let (v, h) = a.arnoldi(&vector![1.0, 1.0, 0.0], 3)?;
+    assert_eq!(v.dims(), Dimensions::new(3, 2));
+    assert_eq!(h.dims(), Dimensions::new(2, 2));
+    let lhs = (a.clone() * v.clone())?;
+    assert!(max_abs_diff(&lhs, &(v * h)?) < 1e-12);
+
+    let (v, h) = a.arnoldi(&vector![0.0, 0.0, 2.0], 2)?;
+    assert_eq!(v, matrix! {0.0; 0.0; 1.0});
+    assert_eq!(h, matrix! {5.0});
+
+    // The subspace becomes invariant exactly in the last step.
+    let (v, h) = a.arnoldi(&vector![1.0, 1.0, 0.0], 2)?;
+    assert_eq!(v.dims(), Dimensions::new(3, 2));
+    assert_eq!(h.dims(), Dimensions::new(2, 2));
+    let lhs = (a.clone() * v.clone())?;
+    assert!(max_abs_diff(&lhs, &(v * h)?) < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn arnoldi_errors() -> Result<(), DimensionError> {
+    let a = Matrix::<f64>::one(3)?;
+    assert_eq!(
+        Matrix::new(2, 3, 1.0)?.arnoldi(&vector![1.0, 0.0], 1),
+        Err(DimensionError::NoSquare)
+    );
+    assert!(matches!(
+        a.arnoldi(&vector![1.0, 0.0], 1),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    assert_eq!(
+        a.arnoldi(&vector![1.0, 0.0, 0.0], 0),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert!(matches!(
+        a.arnoldi(&vector![0.0, 0.0, 0.0], 2),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    Ok(())
+}
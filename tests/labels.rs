@@ -0,0 +1,84 @@
+use libmat::err::{DimensionError, Operand};
+use libmat::mat::dims::Dimensions;
+use libmat::mat::Matrix;
+use libmat::matrix;
+
+#[test]
+fn labeled_operands_in_errors() -> Result<(), DimensionError> {
+    let stiffness = Matrix::new(3, 4, 1.0)?.with_label("stiffness");
+    let load = Matrix::new(3, 4, 2.0)?.with_label("load");
+    let err = (stiffness.clone() * load.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        DimensionError::LabeledNoMatch(
+            Operand {
+                dims: Dimensions::new(3, 4),
+                label: Some("stiffness".to_owned())
+            },
+            Operand {
+                dims: Dimensions::new(3, 4),
+                label: Some("load".to_owned())
+            },
+            "multiply".to_owned()
+        )
+    );
+    assert!(err
+        .to_string()
+        .ends_with("Cannot multiply `stiffness` (3x4) with `load` (3x4)."));
+
+    // only one operand has a label
+    let err = (stiffness + Matrix::new(2, 2, 1.0)?).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("Cannot add `stiffness` (3x4) with 2x2 matrix."));
+    let err = (Matrix::new(2, 2, 1.0)? - load).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("Cannot subtract 2x2 matrix with `load` (3x4)."));
+    Ok(())
+}
+
+#[test]
+fn unlabeled_errors_are_unchanged() -> Result<(), DimensionError> {
+    let err = (Matrix::new(3, 4, 1)? * Matrix::new(3, 4, 1)?).unwrap_err();
+    assert_eq!(
+        err,
+        DimensionError::NoMatch(
+            Dimensions::new(3, 4),
+            Dimensions::new(3, 4),
+            "multiply".to_owned()
+        )
+    );
+    assert_eq!(
+        err.to_string(),
+        "Dimensions of two matrices do not match in the correct way. Cannot multiply 3x4 matrix with 3x4 matrix."
+    );
+    assert_eq!(matrix! {1, 2; 3, 4}.to_string(), "1 2\n3 4");
+    Ok(())
+}
+
+#[test]
+fn labels_do_not_change_semantics() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2; 3, 4}.with_label("a");
+    assert_eq!(mat_a.label(), Some("a"));
+    assert_eq!(mat_a, matrix! {1, 2; 3, 4});
+    assert_eq!(mat_a, matrix! {1, 2; 3, 4}.with_label("b"));
+    assert_eq!(mat_a.to_string(), "a =\n1 2\n3 4");
+
+    // clones keep the label, results of operations don't
+    assert_eq!(mat_a.clone().label(), Some("a"));
+    assert_eq!((mat_a.clone() * mat_a.clone())?.label(), None);
+    assert_eq!((mat_a.clone() + mat_a.clone())?.label(), None);
+    assert_eq!((mat_a.clone() * 2).label(), None);
+    assert_eq!((-mat_a.clone()).label(), None);
+    assert_eq!(mat_a.transpose().label(), None);
+    assert_eq!(mat_a.rref().label(), None);
+    assert_eq!(mat_a.lupdecompose()?.unwrap().0.label(), None);
+
+    // in-place operations keep the label of the target
+    let mut mat_b = mat_a.clone();
+    mat_b *= 3;
+    assert_eq!(mat_b.label(), Some("a"));
+    assert_eq!(mat_b, matrix! {3, 6; 9, 12});
+    Ok(())
+}
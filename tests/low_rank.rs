@@ -0,0 +1,60 @@
+#![cfg(feature = "rand")]
+use libmat::{err::DimensionError, mat::Matrix};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn approximation_error(
+    mat: &Matrix<f64>,
+    (u, v): (Matrix<f64>, Matrix<f64>),
+) -> Result<f64, DimensionError> {
+    Ok((mat.clone() - (u * v.transpose())?)?.frobenius_norm())
+}
+
+#[test]
+fn rank_three_plus_noise() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(11);
+    let (m, n) = (60, 40);
+    let left = Matrix::from_iter(m, 3, (0..).map(|_| rng.gen_range(-1.0..1.0)))?;
+    let right = Matrix::from_iter(3, n, (0..).map(|_| rng.gen_range(-1.0..1.0)))?;
+    let noise = Matrix::from_iter(m, n, (0..).map(|_| rng.gen_range(-1e-3..1e-3)))?;
+    let mat_a = ((left * right)? + noise.clone())?;
+    let noise_floor = noise.frobenius_norm();
+
+    let (u, v) = mat_a.low_rank_approx(3, 2, &mut rng)?;
+    assert_eq!((u.rows(), u.cols()), (m, 3));
+    assert_eq!((v.rows(), v.cols()), (n, 3));
+    let utu = (u.transpose() * u.clone())?;
+    assert!(utu.approx_eq(&Matrix::one(3)?, 1e-10));
+
+    let rank3 = approximation_error(&mat_a, (u, v))?;
+    let rank1 = approximation_error(&mat_a, mat_a.low_rank_approx(1, 2, &mut rng)?)?;
+    assert!(
+        rank3 <= 1.1 * noise_floor,
+        "{} vs noise {}",
+        rank3,
+        noise_floor
+    );
+    assert!(
+        rank1 > 100.0 * rank3,
+        "rank 1 {} vs rank 3 {}",
+        rank1,
+        rank3
+    );
+    Ok(())
+}
+
+#[test]
+fn low_rank_rejects_invalid_rank() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mat_a = Matrix::new(4, 6, 1_i32)?;
+    assert_eq!(
+        mat_a.low_rank_approx(0, 1, &mut rng),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        mat_a.low_rank_approx(5, 1, &mut rng),
+        Err(DimensionError::InvalidInputDimensions(5, 4))
+    );
+    let (u, v) = mat_a.low_rank_approx(4, 0, &mut rng)?;
+    assert!((u * v.transpose())?.approx_eq(&Matrix::new(4, 6, 1.0)?, 1e-10));
+    Ok(())
+}
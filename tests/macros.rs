@@ -1,6 +1,6 @@
 use libmat::err::DimensionError;
 use libmat::mat::{Matrix, SMatrix, Vector};
-use libmat::{matrix, smatrix, vector};
+use libmat::{matrix, smatrix, try_matrix, vector};
 
 #[test]
 fn matrix_macro() -> Result<(), DimensionError> {
@@ -27,3 +27,21 @@ fn wolfram_alpha_syntax() -> Result<(), DimensionError> {
     assert_eq!(mat_a, mat_b);
     Ok(())
 }
+
+#[test]
+fn ragged_matrix_macro() {
+    assert_eq!(
+        try_matrix! {1, 2, 3, 4; 5, 6},
+        Err(DimensionError::InvalidInputDimensions(2, 4))
+    );
+    assert_eq!(
+        try_matrix! {{1, 2}, {3, 4, 5}},
+        Err(DimensionError::InvalidInputDimensions(3, 2))
+    );
+}
+
+#[test]
+#[should_panic]
+fn ragged_matrix_macro_panics() {
+    let _: Matrix<i32> = matrix! {1, 2, 3, 4; 5, 6};
+}
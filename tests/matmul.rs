@@ -0,0 +1,44 @@
+#![cfg(feature = "rand")]
+use libmat::{err::DimensionError, mat::Matrix};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn naive_mul<T>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T>
+where
+    T: num_traits::Zero + num_traits::One + Clone + std::ops::Mul<Output = T>,
+{
+    let mut res = Matrix::<T>::zero(a.rows(), b.cols()).unwrap();
+    for i in 0..a.rows() {
+        for j in 0..b.cols() {
+            for k in 0..a.cols() {
+                res[i][j] = res[i][j].clone() + a[i][k].clone() * b[k][j].clone();
+            }
+        }
+    }
+    res
+}
+
+#[test]
+fn random_float_matrices() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(64);
+    let mat_a = Matrix::<f64>::from_iter(64, 64, (0..).map(|_| rng.gen_range(-1.0..1.0)))?;
+    let mat_b = Matrix::from_iter(64, 64, (0..).map(|_| rng.gen_range(-1.0..1.0)))?;
+    let product = (&mat_a * &mat_b)?;
+    let expected = naive_mul(&mat_a, &mat_b);
+    for i in 0..64 {
+        for j in 0..64 {
+            assert!((product[i][j] - expected[i][j]).abs() <= 1e-12);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn random_integer_matrices_across_tile_borders() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(65);
+    for &(m, k, n) in [(64, 64, 64), (1, 130, 1), (65, 130, 67), (129, 3, 70)].iter() {
+        let mat_a = Matrix::from_iter(m, k, (0..).map(|_| rng.gen_range(-100_i64..100)))?;
+        let mat_b = Matrix::from_iter(k, n, (0..).map(|_| rng.gen_range(-100_i64..100)))?;
+        assert_eq!((&mat_a * &mat_b)?, naive_mul(&mat_a, &mat_b));
+    }
+    Ok(())
+}
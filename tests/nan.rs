@@ -0,0 +1,50 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, Vector},
+    matrix,
+};
+
+#[test]
+fn reductions_skip_nan() -> Result<(), DimensionError> {
+    let nan = f64::NAN;
+    let readings = matrix! {
+        2.5, nan, 3.0, 1.5;
+        nan, 4.0, nan, 0.5;
+        6.0, 2.0, nan, 3.5
+    };
+    let clean: Vec<f64> = (0..readings.rows())
+        .flat_map(|i| readings[i].to_vec())
+        .filter(|x| !x.is_nan())
+        .collect();
+
+    assert!(readings.has_nan());
+    assert_eq!(readings.nan_min(), clean.iter().copied().reduce(f64::min));
+    assert_eq!(readings.nan_max(), clean.iter().copied().reduce(f64::max));
+    let (mean, skipped) = readings.nan_mean();
+    assert_eq!(skipped, 4);
+    assert_eq!(mean, Some(Vector::from(clean.clone()).mean()));
+    assert_eq!(
+        (mean, readings.nan_min(), readings.nan_max()),
+        (Some(2.875), Some(0.5), Some(6.0))
+    );
+
+    let filled = readings.replace_nan(0.0);
+    assert!(!filled.has_nan());
+    assert_eq!(filled.nan_mean(), (Some(23.0 / 12.0), 0));
+    Ok(())
+}
+
+#[test]
+fn clean_and_all_nan_matrices() -> Result<(), DimensionError> {
+    let clean = matrix! {1.0, -2.0; f64::INFINITY, 0.0};
+    assert!(!clean.has_nan());
+    assert_eq!(clean.replace_nan(5.0), clean);
+    assert_eq!(clean.nan_max(), Some(f64::INFINITY));
+
+    let empty = Matrix::new(2, 2, f64::NAN)?;
+    assert_eq!(empty.nan_min(), None);
+    assert_eq!(empty.nan_max(), None);
+    assert_eq!(empty.nan_mean(), (None, 4));
+    assert_eq!(empty.replace_nan(1.0), Matrix::new(2, 2, 1.0)?);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+use ndarray::{array, s, Array2};
+use std::convert::TryFrom;
+
+#[test]
+fn round_trip() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6}.with_label("A");
+    let arr = Array2::from(mat_a.clone());
+    assert_eq!(arr.dim(), (2, 3));
+    assert_eq!(arr[[1, 0]], 4);
+    let back = Matrix::try_from(arr)?;
+    assert_eq!(back, mat_a);
+    assert_eq!(back.label(), None);
+    Ok(())
+}
+
+#[test]
+fn convert_views_with_other_layouts() -> Result<(), DimensionError> {
+    let arr = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    // column-major after transposing
+    let transposed = Matrix::try_from(arr.clone().reversed_axes())?;
+    assert_eq!(transposed, matrix! {1, 4, 7; 2, 5, 8; 3, 6, 9});
+    // standard layout, but starting behind the first row
+    let bottom = Matrix::try_from(arr.clone().slice_move(s![1.., ..]))?;
+    assert_eq!(bottom, matrix! {4, 5, 6; 7, 8, 9});
+    // neither contiguous nor starting at the buffer
+    let inner = Matrix::try_from(arr.slice_move(s![1.., 1..]))?;
+    assert_eq!(inner, matrix! {5, 6; 8, 9});
+    Ok(())
+}
+
+#[test]
+fn reject_empty_arrays() {
+    for &shape in [(0, 3), (3, 0), (0, 0)].iter() {
+        assert_eq!(
+            Matrix::try_from(Array2::<f64>::zeros(shape)),
+            Err(DimensionError::InvalidDimensions)
+        );
+    }
+}
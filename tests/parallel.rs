@@ -0,0 +1,128 @@
+use libmat::err::DimensionError;
+use libmat::mat::{Matrix, Vector};
+use libmat::{matrix, vector};
+use num_traits::{One, Zero};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+
+fn counting_matrix(rows: usize, cols: usize) -> Matrix<i64> {
+    Matrix::from_vec(
+        rows,
+        cols,
+        (0..rows * cols)
+            .map(|x| (x as i64 * 7919) % 101 - 50)
+            .collect(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn par_map_rows_keeps_row_order() {
+    for &(rows, cols) in [(1, 1), (1, 9), (7, 1), (13, 3), (97, 5), (1031, 2)].iter() {
+        let mat_a = counting_matrix(rows, cols);
+        let firsts = mat_a.par_map_rows(|row| row[0]);
+        let serial: Vec<i64> = (0..rows).map(|i| mat_a[i][0]).collect();
+        assert_eq!(firsts, Vector::from(serial));
+        assert_eq!(mat_a.par_row_sums(), mat_a.row_sums());
+    }
+    let lengths = matrix! {1, 2; 3, 4; 5, 6}.par_map_rows(|row| row.len());
+    assert_eq!(lengths, vector![2, 2, 2]);
+}
+
+#[test]
+fn par_fold_entries_matches_serial_fold() {
+    for &(rows, cols) in [(1, 1), (3, 7), (61, 3), (509, 4)].iter() {
+        let mat_a = counting_matrix(rows, cols);
+        let entries: Vec<i64> = (0..rows).flat_map(|i| mat_a[i].to_vec()).collect();
+        let sum = mat_a.par_fold_entries(|| 0, |acc, x| acc + x, |a, b| a + b);
+        assert_eq!(sum, entries.iter().sum::<i64>());
+
+        // concatenation is associative but not commutative, so any reordering would show up
+        let concat = mat_a.par_fold_entries(
+            String::new,
+            |acc, x| acc + &x.to_string() + ",",
+            |a, b| a + &b,
+        );
+        let serial: String = entries.iter().map(|x| x.to_string() + ",").collect();
+        assert_eq!(concat, serial);
+    }
+}
+
+fn naive_mul<T>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    let entries = (0..a.rows())
+        .flat_map(|i| (0..b.cols()).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            (0..a.cols()).fold(T::zero(), |acc, k| acc + a[i][k].clone() * b[k][j].clone())
+        })
+        .collect();
+    Matrix::from_vec(a.rows(), b.cols(), entries).unwrap()
+}
+
+#[test]
+fn par_mul_matches_serial_product() -> Result<(), DimensionError> {
+    for &(m, k, n) in [(1, 1, 1), (15, 4, 9), (16, 70, 3), (17, 5, 2), (33, 65, 66)].iter() {
+        let mat_a = counting_matrix(m, k);
+        let mat_b = counting_matrix(k, n);
+        assert_eq!(mat_a.par_mul(&mat_b)?, naive_mul(&mat_a, &mat_b));
+
+        // every entry is summed in the same order as on a single thread
+        let float_a = Matrix::from_iter(m, k, (&mat_a).into_iter().map(|&x| x as f64 * 0.1))?;
+        let float_b = Matrix::from_iter(k, n, (&mat_b).into_iter().map(|&x| x as f64 * 0.3))?;
+        assert_eq!(float_a.par_mul(&float_b)?, (&float_a * &float_b)?);
+    }
+    let mat_a = counting_matrix(20, 3);
+    assert_eq!(
+        mat_a.par_mul(&mat_a),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            mat_a.dims(),
+            "multiply".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+/// An entry type that can't be sent to other threads.
+#[derive(Debug, Clone, PartialEq)]
+struct Local(i64, PhantomData<*const ()>);
+
+impl Add for Local {
+    type Output = Local;
+    fn add(self, rhs: Local) -> Local {
+        Local(self.0 + rhs.0, PhantomData)
+    }
+}
+
+impl Mul for Local {
+    type Output = Local;
+    fn mul(self, rhs: Local) -> Local {
+        Local(self.0 * rhs.0, PhantomData)
+    }
+}
+
+impl Zero for Local {
+    fn zero() -> Local {
+        Local(0, PhantomData)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Local {
+    fn one() -> Local {
+        Local(1, PhantomData)
+    }
+}
+
+#[test]
+fn serial_mul_doesnt_need_send() -> Result<(), DimensionError> {
+    let local = |x| Local(x, PhantomData);
+    let mat_a = Matrix::from_vec(1, 2, vec![local(1), local(2)])?;
+    let mat_b = Matrix::from_vec(2, 1, vec![local(3), local(4)])?;
+    assert_eq!((mat_a * mat_b)?, Matrix::from_vec(1, 1, vec![local(11)])?);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use libmat::{
+    err::ParseMatrixError,
+    mat::{Matrix, Vector},
+    matrix, vector,
+};
+
+#[test]
+fn parse_matrix() -> Result<(), ParseMatrixError> {
+    let expected = matrix! {1.0, 2.0, 3.0; 4.0, 5.0, 6.0};
+    assert_eq!("1 2 3; 4 5 6".parse::<Matrix<f64>>()?, expected);
+    assert_eq!("1, 2, 3; 4, 5, 6;".parse::<Matrix<f64>>()?, expected);
+    assert_eq!("  1,2 ,3\n4\t5 6\n\n".parse::<Matrix<f64>>()?, expected);
+    assert_eq!("-7".parse::<Matrix<i32>>()?, matrix! {-7});
+    let column: Matrix<u8> = "1; 2; 3".parse()?;
+    assert_eq!((column.rows(), column.cols()), (3, 1));
+    // round trip of the single line output needs the brackets removed
+    let flat = matrix! {1, -2; 3, 4}.to_string_flat().replace("], [", ";");
+    assert_eq!(
+        flat.trim_matches(['[', ']']).parse::<Matrix<i64>>()?,
+        matrix! {1, -2; 3, 4}
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_matrix_errors() {
+    assert_eq!(
+        "1 2; 3 four".parse::<Matrix<f64>>(),
+        Err(ParseMatrixError::InvalidEntry {
+            row: 1,
+            col: 1,
+            token: "four".to_owned()
+        })
+    );
+    assert_eq!(
+        "1 2 3; 4 5; 6 7 8".parse::<Matrix<f64>>(),
+        Err(ParseMatrixError::RaggedRow {
+            row: 1,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!("".parse::<Matrix<f64>>(), Err(ParseMatrixError::Empty));
+    assert_eq!(
+        " ; ,\n".parse::<Matrix<f64>>(),
+        Err(ParseMatrixError::Empty)
+    );
+    // negative numbers are no valid unsigned entries
+    let err = "1 -1".parse::<Matrix<u32>>().unwrap_err();
+    assert_eq!(err.to_string(), "Invalid entry `-1` in row 0, column 1.");
+}
+
+#[test]
+fn parse_vector() -> Result<(), ParseMatrixError> {
+    assert_eq!("1 2 3".parse::<Vector<i32>>()?, vector![1, 2, 3]);
+    assert_eq!("1, 2; 3\n4".parse::<Vector<i32>>()?, vector![1, 2, 3, 4]);
+    assert!("0.5 0.25".parse::<Vector<f64>>()?.is_col_vector());
+    assert_eq!(
+        "1, 2, x".parse::<Vector<i32>>(),
+        Err(ParseMatrixError::InvalidEntry {
+            row: 0,
+            col: 2,
+            token: "x".to_owned()
+        })
+    );
+    assert_eq!(" ".parse::<Vector<i32>>(), Err(ParseMatrixError::Empty));
+    Ok(())
+}
+
+#[test]
+fn parse_text_grid_from_file_contents() -> Result<(), ParseMatrixError> {
+    // like a fixture file written on Windows, with a trailing line break
+    let grid = "1 2 3\r\n4 5 6\r\n";
+    let mat_a: Matrix<f64> = grid.parse()?;
+    assert_eq!(mat_a, matrix! {1.0, 2.0, 3.0; 4.0, 5.0, 6.0});
+    assert_eq!(
+        "1 2\n3 4 5\n".parse::<Matrix<f64>>(),
+        Err(ParseMatrixError::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 3
+        })
+    );
+    Ok(())
+}
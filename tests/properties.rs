@@ -0,0 +1,87 @@
+//! Algebraic laws checked on generated matrices, run with `cargo test --features proptest-support`.
+
+use libmat::mat::Matrix;
+use libmat::proptest_support::{
+    invertible_matrix, matrix_with_dims, spd_matrix, square_matrix, vector,
+};
+use num_traits::Inv;
+use proptest::prelude::*;
+
+fn max_abs_diff(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+    assert_eq!(a.dims(), b.dims());
+    (0..a.rows())
+        .flat_map(|i| (0..a.cols()).map(move |j| (i, j)))
+        .map(|(i, j)| (a[i][j] - b[i][j]).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Two matrices of the same dimension, which can be multiplied in either order.
+fn square_pair() -> impl Strategy<Value = (Matrix<f64>, Matrix<f64>)> {
+    (1..6_usize).prop_flat_map(|n| {
+        (
+            matrix_with_dims(-2.0..2.0, n, n),
+            matrix_with_dims(-2.0..2.0, n, n),
+        )
+    })
+}
+
+/// Two integer matrices with dimensions `m x k` and `k x n`.
+fn product_pair() -> impl Strategy<Value = (Matrix<i64>, Matrix<i64>)> {
+    (1..6_usize, 1..6_usize, 1..6_usize).prop_flat_map(|(m, k, n)| {
+        (
+            matrix_with_dims(-100_i64..100, m, k),
+            matrix_with_dims(-100_i64..100, k, n),
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn det_is_multiplicative((a, b) in square_pair()) {
+        let det_ab = (a.clone() * b.clone()).unwrap().det().unwrap();
+        let det_a_det_b = a.det().unwrap() * b.det().unwrap();
+        prop_assert!(
+            (det_ab - det_a_det_b).abs() <= 1e-9 * (1.0 + det_a_det_b.abs()),
+            "{} vs {}", det_ab, det_a_det_b
+        );
+    }
+
+    #[test]
+    fn transpose_of_product((a, b) in product_pair()) {
+        prop_assert_eq!(
+            (a.clone() * b.clone()).unwrap().transpose(),
+            (b.transpose() * a.transpose()).unwrap()
+        );
+    }
+
+    #[test]
+    fn inverse_is_an_involution(a in invertible_matrix(1..6)) {
+        let inv_inv = a.clone().inv().unwrap().unwrap().inv().unwrap().unwrap();
+        prop_assert!(max_abs_diff(&inv_inv, &a) < 1e-8, "{}\nvs\n{}", inv_inv, a);
+    }
+
+    #[test]
+    fn rref_is_idempotent(a in square_matrix((-5_i32..5).prop_map(f64::from), 1..6)) {
+        let once = a.rref();
+        let twice = once.rref();
+        prop_assert!(max_abs_diff(&once, &twice) < 1e-9, "{}\nvs\n{}", once, twice);
+    }
+
+    #[test]
+    fn invertible_matrices_have_unit_det(a in invertible_matrix(1..6)) {
+        prop_assert!((a.det().unwrap().abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spd_matrices_have_cholesky_factor(a in spd_matrix(1..6)) {
+        prop_assert!(a.is_symmetric());
+        let l = a.cholesky().unwrap();
+        prop_assert!(l.is_some());
+    }
+
+    #[test]
+    fn vector_norm_is_nonnegative(v in vector(-1e3..1e3_f64, 1..10)) {
+        prop_assert!(v.norm() >= 0.0);
+        prop_assert!(v.norm() <= v.norm_l1() + 1e-9);
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rand")]
+use libmat::{err::DimensionError, mat::Matrix};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn sq_dist(mat: &Matrix<f64>, i: usize, j: usize) -> f64 {
+    mat[i]
+        .iter()
+        .zip(mat[j].iter())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum()
+}
+
+#[test]
+fn projection_shape() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mat_a = Matrix::new(6, 40, 2.5_f64)?;
+    let mat_b = mat_a.random_projection(12, &mut rng)?;
+    assert_eq!(mat_b.rows(), 6);
+    assert_eq!(mat_b.cols(), 12);
+    Ok(())
+}
+
+#[test]
+fn projection_too_large() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mat_a = Matrix::new(3, 4, 1_i32)?;
+    assert_eq!(
+        mat_a.random_projection(5, &mut rng),
+        Err(DimensionError::InvalidInputDimensions(5, 4))
+    );
+    assert_eq!(
+        mat_a.subsampled_rows(4, &mut rng),
+        Err(DimensionError::InvalidInputDimensions(4, 3))
+    );
+    Ok(())
+}
+
+#[test]
+fn projection_preserves_distances() -> Result<(), DimensionError> {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mat_a = Matrix::from_iter(5, 300, (0..).map(|_| rng.gen_range(-1.0..1.0)))?;
+    let mat_b = mat_a.random_projection(200, &mut rng)?;
+    for i in 0..5 {
+        for j in (i + 1)..5 {
+            let ratio = sq_dist(&mat_b, i, j) / sq_dist(&mat_a, i, j);
+            assert!((ratio - 1.0).abs() < 0.5, "ratio {} out of bounds", ratio);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn fixed_seed_is_deterministic() -> Result<(), DimensionError> {
+    let mat_a = Matrix::from_iter(8, 10, (0..).map(|x| x as f64))?;
+    let proj_a = mat_a.random_projection(4, &mut StdRng::seed_from_u64(3))?;
+    let proj_b = mat_a.random_projection(4, &mut StdRng::seed_from_u64(3))?;
+    assert_eq!(proj_a, proj_b);
+    let rows_a = mat_a.subsampled_rows(5, &mut StdRng::seed_from_u64(3))?;
+    let rows_b = mat_a.subsampled_rows(5, &mut StdRng::seed_from_u64(3))?;
+    assert_eq!(rows_a, rows_b);
+    Ok(())
+}
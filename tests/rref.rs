@@ -0,0 +1,209 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, PivotStrategy, SMatrix, Vector},
+    matrix, smatrix, vector,
+};
+
+#[test]
+fn rref_identity() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1.0, 2.0; 3.0, 4.0};
+    assert_eq!(mat_a.rref(), Matrix::one(2)?);
+    Ok(())
+}
+
+#[test]
+fn rref_zero_leading_column() {
+    assert_eq!(
+        matrix! {0, 0, 1; 0, 1, 0; 0, 0, 0}.rref(),
+        matrix! {0, 1, 0; 0, 0, 1; 0, 0, 0}
+    );
+    assert_eq!(
+        matrix! {0.0, 2.0, 4.0; 0.0, 1.0, 3.0}.rref(),
+        matrix! {0.0, 1.0, 0.0; 0.0, 0.0, 1.0}
+    );
+}
+
+#[test]
+fn rref_swap_in_the_middle() {
+    // the second pivot has to come from the last row
+    let mat_a = matrix! {1.0, 2.0, 3.0; 2.0, 4.0, 7.0; 1.0, 3.0, 3.0};
+    assert_eq!(mat_a.rref(), Matrix::one(3).unwrap());
+}
+
+#[test]
+fn rref_augmented_system() {
+    // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27 has the solution (5, 3, -2)
+    let mat_a = matrix! {1.0, 1.0, 1.0, 6.0; 0.0, 2.0, 5.0, -4.0; 2.0, 5.0, -1.0, 27.0};
+    let expected = matrix! {1.0, 0.0, 0.0, 5.0; 0.0, 1.0, 0.0, 3.0; 0.0, 0.0, 1.0, -2.0};
+    let partial = mat_a.rref_with(PivotStrategy::Largest(|x: &f64| x.abs()));
+    for (res, eps) in [(mat_a.rref(), 1e-12), (partial, 1e-12)] {
+        for i in 0..3 {
+            for j in 0..4 {
+                assert!((res[i][j] - expected[i][j]).abs() < eps, "{}", res);
+            }
+        }
+    }
+}
+
+#[test]
+fn rref_partial_pivoting() {
+    let abs = PivotStrategy::Largest(|x: &f64| x.abs());
+    let mat_a = matrix! {1e-20, 1.0, 1.0; 1.0, 1.0, 2.0};
+    assert_eq!(mat_a.rref_with(abs), matrix! {1.0, 0.0, 1.0; 0.0, 1.0, 1.0});
+    assert_eq!(mat_a.rref(), matrix! {1.0, 0.0, 0.0; 0.0, 1.0, 1.0});
+    // exact types give the same result with either strategy
+    let mat_b = matrix! {0, 0, 1; 0, 1, 0; 0, 0, 0};
+    assert_eq!(
+        mat_b.rref_with(PivotStrategy::Largest(|x: &i32| x.abs() as f64)),
+        mat_b.rref()
+    );
+}
+
+#[test]
+fn null_space_rank_one() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 2, 4, 6};
+    let basis = mat_a.null_space();
+    assert_eq!(basis.len(), 2);
+    for v in basis {
+        assert_eq!((mat_a.clone() * v)?, Vector::new(2, 0));
+    }
+    Ok(())
+}
+
+#[test]
+fn null_space_full_rank() -> Result<(), DimensionError> {
+    assert!(Matrix::<f64>::one(4)?.null_space().is_empty());
+    assert!(matrix! {2.0, 1.0; 1.0, 3.0; 0.0, 1.0}
+        .null_space()
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn null_space_wide() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1.0, 0.0, 2.0, 1.0; 0.0, 1.0, -1.0, 3.0};
+    let basis = mat_a.null_space();
+    assert_eq!(
+        basis,
+        vec![vector![-2.0, 1.0, 1.0, 0.0], vector![-1.0, -3.0, 0.0, 1.0]]
+    );
+    Ok(())
+}
+
+#[test]
+fn kernel_of_rank_deficient_matrix() -> Result<(), DimensionError> {
+    // 3x4 with rank 3, so the kernel is one-dimensional
+    let mat_a: Matrix<f64> = matrix! {1.0, 2.0, 0.0, 1.0; 0.0, 1.0, 1.0, 0.0; 1.0, 3.0, 2.0, 0.0};
+    let kernel = mat_a.kernel().unwrap();
+    assert_eq!((kernel.rows(), kernel.cols()), (4, 1));
+    assert_eq!(mat_a.null_space().len(), 1);
+    let product = (mat_a.clone() * kernel.clone())?;
+    for i in 0..3 {
+        assert!(product[i][0].abs() < 1e-12);
+    }
+    assert!((0..4).any(|i| kernel[i][0] != 0.0));
+
+    // two-dimensional kernel, checked column by column
+    let mat_b = matrix! {1, 2, 0, 1; 0, 1, 1, 0; 1, 3, 1, 1};
+    let kernel = mat_b.kernel().unwrap();
+    assert_eq!((kernel.rows(), kernel.cols()), (4, 2));
+    assert_eq!((mat_b * kernel)?, Matrix::zero(3, 2)?);
+
+    assert_eq!(Matrix::<f64>::one(3)?.kernel(), None);
+    Ok(())
+}
+
+#[test]
+fn rref_augmented_systems() -> Result<(), DimensionError> {
+    // two right hand sides at once
+    let mat_a = matrix! {2.0, 1.0; 1.0, 3.0};
+    let (left, right) = mat_a.rref_augmented(&matrix! {3.0, 1.0; 4.0, 3.0})?;
+    assert_eq!(left, Matrix::one(2)?);
+    assert_eq!(right, matrix! {1.0, 0.0; 1.0, 1.0});
+
+    // underdetermined: x + y + z = 3, y - z = 1 has the solutions (2 - 2t, 1 + t, t)
+    let mat_b = matrix! {1.0, 1.0, 1.0; 0.0, 1.0, -1.0};
+    let (left, right) = mat_b.rref_augmented(&matrix! {3.0; 1.0})?;
+    assert_eq!(left, matrix! {1.0, 0.0, 2.0; 0.0, 1.0, -1.0});
+    assert_eq!(right, matrix! {2.0; 1.0});
+
+    // inconsistent: pivots are not taken from the right hand side
+    let mat_c = matrix! {1.0, 2.0; 2.0, 4.0};
+    let (left, right) = mat_c.rref_augmented(&matrix! {1.0; 3.0})?;
+    assert_eq!(left, matrix! {1.0, 2.0; 0.0, 0.0});
+    assert_eq!(right, matrix! {1.0; 1.0});
+
+    assert!(matches!(
+        mat_c.rref_augmented(&matrix! {1.0; 2.0; 3.0}),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    Ok(())
+}
+
+/// Compare the entries of a static and a dynamic matrix.
+fn same_entries<T: PartialEq + std::fmt::Debug, const M: usize, const N: usize>(
+    smat: &SMatrix<T, M, N>,
+    mat: &Matrix<T>,
+) {
+    assert_eq!((mat.rows(), mat.cols()), (M, N));
+    for i in 0..M {
+        assert_eq!(&smat[i][..], &mat[i]);
+    }
+}
+
+#[test]
+fn smatrix_rref_matches_matrix() {
+    let smat_a: SMatrix<i32, 3, 3> = smatrix! {0, 0, 1; 0, 1, 0; 0, 0, 0};
+    assert_eq!(smat_a.rref(), smatrix! {0, 1, 0; 0, 0, 1; 0, 0, 0});
+
+    let smat_b: SMatrix<f64, 3, 3> = smatrix! {1.0, 2.0, 3.0; 2.0, 4.0, 7.0; 1.0, 3.0, 3.0};
+    let mat_b = matrix! {1.0, 2.0, 3.0; 2.0, 4.0, 7.0; 1.0, 3.0, 3.0};
+    same_entries(&smat_b.rref(), &mat_b.rref());
+
+    let smat_c: SMatrix<f64, 3, 4> =
+        smatrix! {1.0, 1.0, 1.0, 6.0; 0.0, 2.0, 5.0, -4.0; 2.0, 5.0, -1.0, 27.0};
+    let mat_c = matrix! {1.0, 1.0, 1.0, 6.0; 0.0, 2.0, 5.0, -4.0; 2.0, 5.0, -1.0, 27.0};
+    same_entries(&smat_c.rref(), &mat_c.rref());
+    let abs = PivotStrategy::Largest(|x: &f64| x.abs());
+    same_entries(&smat_c.rref_with(abs), &mat_c.rref_with(abs));
+
+    // tall matrix with a zero row after elimination
+    let smat_d: SMatrix<f64, 4, 2> = smatrix! {1.0, 2.0; 2.0, 4.0; 0.0, 1.0; 3.0, 5.0};
+    assert_eq!(
+        smat_d.rref(),
+        smatrix! {1.0, 0.0; 0.0, 1.0; 0.0, 0.0; 0.0, 0.0}
+    );
+}
+
+#[test]
+fn rref_of_unsigned_matrix_via_f64() {
+    // reducing in u32 would underflow when 4 * row 1 is subtracted from row 2
+    let mat_a: Matrix<u32> = matrix! {1, 2, 3; 4, 5, 6; 7, 8, 10};
+    assert_eq!(mat_a.rref_as_f64(), Matrix::one(3).unwrap());
+
+    let rank_two: Matrix<u32> = matrix! {2, 4, 6; 1, 2, 4};
+    let reduced = rank_two.rref_as_f64();
+    assert_eq!(reduced, matrix! {1.0, 2.0, 0.0; 0.0, 0.0, 1.0});
+    assert_eq!(reduced, rank_two.rref_as_f64().rref());
+
+    // signed integer and float matrices keep the direct path
+    let signed = matrix! {2, 4, 6; 1, 2, 4};
+    assert_eq!(signed.rref(), matrix! {1, 2, 0; 0, 0, 1});
+}
+
+#[test]
+fn rref_zero_in_interior_pivot_position() {
+    // after clearing the first column the second pivot position is zero, but column 0 isn't,
+    // so the row to swap in has to be found by looking at the pivot column
+    let mat_a = matrix! {1, 2, 3; 2, 4, 5; 3, 7, 1};
+    assert_eq!(mat_a.rref(), Matrix::one(3).unwrap());
+    let mat_b = matrix! {1.0, 2.0, 3.0; 2.0, 4.0, 5.0; 3.0, 7.0, 1.0};
+    assert_eq!(
+        mat_b.rref_with(PivotStrategy::FirstNonZero),
+        Matrix::one(3).unwrap()
+    );
+
+    // pivots skip a zero column and a dependent column
+    let mat_c = matrix! {0, 1, 2, 3; 0, 2, 4, 7; 0, 0, 0, 1};
+    assert_eq!(mat_c.rref(), matrix! {0, 1, 2, 0; 0, 0, 0, 1; 0, 0, 0, 0});
+}
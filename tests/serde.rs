@@ -0,0 +1,57 @@
+use libmat::mat::{dims::Dimensions, Matrix, SMatrix, Vector};
+use libmat::{matrix, smatrix, vector};
+
+#[test]
+fn matrix_round_trip() {
+    let mat_a = matrix! {1, -2, 3; 4, 5, -6}.with_label("A");
+    let json = serde_json::to_string(&mat_a).unwrap();
+    assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1,-2,3,4,5,-6]}"#);
+    let back: Matrix<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, mat_a);
+    assert_eq!(back.label(), None);
+}
+
+#[test]
+fn vector_round_trip() {
+    let col = vector![0.5, -1.25, 3.0];
+    let back: Vector<f64> = serde_json::from_str(&serde_json::to_string(&col).unwrap()).unwrap();
+    assert_eq!(back, col);
+    assert!(back.is_col_vector());
+
+    let row = col.to_row_vector();
+    let back: Vector<f64> = serde_json::from_str(&serde_json::to_string(&row).unwrap()).unwrap();
+    assert_eq!(back, row);
+    assert!(!back.is_col_vector());
+}
+
+#[test]
+fn smatrix_round_trip() {
+    let mat_a: SMatrix<f64, 3, 3> = smatrix! {1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.5};
+    let json = serde_json::to_string(&mat_a).unwrap();
+    assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.5]]");
+    let back: SMatrix<f64, 3, 3> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, mat_a);
+}
+
+#[test]
+fn inconsistent_lengths_are_rejected() {
+    let err =
+        serde_json::from_str::<Matrix<i32>>(r#"{"rows":2,"cols":2,"data":[1,2,3]}"#).unwrap_err();
+    assert!(err.to_string().contains('3'), "{}", err);
+    assert!(serde_json::from_str::<Matrix<i32>>(r#"{"rows":0,"cols":2,"data":[]}"#).is_err());
+    assert!(
+        serde_json::from_str::<Vector<i32>>(r#"{"rows":2,"cols":2,"data":[1,2,3,4]}"#).is_err()
+    );
+    assert!(serde_json::from_str::<SMatrix<i32, 2, 2>>("[[1,2],[3]]").is_err());
+    assert!(serde_json::from_str::<SMatrix<i32, 2, 2>>("[[1,2],[3,4],[5,6]]").is_err());
+}
+
+#[test]
+fn dimensions_round_trip() {
+    let dims = matrix! {1, 2, 3; 4, 5, 6}.dims();
+    let json = serde_json::to_string(&dims).unwrap();
+    assert_eq!(json, r#"{"rows":2,"cols":3}"#);
+    assert_eq!(serde_json::from_str::<Dimensions>(&json).unwrap(), dims);
+    assert!(serde_json::from_str::<Dimensions>(r#"{"rows":0,"cols":3}"#).is_err());
+    assert!(serde_json::from_str::<Dimensions>(r#"{"rows":2}"#).is_err());
+}
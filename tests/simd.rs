@@ -0,0 +1,169 @@
+use libmat::{err::DimensionError, mat::Matrix};
+use num_traits::{One, Zero};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+const SHAPES: [(usize, usize); 5] = [(1, 1), (3, 5), (4, 4), (7, 9), (2, 17)];
+
+/// Entries that are not exactly representable, so a wrong lane or a skipped tail changes the result.
+fn floats(rows: usize, cols: usize, seed: f64) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|i| ((i as f64 + seed) * 0.731).sin() * 1e3)
+        .collect()
+}
+
+fn ints(rows: usize, cols: usize, seed: i64) -> Vec<i64> {
+    (0..(rows * cols) as i64)
+        .map(|i| (i * 7919 + seed) % 20011 - 10005)
+        .collect()
+}
+
+#[test]
+fn f64_matches_scalar_loop() -> Result<(), DimensionError> {
+    for &(rows, cols) in SHAPES.iter() {
+        let (a, b) = (floats(rows, cols, 0.0), floats(rows, cols, 0.5));
+        let (mat_a, mat_b) = (
+            Matrix::from_vec(rows, cols, a.clone())?,
+            Matrix::from_vec(rows, cols, b.clone())?,
+        );
+        let sum: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x + y).collect();
+        let diff: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x - y).collect();
+        let scaled: Vec<f64> = a.iter().map(|x| x * 0.3).collect();
+        let mut res = mat_a.clone();
+        res += &mat_b;
+        assert_eq!(res, Matrix::from_vec(rows, cols, sum)?);
+        assert_eq!(res, (&mat_a + &mat_b)?);
+        let mut res = mat_a.clone();
+        res -= &mat_b;
+        assert_eq!(res, Matrix::from_vec(rows, cols, diff)?);
+        let mut res = mat_a.clone();
+        res *= 0.3;
+        assert_eq!(res, Matrix::from_vec(rows, cols, scaled)?);
+        assert_eq!(res, mat_a * 0.3);
+    }
+    Ok(())
+}
+
+#[test]
+fn f32_matches_scalar_loop() -> Result<(), DimensionError> {
+    for &(rows, cols) in SHAPES.iter() {
+        let a: Vec<f32> = floats(rows, cols, 1.0).iter().map(|&x| x as f32).collect();
+        let b: Vec<f32> = floats(rows, cols, 2.0).iter().map(|&x| x as f32).collect();
+        let (mut mat_a, mat_b) = (
+            Matrix::from_vec(rows, cols, a.clone())?,
+            Matrix::from_vec(rows, cols, b.clone())?,
+        );
+        mat_a += &mat_b;
+        let sum: Vec<f32> = a.iter().zip(&b).map(|(x, y)| x + y).collect();
+        assert_eq!(mat_a, Matrix::from_vec(rows, cols, sum.clone())?);
+        mat_a -= &mat_b;
+        let back: Vec<f32> = sum.iter().zip(&b).map(|(x, y)| x - y).collect();
+        assert_eq!(mat_a, Matrix::from_vec(rows, cols, back.clone())?);
+        mat_a *= -1.5;
+        let scaled: Vec<f32> = back.iter().map(|x| x * -1.5).collect();
+        assert_eq!(mat_a, Matrix::from_vec(rows, cols, scaled)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn i64_matches_scalar_loop() -> Result<(), DimensionError> {
+    for &(rows, cols) in SHAPES.iter() {
+        let (a, b) = (ints(rows, cols, 3), ints(rows, cols, 11));
+        let (mat_a, mat_b) = (
+            Matrix::from_vec(rows, cols, a.clone())?,
+            Matrix::from_vec(rows, cols, b.clone())?,
+        );
+        let sum: Vec<i64> = a.iter().zip(&b).map(|(x, y)| x + y).collect();
+        let diff: Vec<i64> = a.iter().zip(&b).map(|(x, y)| x - y).collect();
+        let mut res = mat_a.clone();
+        res += &mat_b;
+        assert_eq!(res, Matrix::from_vec(rows, cols, sum)?);
+        let mut res = mat_a.clone();
+        res -= &mat_b;
+        assert_eq!(res, Matrix::from_vec(rows, cols, diff)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn special_float_values_are_kept() -> Result<(), DimensionError> {
+    let mat_a = Matrix::from_vec(1, 5, vec![f64::INFINITY, -0.0, 1e308, 5e-324, 1.0])?;
+    let mat_b = Matrix::from_vec(1, 5, vec![1.0, 0.0, 1e308, 5e-324, f64::NAN])?;
+    let mut sum = mat_a.clone();
+    sum += &mat_b;
+    assert_eq!(sum[0][..4], [f64::INFINITY, 0.0, f64::INFINITY, 1e-323]);
+    assert!(sum[0][4].is_nan());
+    Ok(())
+}
+
+#[test]
+fn i64_overflow_behaves_like_generic_loop() -> Result<(), DimensionError> {
+    let mut entries = ints(2, 9, 5);
+    entries[6] = i64::MAX;
+    let mat_a = Matrix::from_vec(2, 9, entries)?;
+    let mat_b = Matrix::new(2, 9, 1_i64)?;
+    // panics with overflow checks and wraps without them, in both cases like the generic loop
+    let generic = catch_unwind(|| (&mat_a + &mat_b).unwrap());
+    let simd = catch_unwind(AssertUnwindSafe(|| {
+        let mut res = mat_a.clone();
+        res += &mat_b;
+        res
+    }));
+    assert_eq!(generic.ok(), simd.ok());
+    Ok(())
+}
+
+/// An entry type with a lifetime, so it isn't `'static` if it is created from a local.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Borrowed<'a>(i64, PhantomData<&'a i64>);
+
+impl<'a> Borrowed<'a> {
+    fn new(x: &'a i64) -> Self {
+        Borrowed(*x, PhantomData)
+    }
+}
+
+impl Add for Borrowed<'_> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Borrowed(self.0 + rhs.0, PhantomData)
+    }
+}
+
+impl Mul for Borrowed<'_> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Borrowed(self.0 * rhs.0, PhantomData)
+    }
+}
+
+impl AddAssign for Borrowed<'_> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Zero for Borrowed<'_> {
+    fn zero() -> Self {
+        Borrowed(0, PhantomData)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Borrowed<'_> {
+    fn one() -> Self {
+        Borrowed(1, PhantomData)
+    }
+}
+
+#[test]
+fn generic_operators_take_borrowed_entries() -> Result<(), DimensionError> {
+    let (one, two) = (1, 2);
+    let mat_a = Matrix::new(2, 2, Borrowed::new(&one))?;
+    assert_eq!((&mat_a + &mat_a)?, Matrix::new(2, 2, Borrowed::new(&two))?);
+    Ok(())
+}
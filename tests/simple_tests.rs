@@ -1,4 +1,9 @@
-use libmat::{err::DimensionError, mat::Matrix};
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, SMatrix},
+    matrix,
+};
+use num_traits::{Inv, One};
 
 #[test]
 fn construct() -> Result<(), DimensionError> {
@@ -65,6 +70,59 @@ fn scale() -> Result<(), DimensionError> {
     Ok(())
 }
 #[test]
+fn operators_on_references() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2; 3, 4; 5, 6};
+    let mat_b = matrix! {0, -1; 2, 1; 1, 1};
+    let mat_c = matrix! {1, 0, 2; -1, 1, 0};
+    let sum = (mat_a.clone() + mat_b.clone())?;
+    assert_eq!((&mat_a + &mat_b)?, sum);
+    assert_eq!((&mat_a + mat_b.clone())?, sum);
+    assert_eq!((mat_a.clone() + &mat_b)?, sum);
+    let difference = (mat_a.clone() - mat_b.clone())?;
+    assert_eq!((&mat_a - &mat_b)?, difference);
+    assert_eq!((&mat_a - mat_b.clone())?, difference);
+    assert_eq!((mat_a.clone() - &mat_b)?, difference);
+    let product = (mat_a.clone() * mat_c.clone())?;
+    assert_eq!((&mat_a * &mat_c)?, product);
+    assert_eq!((&mat_a * mat_c.clone())?, product);
+    assert_eq!((mat_a.clone() * &mat_c)?, product);
+
+    let mut mat_d = mat_a.clone();
+    mat_d += &mat_b;
+    mat_d -= &mat_b;
+    assert_eq!(mat_d, mat_a);
+
+    assert_eq!(&mat_a * 3, mat_a.clone() * 3);
+    assert_eq!(-&mat_a, -mat_a.clone());
+    assert_eq!(-&(&mat_a * -1), mat_a);
+
+    let labeled = mat_a.clone().with_label("A");
+    assert_eq!((&labeled + &mat_a)?.label(), None);
+    assert_eq!((&labeled * 2).label(), None);
+    assert_eq!((-&labeled).label(), None);
+    assert_eq!(&labeled + &mat_c, labeled.clone() + mat_c.clone());
+    assert_eq!(&labeled * &mat_a, labeled.clone() * mat_a.clone());
+    Ok(())
+}
+
+#[test]
+fn scale_from_the_left() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, -2, 3; -4, 5, -6};
+    assert_eq!(2 * mat_a.clone(), mat_a.clone() * 2);
+    assert_eq!(2 * &mat_a, mat_a * 2);
+    let mat_b: Matrix<u64> = Matrix::new(2, 3, 7)?;
+    assert_eq!(3 * &mat_b, mat_b * 3);
+    let mat_c = matrix! {0.1_f32, 0.2; 0.3, 0.7};
+    assert_eq!(1.5 * &mat_c, mat_c * 1.5);
+
+    let smat_a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[0.1, 0.2, 0.3], [-1.5, 2.5, 1e-3]]);
+    assert_eq!(3.0 * smat_a.clone(), smat_a.clone() * 3.0);
+    assert_eq!(3.0 * &smat_a, smat_a * 3.0);
+    let smat_b: SMatrix<i64, 2, 2> = SMatrix::from_rows([[1, 2], [3, 4]]);
+    assert_eq!(2 * smat_b.clone(), smat_b * 2);
+    Ok(())
+}
+#[test]
 fn add() -> Result<(), DimensionError> {
     let mat_a: Matrix<f32> = Matrix::<f32>::one(3)?;
     let mat_d: Matrix<f32> = Matrix::<f32>::diag(3, 2.0)?;
@@ -78,3 +136,358 @@ fn sub() -> Result<(), DimensionError> {
     assert_eq!((mat_a.clone() - mat_a)?, mat_b);
     Ok(())
 }
+
+#[test]
+fn pow() -> Result<(), DimensionError> {
+    let fib = Matrix::from_vec(2, 2, vec![1_u64, 1, 1, 0])?;
+    assert_eq!(fib.pow(0)?, Matrix::one(2)?);
+    assert_eq!(fib.pow(1)?, fib);
+    assert_eq!(fib.pow(50)?[0][1], 12586269025);
+
+    let a = Matrix::from_vec(3, 3, vec![1, 2, 0, 0, 1, 3, 1, 0, 1])?;
+    let mut expected = Matrix::one(3)?;
+    for _ in 0..7 {
+        expected = (expected * a.clone())?;
+    }
+    assert_eq!(a.pow(7)?, expected);
+    assert_eq!(Matrix::new(2, 3, 1)?.pow(2), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+#[test]
+fn pow_static() {
+    let fib: SMatrix<u64, 2, 2> = SMatrix::from([[1, 1], [1, 0]]);
+    assert_eq!(fib.pow(0), SMatrix::one());
+    assert_eq!(fib.pow(50)[0][1], 12586269025);
+    let markov: SMatrix<f64, 2, 2> = SMatrix::from([[0.5, 0.5], [0.25, 0.75]]);
+    let steady = markov.pow(64);
+    assert!((steady[0][0] - 1.0 / 3.0).abs() < 1e-12);
+    assert!((steady[1][1] - 2.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn identity_like() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2.5, -1.0, 0.0; 3.0, 1.0, 4.0; 0.0, 0.0, 9.0}.with_label("A");
+    let one = mat_a.identity_like()?;
+    assert_eq!(one, Matrix::one(3)?);
+    assert_eq!(one.label(), None);
+    assert_eq!((mat_a.clone() * one)?, mat_a);
+    assert_eq!(
+        Matrix::new(2, 3, 1)?.identity_like(),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
+
+#[test]
+fn multiply_static_2x3_by_3x4() -> Result<(), DimensionError> {
+    let mat_a: SMatrix<i64, 2, 3> = SMatrix::from([[1, -2, 3], [0, 4, -1]]);
+    let mat_b: SMatrix<i64, 3, 4> = SMatrix::from([[2, 0, 1, -1], [1, 3, 0, 2], [-2, 1, 5, 0]]);
+    let prod: SMatrix<i64, 2, 4> = mat_a.clone() * mat_b.clone();
+    assert_eq!(prod, SMatrix::from_rows([[-6, -3, 16, -5], [6, 11, -5, 8]]));
+
+    let dynamic_a = Matrix::from_vec(2, 3, mat_a.iter().flatten().copied().collect())?;
+    let dynamic_b = Matrix::from_vec(3, 4, mat_b.iter().flatten().copied().collect())?;
+    let dynamic = (dynamic_a * dynamic_b)?;
+    for i in 0..2 {
+        assert_eq!(dynamic[i], prod[i]);
+    }
+    Ok(())
+}
+
+#[test]
+fn powi_with_negative_exponents() -> Result<(), DimensionError> {
+    let mat_a = matrix! {0.0, 2.0, 1.0; 3.0, 0.0, 2.0; 1.0, 1.0, 0.0};
+    assert_eq!(mat_a.powi(-1)?, mat_a.clone().inv()?);
+    assert_eq!(mat_a.powi(0)?, Some(Matrix::one(3)?));
+    assert_eq!(mat_a.powi(3)?, Some(mat_a.pow(3)?));
+
+    let cube = (mat_a.powi(3)?.unwrap() * mat_a.powi(-3)?.unwrap())?;
+    assert!(cube.approx_eq(&Matrix::one(3)?, 1e-12), "{}", cube);
+    assert!(mat_a
+        .powi(-2)?
+        .unwrap()
+        .approx_eq(&mat_a.powi(-1)?.unwrap().pow(2)?, 1e-12));
+
+    assert_eq!(matrix! {1.0, 2.0; 3.0, 6.0}.powi(-3)?, None);
+    assert!(matches!(
+        matrix! {1.0, 2.0}.powi(-1),
+        Err(DimensionError::NoSquare)
+    ));
+    Ok(())
+}
+
+#[test]
+fn kronecker_product() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2; 3, 4};
+    assert_eq!(
+        mat_a.kronecker(&Matrix::one(2)?),
+        matrix! {1, 0, 2, 0; 0, 1, 0, 2; 3, 0, 4, 0; 0, 3, 0, 4}
+    );
+    assert_eq!(
+        Matrix::<i32>::one(2)?.kronecker(&mat_a),
+        matrix! {1, 2, 0, 0; 3, 4, 0, 0; 0, 0, 1, 2; 0, 0, 3, 4}
+    );
+
+    let row = matrix! {1, -1, 2};
+    let col = matrix! {2; 5};
+    let prod = row.kronecker(&col);
+    assert_eq!((prod.rows(), prod.cols()), (2, 3));
+    assert_eq!(prod, matrix! {2, -2, 4; 5, -5, 10});
+    // (A ⊗ B)(C ⊗ D) = (AC) ⊗ (BD)
+    let mat_b = matrix! {0, 1; 1, 1};
+    assert_eq!(
+        (mat_a.kronecker(&mat_b) * mat_b.kronecker(&mat_a))?,
+        (mat_a.clone() * mat_b.clone())?.kronecker(&(mat_b * mat_a)?)
+    );
+    Ok(())
+}
+
+#[test]
+fn structural_predicates() -> Result<(), DimensionError> {
+    let diagonal = Matrix::diag_with(3, &[1, 2, 3])?;
+    assert!(diagonal.is_diagonal() && diagonal.is_symmetric());
+    assert!(diagonal.is_upper_triangular() && diagonal.is_lower_triangular());
+
+    let upper = matrix! {1, 2, 3; 0, 4, 5; 0, 0, 6};
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.is_lower_triangular() && !upper.is_diagonal() && !upper.is_symmetric());
+    assert!(upper.transpose().is_lower_triangular());
+    assert!(!upper.transpose().is_upper_triangular());
+
+    // rectangular matrices are checked against their main diagonal
+    assert!(matrix! {1, 2, 3; 0, 4, 5}.is_upper_triangular());
+    assert!(matrix! {1, 0; 2, 3; 4, 5}.is_lower_triangular());
+    assert!(matrix! {7; 0; 0}.is_diagonal());
+    assert!(!matrix! {0, 0; 0, 0; 1, 0}.is_upper_triangular());
+
+    assert!(Matrix::<f64>::zero(2, 3)?.is_diagonal());
+    assert!(matrix! {5}.is_diagonal());
+    Ok(())
+}
+
+#[test]
+fn hadamard_product() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    let mat_b = matrix! {-1, 0, 2; 3, 1, 0};
+    assert_eq!(mat_a.hadamard(&mat_b)?, matrix! {-1, 0, 6; 12, 5, 0});
+    assert_eq!(mat_a.hadamard(&mat_b)?, mat_b.hadamard(&mat_a)?);
+    assert_eq!(mat_a.hadamard(&mat_a.ones_like())?, mat_a);
+    assert!(matches!(
+        mat_a.hadamard(&mat_a.transpose()),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+
+    let static_a: SMatrix<f64, 2, 2> = SMatrix::from([[0.5, 2.0], [-1.0, 3.0]]);
+    let static_b: SMatrix<f64, 2, 2> = SMatrix::from([[4.0, 0.5], [2.0, 0.0]]);
+    assert_eq!(
+        static_a.hadamard(&static_b),
+        SMatrix::from_rows([[2.0, 1.0], [-2.0, 0.0]])
+    );
+    Ok(())
+}
+
+#[test]
+fn zeros_like_and_ones_like() -> Result<(), DimensionError> {
+    let mat_a = matrix! {2.5, -1.0, 0.0; 3.0, 1.0, 4.0}.with_label("A");
+    let zeros = mat_a.zeros_like();
+    assert_eq!(zeros.dims(), mat_a.dims());
+    assert_eq!(zeros, Matrix::zero(2, 3)?);
+    let ones = mat_a.ones_like();
+    assert_eq!(ones, Matrix::new(2, 3, 1.0)?);
+    assert_eq!(ones.label(), None);
+    // an accumulator of the same shape
+    assert_eq!((zeros + mat_a.clone())?, mat_a);
+
+    let column = matrix! {7; 8; 9};
+    assert_eq!(column.ones_like(), matrix! {1; 1; 1});
+    Ok(())
+}
+
+#[test]
+fn map_changes_element_type() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, -2, 3; -4, 5, -6}.with_label("A");
+    let halves: Matrix<f64> = mat_a.map(|&x| f64::from(x) * 0.5);
+    assert_eq!(halves, matrix! {0.5, -1.0, 1.5; -2.0, 2.5, -3.0});
+    assert_eq!(halves.label(), None);
+
+    let relu = mat_a.map(|&x| x.max(0));
+    assert_eq!(relu, matrix! {1, 0, 3; 0, 5, 0});
+    assert_eq!(relu.dims(), mat_a.dims());
+
+    // FnMut closures see the entries in row-major order
+    let mut seen = Vec::new();
+    let index = mat_a.map(|&x| {
+        seen.push(x);
+        seen.len()
+    });
+    assert_eq!(seen, vec![1, -2, 3, -4, 5, -6]);
+    assert_eq!(index, matrix! {1, 2, 3; 4, 5, 6});
+    Ok(())
+}
+
+#[test]
+fn multiply_static_non_symmetric() {
+    // self[0][j] != rhs[0][j] for every j, so reading the wrong operand would change the product
+    let mat_a: SMatrix<i32, 2, 2> = SMatrix::from([[1, 2], [3, 4]]);
+    let mat_b: SMatrix<i32, 2, 2> = SMatrix::from([[5, 6], [7, 8]]);
+    assert_eq!(
+        mat_a.clone() * mat_b.clone(),
+        SMatrix::from([[19, 22], [43, 50]])
+    );
+    assert_eq!(mat_b * mat_a, SMatrix::from([[23, 34], [31, 46]]));
+
+    let mat_c: SMatrix<i32, 2, 3> = SMatrix::from([[1, -2, 0], [4, 0, 3]]);
+    let mat_d: SMatrix<i32, 3, 2> = SMatrix::from([[2, 1], [0, -1], [5, 7]]);
+    assert_eq!(mat_c * mat_d, SMatrix::from([[2, 3], [23, 25]]));
+}
+
+#[test]
+fn clone_into_reuses_target() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1.5, -2.0, 0.0; 4.0, 5.0, 6.5};
+    let mut target = Matrix::new(2, 3, 9.0)?;
+    let buffer = target[0].as_ptr();
+    mat_a.clone_into(&mut target)?;
+    assert_eq!(target, mat_a);
+    assert_eq!(target[0].as_ptr(), buffer);
+
+    let mut wrong = Matrix::new(3, 2, 9.0)?;
+    assert!(matches!(
+        mat_a.clone_into(&mut wrong),
+        Err(DimensionError::NoMatch(_, _, _))
+    ));
+    assert_eq!(wrong, Matrix::new(3, 2, 9.0)?);
+    Ok(())
+}
+
+#[test]
+fn chained_scalar_builders() -> Result<(), DimensionError> {
+    // map [0, 1] to [-1, 1] and shift by 10
+    let mat_a = matrix! {0.0, 0.25; 0.5, 1.0};
+    let mat_b = mat_a.scaled(2.0).offset(-1.0).offset(10.0).scaled(0.5);
+    assert_eq!(mat_b, matrix! {4.5, 4.75; 5.0, 5.5});
+
+    let mat_c = Matrix::new(2, 3, 1)?.with_label("c").offset(2).scaled(-1);
+    assert_eq!(mat_c, Matrix::new(2, 3, -3)?);
+    assert_eq!(mat_c.label(), Some("c"));
+    Ok(())
+}
+
+#[test]
+fn smatrix_from_padded_matrix() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1.0, 2.0; 3.0, 4.0};
+    let padded: SMatrix<f64, 3, 3> = SMatrix::from_matrix_padded(&mat_a, 0.0);
+    assert_eq!(
+        padded,
+        SMatrix::from_rows([[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+
+    let mat_b = Matrix::from_iter(3, 4, 1..)?;
+    let truncated: SMatrix<i32, 2, 2> = SMatrix::from_matrix_padded(&mat_b, -1);
+    assert_eq!(truncated, SMatrix::from_rows([[1, 2], [5, 6]]));
+    let mixed: SMatrix<i32, 4, 2> = SMatrix::from_matrix_padded(&mat_b, -1);
+    assert_eq!(
+        mixed,
+        SMatrix::from_rows([[1, 2], [5, 6], [9, 10], [-1, -1]])
+    );
+    let same: SMatrix<i32, 3, 4> = SMatrix::from_matrix_padded(&mat_b, -1);
+    assert_eq!((same.rows(), same.cols()), (mat_b.rows(), mat_b.cols()));
+    assert!((0..3).all(|i| same[i] == mat_b[i]));
+    Ok(())
+}
+
+/// An entry type that is `Clone` but not `Copy`.
+#[derive(Clone, Debug, PartialEq)]
+struct Tally(Box<i64>);
+
+impl std::ops::AddAssign for Tally {
+    fn add_assign(&mut self, rhs: Tally) {
+        *self.0 += *rhs.0;
+    }
+}
+
+impl std::ops::SubAssign for Tally {
+    fn sub_assign(&mut self, rhs: Tally) {
+        *self.0 -= *rhs.0;
+    }
+}
+
+impl std::ops::Add for Tally {
+    type Output = Tally;
+
+    fn add(self, rhs: Tally) -> Tally {
+        Tally(Box::new(*self.0 + *rhs.0))
+    }
+}
+
+impl std::ops::Mul for Tally {
+    type Output = Tally;
+
+    fn mul(self, rhs: Tally) -> Tally {
+        Tally(Box::new(*self.0 * *rhs.0))
+    }
+}
+
+impl num_traits::Zero for Tally {
+    fn zero() -> Tally {
+        Tally(Box::new(0))
+    }
+
+    fn is_zero(&self) -> bool {
+        *self.0 == 0
+    }
+}
+
+fn tallies(rows: usize, cols: usize, entries: &[i64]) -> Matrix<Tally> {
+    let entries: Vec<Tally> = entries.iter().map(|&x| Tally(Box::new(x))).collect();
+    libmat::mat::Vector::from(entries)
+        .into_matrix(rows, cols)
+        .unwrap()
+}
+
+#[test]
+fn accumulate_by_reference_without_copy() -> Result<(), DimensionError> {
+    let mut total = tallies(2, 2, &[0, 0, 0, 0]);
+    let delta = tallies(2, 2, &[1, -2, 3, 4]);
+    for _ in 0..5 {
+        total += &delta;
+    }
+    assert_eq!(total, tallies(2, 2, &[5, -10, 15, 20]));
+    total -= &delta;
+    total.try_sub_assign(&delta)?;
+    assert_eq!(total, tallies(2, 2, &[3, -6, 9, 12]));
+
+    let wide = tallies(1, 4, &[1, 1, 1, 1]);
+    assert_eq!(
+        total.try_add_assign(&wide),
+        Err(DimensionError::NoMatch(
+            total.dims(),
+            wide.dims(),
+            "add".to_owned()
+        ))
+    );
+    assert_eq!(total, tallies(2, 2, &[3, -6, 9, 12]));
+
+    let mut smat_a: SMatrix<i32, 2, 2> = SMatrix::from_rows([[1, 2], [3, 4]]);
+    let smat_b = SMatrix::from_rows([[1, 1], [1, 1]]);
+    smat_a += &smat_b;
+    smat_a += &smat_b;
+    smat_a -= &smat_b;
+    assert_eq!(smat_a, SMatrix::from_rows([[2, 3], [4, 5]]));
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Cannot add 2x2 matrix with 2x3 matrix")]
+fn add_assign_reports_the_dimensions() {
+    let mut mat_a = matrix! {1, 2; 3, 4};
+    mat_a += &matrix! {1, 2, 3; 4, 5, 6};
+}
+
+#[test]
+fn multiply_without_copy() -> Result<(), DimensionError> {
+    let mat_a = tallies(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let mat_b = tallies(3, 2, &[7, 8, 9, 10, 11, 12]);
+    assert_eq!((&mat_a * &mat_b)?, tallies(2, 2, &[58, 64, 139, 154]));
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Single precision matrices are computed in `f32` without going through `f64`.
+//! The explicit types of the results fail to compile if a method returns `f64`.
+
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, SMatrix, Vector},
+    matrix, smatrix, vector,
+};
+use num_traits::{Inv, One};
+
+const TOL: f32 = 64.0 * f32::EPSILON;
+
+fn close(a: &Matrix<f32>, b: &Matrix<f32>) -> bool {
+    a.approx_eq(b, TOL * b.frobenius_norm().max(1.0))
+}
+
+#[test]
+fn inverse_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {4.0, 1.0, 0.5; 1.0, 3.0, -1.0; 0.5, -1.0, 5.0};
+    let inv: Matrix<f32> = mat_a.clone().inv()?.unwrap();
+    assert!(close(&(mat_a * inv)?, &Matrix::one(3)?));
+    Ok(())
+}
+
+#[test]
+fn det_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {-0.2, 0.0, 0.4; -0.5, 0.4, -0.3; 0.0, 0.8, -0.4};
+    let det: f32 = mat_a.det()?;
+    // the same entries decomposed in f64 round to a different f32
+    let promoted = mat_a.map(|&x| f64::from(x)).det()? as f32;
+    assert_eq!(promoted, -0.17600001);
+    assert_eq!(det, -0.17599998);
+    Ok(())
+}
+
+#[test]
+fn solve_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 2.0};
+    let expected: Vector<f32> = vector![1.0, -2.0, 3.0];
+    let b = (mat_a.clone() * expected.clone())?;
+    let x: Vector<f32> = mat_a.solve(&b)?.unwrap();
+    assert!(x.approx_eq(&expected, TOL * expected.norm()));
+    Ok(())
+}
+
+#[test]
+fn qr_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {1.0, 2.0; 3.0, 4.0; 5.0, 6.0};
+    let (q, r): (Matrix<f32>, Matrix<f32>) = mat_a.qr()?;
+    assert!(close(&(q.clone() * r)?, &mat_a));
+    assert!(close(&(q.transpose() * q)?, &Matrix::one(2)?));
+    let x: Vector<f32> = mat_a.lstsq(&vector![5.0, 11.0, 17.0])?;
+    assert!(x.approx_eq(&vector![1.0, 2.0], 1e-4));
+    Ok(())
+}
+
+#[test]
+fn static_det_and_inverse_in_f32() {
+    let mat_a: SMatrix<f32, 4, 4> = smatrix! {
+        0.0, 2.0, 1.0, 0.0;
+        1.0, 0.0, 0.0, 3.0;
+        2.0, 1.0, 4.0, 1.0;
+        0.0, 1.0, 1.0, 1.0
+    };
+    let det: f32 = mat_a.det();
+    let integer: SMatrix<i64, 4, 4> = smatrix! {
+        0, 2, 1, 0;
+        1, 0, 0, 3;
+        2, 1, 4, 1;
+        0, 1, 1, 1
+    };
+    let exact = integer.det_exact() as f32;
+    assert!(
+        (det - exact).abs() <= TOL * exact.abs(),
+        "{} != {}",
+        det,
+        exact
+    );
+
+    let inv: SMatrix<f32, 4, 4> = mat_a.clone().inv().unwrap();
+    let product = mat_a.clone() * inv;
+    assert!(product.approx_eq(&SMatrix::one(), 8.0 * TOL));
+
+    let singular: SMatrix<f32, 2, 2> = smatrix! {1.0, 2.0; 2.0, 4.0};
+    assert_eq!(singular.clone().inv(), None);
+    assert_eq!(singular.det(), 0.0);
+}
+
+#[test]
+fn norms_in_f32() {
+    let norm: f32 = vector![3.0_f32, 4.0].norm();
+    assert_eq!(norm, 5.0);
+    let unit: Vector<f32> = vector![3.0_f32, 4.0].normalize().unwrap();
+    assert!(unit.approx_eq(&vector![0.6, 0.8], TOL));
+    let frobenius: f32 = matrix! {1.0_f32, 2.0; 2.0, 4.0}.frobenius_norm();
+    assert_eq!(frobenius, 5.0);
+}
+
+#[test]
+fn static_lu_in_f32() {
+    let mat_a: SMatrix<f32, 3, 3> = smatrix! {0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 2.0};
+    let (lu, p, _): (SMatrix<f32, 3, 3>, [usize; 3], usize) = mat_a.lupdecompose().unwrap();
+    assert_eq!(p[0], 2);
+    assert_eq!(lu[0][0], 3.0);
+    // the pivot tolerance is relative to the single precision epsilon
+    let nearly_singular: SMatrix<f32, 2, 2> = smatrix! {1.0, 1.0; 1.0, 1.0 + f32::EPSILON};
+    assert!(nearly_singular.lupdecompose().is_none());
+}
+
+#[test]
+fn gram_schmidt_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {3.0, 1.0, 4.0; 4.0, 2.0, 6.0; 0.0, 5.0, 5.0};
+    let q: Matrix<f32> = mat_a.gram_schmidt().unwrap();
+    assert_eq!(q.cols(), 2);
+    assert!(close(&(q.transpose() * q)?, &Matrix::one(2)?));
+    Ok(())
+}
+
+#[test]
+fn eigen_sym_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {4.0, 1.0, -2.0; 1.0, 2.0, 0.0; -2.0, 0.0, 3.0};
+    let (values, vectors): (Vector<f32>, Matrix<f32>) = mat_a.eigen_sym()?;
+    let mut diag = Matrix::zero(3, 3)?;
+    for i in 0..3 {
+        diag[i][i] = values[i];
+    }
+    let reconstructed = ((vectors.clone() * diag)? * vectors.transpose())?;
+    assert!(close(&reconstructed, &mat_a));
+    Ok(())
+}
+
+#[test]
+fn inv_refined_in_f32() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f32> = matrix! {4.0, 1.0, 0.5; 1.0, 3.0, -1.0; 0.5, -1.0, 5.0};
+    let inv: Matrix<f32> = mat_a.inv_refined(2)?.unwrap();
+    assert!(close(&(mat_a * inv)?, &Matrix::one(3)?));
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use libmat::err::DimensionError;
+use libmat::mat::{Matrix, Vector};
+use libmat::{matrix, vector};
+
+fn assert_doubly_stochastic(mat: &Matrix<f64>, tol: f64) {
+    for sum in mat.row_sums().iter().chain(mat.col_sums().iter()) {
+        assert!((sum - 1.0).abs() <= tol, "sum {} in\n{}", sum, mat);
+    }
+}
+
+#[test]
+fn row_and_col_sums() {
+    let mat = matrix! {1, -2, 3; 4, 5, -6};
+    assert_eq!(mat.row_sums(), vector![2, 3]);
+    assert_eq!(mat.col_sums(), vector![5, 3, -3].to_row_vector());
+    assert!(mat.col_sums().is_row_vector());
+}
+
+#[test]
+fn converges_on_positive_matrix() -> Result<(), DimensionError> {
+    // deterministic pseudo random entries in (0, 1]
+    let mut state = 12345_u64;
+    let entries = (0..25)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % 1000 + 1) as f64 / 1000.0
+        })
+        .collect();
+    let mat = Matrix::from_vec(5, 5, entries)?;
+    let balanced = mat.sinkhorn(1000, 1e-10)?;
+    assert_doubly_stochastic(&balanced, 1e-10);
+    for i in 0..5 {
+        assert!(balanced[i].iter().all(|x| *x > 0.0));
+    }
+    Ok(())
+}
+
+#[test]
+fn integer_input() -> Result<(), DimensionError> {
+    let balanced = matrix! {1, 1; 1, 3}.sinkhorn(1000, 1e-12)?;
+    assert_doubly_stochastic(&balanced, 1e-12);
+    Ok(())
+}
+
+#[test]
+fn permutation_is_fixed_point() -> Result<(), DimensionError> {
+    let perm = matrix! {0.0, 1.0, 0.0; 0.0, 0.0, 1.0; 1.0, 0.0, 0.0};
+    assert_eq!(perm.sinkhorn(10, 1e-12)?, perm);
+    assert_eq!(perm.sinkhorn(0, 1e-12)?, perm);
+    Ok(())
+}
+
+#[test]
+fn invalid_input() -> Result<(), DimensionError> {
+    assert!(matches!(
+        matrix! {1.0, -0.5; 0.5, 1.0}.sinkhorn(10, 1e-9),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        matrix! {1.0, 2.0; 0.0, 0.0}.sinkhorn(10, 1e-9),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        matrix! {1.0, 0.0; 2.0, 0.0}.sinkhorn(10, 1e-9),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        matrix! {1.0, f64::NAN; 2.0, 1.0}.sinkhorn(10, 1e-9),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    assert_eq!(
+        Matrix::new(2, 3, 1.0)?.sinkhorn(10, 1e-9),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
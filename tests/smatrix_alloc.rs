@@ -0,0 +1,60 @@
+//! Checks that determinants and inverses of statically sized matrices don't touch the heap.
+//! This is its own test binary, because it replaces the global allocator.
+
+use libmat::mat::SMatrix;
+use libmat::smatrix;
+use num_traits::Inv;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(|n| n.get());
+    let result = f();
+    (result, ALLOCATIONS.with(|n| n.get()) - before)
+}
+
+#[test]
+fn det_and_inv_do_not_allocate() {
+    let mat: SMatrix<f64, 6, 6> = smatrix! {
+        4.0, 1.0, 0.0, 2.0, 0.0, 1.0;
+        1.0, 5.0, 1.0, 0.0, 3.0, 0.0;
+        0.0, 1.0, 6.0, 1.0, 0.0, 2.0;
+        2.0, 0.0, 1.0, 7.0, 1.0, 0.0;
+        0.0, 3.0, 0.0, 1.0, 8.0, 1.0;
+        1.0, 0.0, 2.0, 0.0, 1.0, 9.0
+    };
+
+    let (_, n) = allocations(|| mat.det());
+    assert_eq!(n, 0);
+    let (_, n) = allocations(|| mat.lupdecompose());
+    assert_eq!(n, 0);
+    let (_, n) = allocations(|| mat.clone().inv());
+    assert_eq!(n, 0);
+}
+
+#[test]
+fn counting_allocator_counts() {
+    let (v, n) = allocations(|| vec![1_u8; 16]);
+    assert_eq!(v.len(), 16);
+    assert!(n > 0);
+}
@@ -0,0 +1,504 @@
+use libmat::{
+    assert_matrix_approx_eq,
+    err::DimensionError,
+    mat::{dims::Dimensions, Matrix, SColVector, SMatrix, SRowVector, Vector},
+    matrix, smatrix, vector,
+};
+
+#[test]
+fn matrix_times_col_vector() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    let vec_a = vector![1, 0, 2];
+    let res = (mat_a * vec_a)?;
+    assert_eq!(res, vector![7, 16]);
+    assert!(res.is_col_vector());
+    assert!(!res.is_row_vector());
+    Ok(())
+}
+
+#[test]
+fn row_vector_times_matrix() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    let vec_a = vector![1, 2].to_row_vector();
+    let res = (vec_a * mat_a)?;
+    assert_eq!(res, vector![9, 12, 15].to_row_vector());
+    assert!(res.is_row_vector());
+    assert!(!res.is_col_vector());
+    Ok(())
+}
+
+#[test]
+fn vector_matrix_mismatch() -> Result<(), DimensionError> {
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    let vec_a = vector![1, 2];
+    let vec_b = vector![1, 2, 3].to_row_vector();
+    assert_eq!(
+        mat_a.clone() * vec_a.clone(),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            Matrix::from(vec_a).dims(),
+            "multiply".to_owned()
+        ))
+    );
+    assert_eq!(
+        vec_b.clone() * mat_a.clone(),
+        Err(DimensionError::NoMatch(
+            Matrix::from(vec_b).dims(),
+            mat_a.dims(),
+            "multiply".to_owned()
+        ))
+    );
+    // a column vector can't be multiplied from the left
+    let res: Result<Vector<i32>, DimensionError> = vector![1, 2] * mat_a;
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn neg_by_reference() {
+    let vec_a = vector![1.5, -2.0, 0.0].to_row_vector();
+    let vec_b = -&vec_a;
+    assert_eq!(vec_b, vector![-1.5, 2.0, -0.0].to_row_vector());
+    assert!(vec_b.is_row_vector());
+    assert_eq!(vec_a, vector![1.5, -2.0, 0.0].to_row_vector());
+}
+
+#[test]
+fn norms() {
+    assert_eq!(vector![3, 4].norm(), 5.0);
+    assert_eq!(vector![3.0_f32, -4.0].norm(), 5.0);
+    assert_eq!(vector![3, 4].norm_squared(), 25);
+    assert_eq!(vector![1, -1, 1, -1].p_norm(1.0), 4.0);
+    assert!((vector![2.0, 2.0].p_norm(3.0) - 16_f64.cbrt()).abs() < 1e-12);
+}
+
+#[test]
+fn norms_l1_and_inf() {
+    let vec_a = vector![1, -7, 2, 0];
+    assert_eq!(vec_a.norm_l1(), 10.0);
+    assert_eq!(vec_a.norm_inf(), 7.0);
+    assert_eq!(vec_a.norm_l1(), vec_a.p_norm(1.0));
+    let vec_b = vector![-0.5_f32, 0.25];
+    assert_eq!(vec_b.norm_l1(), 0.75);
+    assert_eq!(vec_b.norm_inf(), 0.5);
+    assert_eq!(Vector::new(4, 0_u8).norm_inf(), 0.0);
+    // f64::max would skip the NaN and return 2
+    assert!(vector![f64::NAN, -2.0].norm_inf().is_nan());
+    assert!(vector![-2.0, f64::NAN, 1.0].norm_inf().is_nan());
+    // norm_inf <= norm <= norm_l1
+    assert!(vec_a.norm_inf() <= vec_a.norm() && vec_a.norm() <= vec_a.norm_l1());
+}
+
+#[test]
+fn normalize() {
+    let vec_a = vector![1.0_f64, -2.0, 2.0].normalize().unwrap();
+    assert!((vec_a.norm() - 1.0).abs() < 1e-12);
+    assert_eq!(vec_a, vector![1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0]);
+    assert_eq!(Vector::new(3, 0).normalize(), None);
+}
+
+#[test]
+fn normalize_keeps_direction_and_orientation() {
+    assert_eq!(vector![3.0, 4.0].normalize(), Some(vector![0.6, 0.8]));
+    assert_eq!(vector![0.0, -2.5].normalize(), Some(vector![0.0, -1.0]));
+    let row = vector![-6, 8].to_row_vector().normalize().unwrap();
+    assert!(row.is_row_vector());
+    assert_eq!(row, vector![-0.6, 0.8].to_row_vector());
+    assert_eq!(Vector::new(2, 0.0).to_row_vector().normalize(), None);
+    let unit = vector![1e-100_f64, -1e-100, 1e-100].normalize().unwrap();
+    assert!((unit.norm() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn distance() -> Result<(), DimensionError> {
+    let vec_a = vector![1, 2, 3];
+    let vec_b = vector![1, 5, 7];
+    assert_eq!(vec_a.distance(&vec_b)?, 5.0);
+    assert_eq!(vec_b.distance(&vec_a)?, 5.0);
+    assert!(vec_a.distance(&vector![1, 2]).is_err());
+    Ok(())
+}
+
+#[test]
+fn frobenius_norm() -> Result<(), DimensionError> {
+    assert_eq!(Matrix::new(2, 2, 1.5)?.frobenius_norm(), 3.0);
+    assert_eq!(matrix! {3, 0; 0, -4}.frobenius_norm(), 5.0);
+    Ok(())
+}
+
+#[test]
+fn clamp() {
+    let vec_a = vector![-1.5, 0.25, 0.5, 3.0].to_row_vector();
+    let vec_b = vec_a.clamp(0.0, 1.0);
+    assert_eq!(vec_b, vector![0.0, 0.25, 0.5, 1.0].to_row_vector());
+    assert!(vec_b.is_row_vector());
+    assert_eq!(vector![2, 3].clamp(2, 3), vector![2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn clamp_invalid_bounds() {
+    vector![1, 2].clamp(3, 1);
+}
+
+#[test]
+fn unique() {
+    assert_eq!(vector![1, 2, 2, 3, 1].unique(), vector![1, 2, 3]);
+    assert_eq!(vector![4, 4, 4].unique(), vector![4]);
+    let vec_a = vector![0.5, -1.0, 0.5].to_row_vector().unique();
+    assert_eq!(vec_a, vector![0.5, -1.0].to_row_vector());
+    assert!(vec_a.is_row_vector());
+}
+
+#[test]
+fn outer() -> Result<(), DimensionError> {
+    let vec_a = vector![1, 2];
+    let vec_b = vector![3, -1, 4];
+    let prod = vec_a.outer(&vec_b);
+    assert_eq!(prod, matrix! {3, -1, 4; 6, -2, 8});
+    assert_eq!(prod.dims(), Dimensions::new(2, 3));
+    assert_eq!(prod.transpose(), vec_b.outer(&vec_a));
+    assert_eq!(vec_a.to_row_vector().outer(&vec_b), prod);
+    assert_eq!(vec_a.outer(&vec_b.to_row_vector()), prod);
+
+    let vec_c = vector![1.5, 0.0, -2.0];
+    assert_eq!(
+        vec_c.outer(&vec_c),
+        matrix! {2.25, 0.0, -3.0; 0.0, 0.0, 0.0; -3.0, 0.0, 4.0}
+    );
+    Ok(())
+}
+
+#[test]
+fn outer_rank_one_update_and_projection() -> Result<(), DimensionError> {
+    // rank-1 update of the identity
+    let u = vector![1.0, 2.0];
+    let v = vector![3.0, 4.0, 5.0];
+    let update = u.outer(&v);
+    assert_eq!(update, matrix! {3.0, 4.0, 5.0; 6.0, 8.0, 10.0});
+    assert_eq!(update.rank(), 1);
+    let updated = (Matrix::<f64>::one(2)? + u.outer(&u))?;
+    assert_eq!(updated, matrix! {2.0, 2.0; 2.0, 5.0});
+
+    // projection onto the line spanned by w
+    let w = vector![3.0, 4.0];
+    let mut proj = w.outer(&w);
+    proj *= 1.0 / w.norm_squared();
+    assert_matrix_approx_eq!((proj.clone() * proj.clone())?, proj, 1e-12);
+    assert_matrix_approx_eq!((proj * Matrix::from(w.clone()))?, Matrix::from(w), 1e-12);
+    Ok(())
+}
+
+#[test]
+fn outer_static() {
+    let vec_a: SColVector<i32, 2> = smatrix! {1; 2};
+    let vec_b: SColVector<i32, 3> = smatrix! {3; -1; 4};
+    let prod: SMatrix<i32, 2, 3> = vec_a.outer(&vec_b);
+    assert_eq!(prod, smatrix! {3, -1, 4; 6, -2, 8});
+    assert_eq!(prod.transpose(), vec_b.outer(&vec_a));
+}
+
+#[test]
+fn static_column_vectors() {
+    let mut vec_a = SColVector::from([1.0, 2.0, 2.0]);
+    assert_eq!(vec_a, smatrix! {1.0; 2.0; 2.0});
+    assert_eq!((vec_a[0], vec_a[1], vec_a[2]), (1.0, 2.0, 2.0));
+    assert_eq!(vec_a.norm(), 3.0);
+    vec_a[2] = -2.0;
+    assert_eq!(vec_a, smatrix! {1.0; 2.0; -2.0});
+
+    let vec_b = SColVector::from([4.0, 0.0, 1.0]);
+    assert_eq!(vec_a.dot(&vec_b), 2.0);
+    let normal = vec_a.cross(&vec_b);
+    assert_eq!(normal, SColVector::from([2.0, -9.0, -8.0]));
+    assert_eq!((normal.dot(&vec_a), normal.dot(&vec_b)), (0.0, 0.0));
+}
+
+#[test]
+fn static_matrix_vector_products() {
+    let mat_a: SMatrix<i32, 2, 3> = smatrix! {1, 2, 3; 4, 5, 6};
+    // the dimensions of the results are inferred from the operands
+    let col = mat_a.clone() * SColVector::from([1, 0, -1]);
+    assert_eq!((col.rows(), col.cols()), (2, 1));
+    assert_eq!((col[0], col[1]), (-2, -2));
+
+    let row: SRowVector<i32, 2> = smatrix! {1, -1};
+    let prod = row.clone() * mat_a;
+    assert_eq!(prod, smatrix! {-3, -3, -3});
+
+    // a row vector times a column vector is a 1x1 matrix, whose only entry is its single index
+    let inner = row * SColVector::from([2, 5]);
+    assert_eq!(inner[0], -3);
+}
+
+#[test]
+fn geometric_sequences() -> Result<(), DimensionError> {
+    assert_eq!(Vector::geomspace(1.0, 100.0, 3)?, vector![1.0, 10.0, 100.0]);
+    assert_eq!(Vector::geomspace(8.0, 1.0, 4)?, vector![8.0, 4.0, 2.0, 1.0]);
+    assert_eq!(Vector::geomspace(5.0, 7.0, 1)?, vector![5.0]);
+
+    let frequencies = Vector::geomspace(20.0, 20_000.0, 31)?;
+    assert_eq!(frequencies.size(), 31);
+    assert_eq!((frequencies[0], frequencies[30]), (20.0, 20_000.0));
+    for i in 1..30 {
+        let (left, right) = (
+            frequencies[i] / frequencies[i - 1],
+            frequencies[i + 1] / frequencies[i],
+        );
+        assert!((left - right).abs() < 1e-12, "{} vs {}", left, right);
+    }
+
+    assert_eq!(
+        Vector::geomspace(1.0, 10.0, 0),
+        Err(DimensionError::InvalidDimensions)
+    );
+    for (start, end) in [
+        (0.0, 10.0),
+        (-1.0, 10.0),
+        (1.0, f64::INFINITY),
+        (f64::NAN, 1.0),
+    ] {
+        assert!(matches!(
+            Vector::geomspace(start, end, 5),
+            Err(DimensionError::InvalidValue(_))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn arithmetic_sequences() -> Result<(), DimensionError> {
+    assert_eq!(
+        Vector::arange(0.0, 1.0, 0.25)?,
+        vector![0.0, 0.25, 0.5, 0.75]
+    );
+    assert_eq!(Vector::arange(0.0, 1.1, 0.5)?, vector![0.0, 0.5, 1.0]);
+    assert_eq!(Vector::arange(2.0, -1.0, -1.5)?, vector![2.0, 0.5]);
+    assert_eq!(Vector::arange(1.0, 1.5, 2.0)?, vector![1.0]);
+
+    let tenths = Vector::arange(0.0, 1.0, 0.1)?;
+    assert_eq!(tenths.size(), 10);
+    assert!((tenths[9] - 0.9).abs() < 1e-15);
+
+    assert_eq!(
+        Vector::arange(1.0, 1.0, 0.5),
+        Err(DimensionError::InvalidDimensions)
+    );
+    for (start, stop, step) in [
+        (0.0, 1.0, 0.0),
+        (0.0, 1.0, -0.25),
+        (1.0, 0.0, 0.25),
+        (0.0, f64::INFINITY, 1.0),
+        (f64::NAN, 1.0, 0.5),
+    ] {
+        assert!(matches!(
+            Vector::arange(start, stop, step),
+            Err(DimensionError::InvalidValue(_))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn statistics() {
+    let data = vector![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    assert_eq!(data.mean(), 5.0);
+    assert_eq!(data.variance(), 4.0);
+    assert_eq!(data.std_dev(), 2.0);
+    assert_eq!(data.median(), 4.5);
+
+    let data = vector![7, -1, 3, 10, 1];
+    assert_eq!(data.mean(), 4.0);
+    assert_eq!(data.variance(), 16.0);
+    assert_eq!(data.std_dev(), 4.0);
+    assert_eq!(data.median(), 3.0);
+    assert_eq!(vector![5].variance(), 0.0);
+}
+
+#[test]
+fn histogram() {
+    let data = vector![0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 9.0, -1.0];
+    let (edges, counts) = data.histogram(5);
+    assert_eq!(edges, vec![-1.0, 1.0, 3.0, 5.0, 7.0, 9.0]);
+    assert_eq!(counts, vec![2, 4, 1, 0, 1]);
+    assert_eq!(counts.iter().sum::<usize>(), data.size());
+
+    let (edges, counts) = vector![2, 2, 2].histogram(3);
+    assert_eq!(edges.len(), 4);
+    assert_eq!(counts.iter().filter(|c| **c > 0).count(), 1);
+    assert_eq!(counts.iter().sum::<usize>(), 3);
+
+    let (_, counts) = vector![4.0, 1.0].histogram(1);
+    assert_eq!(counts, vec![2]);
+}
+
+#[test]
+fn cross() -> Result<(), DimensionError> {
+    assert_eq!(vector![1, 0, 0].cross(&vector![0, 1, 0])?, vector![0, 0, 1]);
+    assert_eq!(
+        vector![0, 1, 0].cross(&vector![1, 0, 0])?,
+        vector![0, 0, -1]
+    );
+    let vec_a = vector![2.0, -1.0, 3.0];
+    let vec_b = vector![0.5, 4.0, 1.0];
+    let vec_c = vec_a.cross(&vec_b)?;
+    assert_eq!(vec_c, vector![-13.0, -0.5, 8.5]);
+    assert_eq!((vec_a.clone() * vec_c.clone())?, 0.0);
+    assert_eq!((vec_b * vec_c)?, 0.0);
+    assert_eq!(vec_a.cross(&vec_a)?, vector![0.0, 0.0, 0.0]);
+    assert!(vector![1, 2, 3]
+        .to_row_vector()
+        .cross(&vector![4, 5, 6])?
+        .is_row_vector());
+    assert_eq!(
+        vector![1, 2].cross(&vector![1, 2, 3]),
+        Err(DimensionError::InvalidInputDimensions(2, 3))
+    );
+    assert_eq!(
+        vector![1, 2, 3].cross(&vector![1, 2, 3, 4]),
+        Err(DimensionError::InvalidInputDimensions(4, 3))
+    );
+    Ok(())
+}
+
+#[test]
+fn reverse() {
+    let vec_a = vector![1.5, -2.0, 0.0, 4.0];
+    assert_eq!(vec_a.reverse(), vector![4.0, 0.0, -2.0, 1.5]);
+    assert_eq!(vec_a.reverse().reverse(), vec_a);
+    let row = vector![1, 2, 3].to_row_vector();
+    assert!(row.reverse().is_row_vector());
+    assert_eq!(row.reverse().reverse(), row);
+    assert_eq!(vector![7].reverse(), vector![7]);
+}
+
+#[test]
+fn comparison_masks() {
+    let vec_a = vector![1, 2, 3];
+    assert_eq!(vec_a.gt(1), vector![false, true, true]);
+    assert_eq!(vec_a.lt(1), vector![false, false, false]);
+    assert_eq!(vec_a.ge(1), vector![true, true, true]);
+    assert_eq!(vec_a.le(2), vector![true, true, false]);
+    let vec_b = vector![0.5, f64::NAN, -1.0].to_row_vector();
+    let mask = vec_b.gt(0.0);
+    assert_eq!(mask, vector![true, false, false].to_row_vector());
+    assert!(mask.is_row_vector());
+    assert_eq!(vec_b.le(0.0), vector![false, false, true].to_row_vector());
+}
+
+#[test]
+fn select_thresholds_entries() -> Result<(), DimensionError> {
+    let vec_a = vector![4, -2, 9, 1, 12];
+    assert_eq!(vec_a.select(&vec_a.gt(5), 5)?, vector![4, -2, 5, 1, 5]);
+    assert_eq!(vec_a.select(&vec_a.lt(0), 0)?, vector![4, 0, 9, 1, 12]);
+
+    // orientation of self is kept, the mask may be a row vector
+    let vec_b = vector![0.1, 0.7, 0.4].to_row_vector();
+    let res = vec_b.select(&vector![false, true, false], 0.5)?;
+    assert!(res.is_row_vector());
+    assert_eq!(res, vector![0.1, 0.5, 0.4].to_row_vector());
+
+    assert_eq!(
+        vec_a.select(&vector![true, false], 0),
+        Err(DimensionError::InvalidInputDimensions(2, 5))
+    );
+    Ok(())
+}
+
+#[test]
+fn unit_vectors() -> Result<(), DimensionError> {
+    assert_eq!(Vector::<f64>::unit(3, 1)?, vector![0.0, 1.0, 0.0]);
+    assert_eq!(Vector::<i32>::unit(1, 0)?, vector![1]);
+    assert!(Vector::<i32>::unit(4, 3)?.is_col_vector());
+    // picks a column of the identity
+    let mat_a = matrix! {1, 2, 3; 4, 5, 6};
+    assert_eq!((mat_a * Vector::unit(3, 2)?)?, vector![3, 6]);
+
+    assert_eq!(
+        Vector::<i32>::unit(0, 0),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert!(matches!(
+        Vector::<i32>::unit(2, 2),
+        Err(DimensionError::InvalidValue(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn scale_vectors_from_the_left() {
+    let vec_a = vector![1, -2, 3];
+    assert_eq!(2 * vec_a.clone(), vec_a.clone() * 2);
+    assert_eq!(2 * &vec_a, vec_a * 2);
+    let vec_b: Vector<f64> = vector![0.1, 0.2, 0.7].to_row_vector();
+    assert_eq!(3.0 * &vec_b, vec_b.clone() * 3.0);
+    assert!((3.0 * &vec_b).is_row_vector());
+    let vec_c: Vector<u32> = Vector::new(4, 5);
+    assert_eq!(4 * vec_c.clone(), vec_c * 4);
+}
+
+#[test]
+fn vector_operators_on_references() -> Result<(), DimensionError> {
+    let vec_a = vector![1, 2, 3];
+    let vec_b = vector![4, -5, 6];
+    assert_eq!((&vec_a * &vec_b)?, 12);
+    assert_eq!((&vec_a * vec_b.clone())?, 12);
+    assert_eq!((vec_a.clone() * &vec_b)?, 12);
+    assert_eq!((&vec_a + &vec_b)?, vector![5, -3, 9]);
+    assert_eq!((vec_a.clone() + &vec_b)?, (&vec_a + vec_b.clone())?);
+    assert_eq!((&vec_a - &vec_b)?, vector![-3, 7, -3]);
+    assert_eq!((vec_a.clone() - &vec_b)?, (&vec_a - vec_b.clone())?);
+
+    let mat_a = matrix! {1, 0; 0, 1; 1, 1};
+    let image = (mat_a.clone() * vector![1, -1])?;
+    assert_eq!(image, vector![1, -1, 0]);
+    assert_eq!((&mat_a * &vector![1, -1])?, image);
+    assert_eq!((mat_a.clone() * &vector![1, -1])?, image);
+    assert_eq!(&mat_a * &vec_a, mat_a.clone() * vec_a.clone());
+    let row = vec_a.to_row_vector();
+    assert_eq!((&row * &mat_a)?, vector![4, 5].to_row_vector());
+    assert_eq!((row.clone() * &mat_a)?, (&row * mat_a.clone())?);
+    assert_eq!(&vec_a * &mat_a, vec_a.clone() * mat_a);
+    assert_eq!(&vec_a * &vector![1, 2], vec_a.clone() * vector![1, 2]);
+
+    let mut vec_c = vec_a.clone();
+    vec_c += &vec_b;
+    vec_c -= &vec_b;
+    assert_eq!(vec_c, vec_a);
+    Ok(())
+}
+
+#[test]
+fn reshape_between_vectors_and_matrices() -> Result<(), DimensionError> {
+    let vec_a: Vector<u32> = (0..20).collect();
+    let vec_b = vec_a.clone();
+    let ptr = vec_b.as_ptr();
+    let mat_a = vec_b.into_matrix(4, 5)?;
+    assert_eq!((mat_a.rows(), mat_a.cols()), (4, 5));
+    assert_eq!(mat_a[0].as_ptr(), ptr);
+    assert_eq!(mat_a[1], [5, 6, 7, 8, 9]);
+    assert_eq!(mat_a[3][4], 19);
+
+    let round_trip = mat_a.into_vector();
+    assert_eq!(round_trip.as_ptr(), ptr);
+    assert_eq!(round_trip, vec_a);
+    assert!(round_trip.is_col_vector());
+    let row = round_trip.into_matrix(2, 10)?.into_row_vector();
+    assert_eq!(row.as_ptr(), ptr);
+    assert_eq!(row, vec_a.to_row_vector());
+
+    assert_eq!(
+        vec_a.clone().into_matrix(3, 7),
+        Err(DimensionError::InvalidInputDimensions(20, 21))
+    );
+    assert_eq!(
+        vec_a.clone().into_matrix(0, 20),
+        Err(DimensionError::InvalidDimensions)
+    );
+    // the product of the dimensions wraps around to the length of the vector
+    assert_eq!(
+        vec_a.into_matrix(usize::MAX / 2 + 11, 2),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}